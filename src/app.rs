@@ -1,14 +1,59 @@
+use crate::approval::{self, ActionRequest, PendingRequest, RequestDraft};
 use crate::aws;
 use crate::aws::client::AwsClients;
-use crate::config::Config;
+use crate::aws::profiles::ProfileInfo;
+use crate::config::{
+    Config, FreezePolicy, IncidentLayout, IncidentPanel, LiveRefreshSource, NamedContext,
+    ViewConfig, ViewSort,
+};
+use crate::external::{EditTarget, ExternalRequest, ExternalTool};
+use crate::jobs::{BackgroundTask, JobProgress, JobWatch, WatchedJob};
 use crate::resource::{
-    extract_json_value, fetch_resources_paginated, get_all_resource_keys, get_resource,
-    ResourceDef, ResourceFilter,
+    cell_error, extract_json_value, fetch_resources_paginated, find_references,
+    get_all_resource_keys, get_resource, has_cell_errors, required_iam_actions, ActionDef,
+    ColumnDef, FormField, ResourceDef, ResourceFilter, ResourceRef, CELL_ERRORS_FIELD,
+    SENSITIVE_FIELDS_FIELD,
 };
 use anyhow::Result;
 use crossterm::event::KeyCode;
 use fuzzy_matcher::{skim::SkimMatcherV2, FuzzyMatcher};
 use serde_json::Value;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+
+/// How often views tracking an in-progress job are refreshed
+const JOB_REFRESH_INTERVAL: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// Delays before the failed cells of a page are retried; retrying stops after the last
+const CELL_RETRY_DELAYS: [std::time::Duration; 3] = [
+    std::time::Duration::from_secs(15),
+    std::time::Duration::from_secs(30),
+    std::time::Duration::from_secs(60),
+];
+
+/// How often incident dashboard panels are refreshed
+const INCIDENT_REFRESH_INTERVAL: std::time::Duration = std::time::Duration::from_secs(10);
+
+/// Log lines kept per incident dashboard log panel
+const INCIDENT_LOG_LINES: usize = 200;
+
+/// How often awaited action requests are checked for approval
+const APPROVAL_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// How often watched jobs are checked for completion
+const JOB_WATCH_INTERVAL: std::time::Duration = std::time::Duration::from_secs(15);
+
+/// Result pages searched for a watched job's row
+const JOB_WATCH_MAX_PAGES: usize = 5;
+
+/// How far back live refresh reads CloudTrail events (covers delivery delay)
+const LIVE_REFRESH_LOOKBACK: std::time::Duration = std::time::Duration::from_secs(900);
+
+/// How often the SSM Change Calendar state is re-checked
+const CHANGE_CALENDAR_REFRESH_INTERVAL: std::time::Duration = std::time::Duration::from_secs(300);
+
+/// Rows handed to the draw loop while pages stream in (more than a screen)
+const FETCH_STREAM_ROWS: usize = 200;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum Mode {
@@ -19,10 +64,15 @@ pub enum Mode {
     Warning,      // Warning/info dialog (OK only)
     Profiles,     // Profile selection
     Regions,      // Region selection
+    Contexts,     // Named context selection
     Describe,     // Viewing JSON details of selected item
     SsoLogin,     // SSO login dialog (IAM Identity Center)
     ConsoleLogin, // Console login dialog (aws login)
     LogTail,      // Tailing CloudWatch logs
+    Form,         // Parameter input form for an action
+    Incident,     // Incident dashboard of pinned panels
+    CellView,     // Full value of a table cell
+    Columns,      // Column visibility, width and sort editor
 }
 
 /// Pending action that requires confirmation
@@ -32,8 +82,8 @@ pub struct PendingAction {
     pub service: String,
     /// SDK method to call (e.g., "terminate_instance")  
     pub sdk_method: String,
-    /// Resource ID to act on
-    pub resource_id: String,
+    /// Resource IDs to act on (more than one when items are marked for bulk actions)
+    pub resource_ids: Vec<String>,
     /// Display message for confirmation dialog
     pub message: String,
     /// If true, default selection is No (kept for potential future use)
@@ -43,6 +93,67 @@ pub struct PendingAction {
     pub destructive: bool,
     /// Currently selected option (true = Yes, false = No)
     pub selected_yes: bool,
+    /// If true, needs a second operator's approval when approval mode is enabled
+    pub requires_approval: bool,
+    /// If set, the result is shown in the describe view under this title
+    pub result_title: Option<String>,
+    /// Result fields to mask until revealed
+    pub sensitive_fields: Vec<String>,
+    /// If set, accepting bills requests to this Requester Pays bucket to the
+    /// caller and retries the listing (no action is run)
+    pub requester_pays_bucket: Option<String>,
+    /// Submitted form of a form action, whose values the action runs with
+    pub form: Option<FormState>,
+    /// Job to watch once the action ran
+    pub watch: Option<JobWatch>,
+}
+
+/// Input form collecting parameters for an action
+#[derive(Debug, Clone)]
+pub struct FormState {
+    /// Dialog title (action display name)
+    pub title: String,
+    /// Service name (e.g., "ssm")
+    pub service: String,
+    /// SDK method executed on submit (e.g., "start_automation")
+    pub sdk_method: String,
+    /// Resources the action applies to (more than one when items are marked for bulk actions)
+    pub resource_ids: Vec<String>,
+    /// Input fields
+    pub fields: Vec<FormField>,
+    /// Index of the field being edited
+    pub selected: usize,
+    /// Whether submitting during a change freeze was already confirmed
+    pub freeze_confirmed: bool,
+    /// Confirmation the action asks for once the form is submitted
+    pub confirm: Option<crate::resource::ConfirmConfig>,
+    /// Job to watch once the action ran
+    pub watch: Option<JobWatch>,
+}
+
+/// Popup showing the full value of a (possibly truncated) table cell
+#[derive(Debug, Clone, Default)]
+pub struct CellViewState {
+    /// Column index in the current resource's columns
+    pub column: usize,
+    pub scroll: usize,
+    /// Whether the value was just copied to the clipboard
+    pub copied: bool,
+}
+
+/// Pending retry of the cells that failed to load on the current page
+#[derive(Debug, Clone)]
+pub struct CellRetry {
+    /// Index into CELL_RETRY_DELAYS of this retry
+    pub attempt: usize,
+    pub due: std::time::Instant,
+}
+
+/// Popup for showing, hiding, resizing and sorting the current view's columns
+#[derive(Debug, Clone, Default)]
+pub struct ColumnsEditorState {
+    /// Index in the resource definition's columns (hidden ones included)
+    pub selected: usize,
 }
 
 /// Parent context for hierarchical navigation
@@ -98,6 +209,25 @@ impl AwsFilters {
         }
     }
 
+    /// Request parameters for the filters
+    pub fn to_resource_filters(&self) -> Vec<ResourceFilter> {
+        self.filters
+            .iter()
+            .map(|(key, value)| {
+                if key.to_lowercase() == "owner" {
+                    // Special handling for "owner" - uses Owner.N param, not Filter
+                    ResourceFilter::new(&format!("owner:{}", value), vec![value.clone()])
+                } else if key.starts_with("tag:") {
+                    // Tag filters: tag:Key=Value -> Filter.N.Name=tag:Key, Filter.N.Value.1=Value
+                    ResourceFilter::new(key, vec![value.clone()])
+                } else {
+                    // Regular filters: key=value -> Filter.N.Name=key, Filter.N.Value.1=value
+                    ResourceFilter::new(&format!("filter:{}", key), vec![value.clone()])
+                }
+            })
+            .collect()
+    }
+
     /// Display string for the filters
     pub fn display(&self) -> String {
         let pairs: Vec<String> = self
@@ -143,20 +273,34 @@ pub struct App {
     // Profile/Region
     pub profile: String,
     pub region: String,
-    pub available_profiles: Vec<String>,
+    pub available_profiles: Vec<ProfileInfo>,
     pub available_regions: Vec<String>,
     pub profiles_selected: usize,
     pub regions_selected: usize,
+    pub contexts_selected: usize,
+    /// Context to finish switching to once an SSO/console login completes
+    pub pending_context: Option<String>,
 
     // Confirmation
     pub pending_action: Option<PendingAction>,
 
+    // Multi-select: IDs of items marked for bulk actions
+    pub marked_ids: HashSet<String>,
+
+    /// Buckets the user was asked about Requester Pays charges (true = accepted)
+    pub requester_pays_buckets: HashMap<String, bool>,
+
     // UI state
     pub loading: bool,
     pub error_message: Option<String>,
     pub describe_scroll: usize,
     pub describe_data: Option<Value>, // Full resource details from describe API
     pub last_action_display_name: Option<String>,
+    /// Fields of describe_data masked until revealed (sensitive action results)
+    pub describe_masked_fields: Vec<String>,
+    pub describe_revealed: bool,
+    /// Index into describe_references() of the highlighted link (Tab cycles)
+    pub describe_reference_selected: Option<usize>,
 
     // Describe search state
     pub describe_search_text: String,
@@ -176,6 +320,9 @@ pub struct App {
     // Read-only mode (blocks all write operations)
     pub readonly: bool,
 
+    // Mask sensitive values on screen (for screen sharing)
+    pub redacted: bool,
+
     // Warning message for modal dialog
     pub warning_message: Option<String>,
 
@@ -203,6 +350,59 @@ pub struct App {
     // SSM connect request (instance_id, region, profile)
     pub ssm_connect_request: Option<SsmConnectRequest>,
 
+    // Content to open in the external pager or editor (requires suspending TUI)
+    pub external_request: Option<ExternalRequest>,
+
+    // Rows to hand to the external fuzzy finder (requires suspending TUI)
+    pub picker_request: Option<Vec<String>>,
+
+    // Action input form state
+    pub form_state: Option<FormState>,
+
+    // Expanded cell popup state
+    pub cell_view: Option<CellViewState>,
+
+    // Column editor popup state
+    pub columns_editor: Option<ColumnsEditorState>,
+
+    // Incident dashboard (pinned panels survive leaving the dashboard)
+    pub incident: IncidentState,
+
+    // SSM Change Calendar state (None until first checked)
+    pub change_calendar: Option<ChangeCalendarState>,
+
+    // Change event cursor for live refresh (None until first polled)
+    pub live_refresh: Option<LiveRefreshState>,
+
+    // Retry of cells whose lookups failed (None when the page has none)
+    pub cell_retry: Option<CellRetry>,
+
+    // IAM policy simulation results for action preflight
+    pub permission_cache: PermissionCache,
+
+    // Services the identity can't access, from the capability probe
+    pub capabilities: CapabilityState,
+
+    // Fetch in flight (None when idle), shared with the draw loop
+    pub fetch_progress: Arc<Mutex<Option<FetchProgress>>>,
+
+    // Open tabs in display order; the active tab's slot is None because its
+    // state is held in the fields above
+    pub tabs: Vec<Option<Tab>>,
+    pub active_tab: usize,
+
+    // Jobs started from taws that notify the webhook when they finish
+    pub watched_jobs: Vec<WatchedJob>,
+    // Clients for jobs started in another profile or region, by (profile, region)
+    job_clients: HashMap<(String, String), AwsClients>,
+    // Long-running actions executing off the UI loop
+    pub background_tasks: Vec<BackgroundTask>,
+    pub jobs_polled_at: std::time::Instant,
+
+    // Action requests waiting for a second operator's approval (kept across restarts)
+    pub awaiting_approval: Vec<PendingRequest>,
+    pub approvals_polled_at: std::time::Instant,
+
     // Fuzzy matcher for filtering (reused to avoid repeated allocations)
     pub fuzzy_matcher: SkimMatcherV2,
 }
@@ -215,30 +415,134 @@ pub struct SsmConnectRequest {
     pub profile: String,
 }
 
+/// A tab's credential context and view (the active tab lives in `App` itself)
+pub struct Tab {
+    pub clients: AwsClients,
+    pub profile: String,
+    pub region: String,
+    pub current_resource_key: String,
+    pub items: Vec<Value>,
+    pub filtered_items: Vec<Value>,
+    pub selected: usize,
+    pub filter_text: String,
+    pub aws_filters: Option<AwsFilters>,
+    pub parent_context: Option<ParentContext>,
+    pub navigation_stack: Vec<ParentContext>,
+    pub pagination: PaginationState,
+    pub marked_ids: HashSet<String>,
+}
+
+impl Tab {
+    /// An empty view of `resource_key` using the given clients
+    fn new(clients: AwsClients, resource_key: &str) -> Self {
+        Self {
+            profile: clients.profile.clone(),
+            region: clients.region.clone(),
+            clients,
+            current_resource_key: resource_key.to_string(),
+            items: Vec::new(),
+            filtered_items: Vec::new(),
+            selected: 0,
+            filter_text: String::new(),
+            aws_filters: None,
+            parent_context: None,
+            navigation_stack: Vec::new(),
+            pagination: PaginationState::default(),
+            marked_ids: HashSet::new(),
+        }
+    }
+}
+
 /// Pagination state for resource listings
 #[derive(Debug, Clone)]
 pub struct PaginationState {
+    /// Token the current page was fetched with (None for the first page)
+    pub current_token: Option<String>,
     /// Token for fetching next page (None if no more pages)
     pub next_token: Option<String>,
-    /// Stack of previous page tokens for going back
+    /// Tokens of the pages before the current one, for going back
     pub token_stack: Vec<Option<String>>,
     /// Current page number (1-indexed for display)
     pub current_page: usize,
     /// Whether there are more pages available
     pub has_more: bool,
+    /// Recently visited pages by page number, so paging back and forth doesn't refetch
+    pub cached_pages: BTreeMap<usize, CachedPage>,
+    /// Whether the items shown belong to this view (false until its first fetch)
+    pub loaded: bool,
+}
+
+/// Items of a visited page and the token of the page after it
+#[derive(Debug, Clone)]
+pub struct CachedPage {
+    pub items: Vec<Value>,
+    pub next_token: Option<String>,
 }
 
+/// Pages kept in memory on either side of the current page
+const PAGE_CACHE_WINDOW: usize = 5;
+
 impl Default for PaginationState {
     fn default() -> Self {
         Self {
+            current_token: None,
             next_token: None,
             token_stack: Vec::new(),
             current_page: 1,
             has_more: false,
+            cached_pages: BTreeMap::new(),
+            loaded: false,
         }
     }
 }
 
+impl PaginationState {
+    /// Keep the current page's items before leaving it
+    fn cache_current(&mut self, items: &[Value]) {
+        self.cached_pages.insert(
+            self.current_page,
+            CachedPage {
+                items: items.to_vec(),
+                next_token: self.next_token.clone(),
+            },
+        );
+    }
+
+    /// Move to the next page, returning the token to fetch it with
+    fn advance(&mut self) -> Option<String> {
+        self.token_stack.push(self.current_token.take());
+        self.current_token = self.next_token.clone();
+        self.current_page += 1;
+        self.current_token.clone()
+    }
+
+    /// Move to the previous page, returning the token to fetch it with
+    fn go_back(&mut self) -> Option<String> {
+        self.current_token = self.token_stack.pop().flatten();
+        self.current_page = self.current_page.saturating_sub(1).max(1);
+        self.current_token.clone()
+    }
+
+    /// Pages after the current one were appended to it; `next_token` continues after them
+    fn absorb_following(&mut self, next_token: Option<String>) {
+        let current = self.current_page;
+        self.cached_pages.retain(|page, _| *page < current);
+        self.has_more = next_token.is_some();
+        self.next_token = next_token;
+    }
+
+    /// The (now current) page if it is cached; pages outside the window are dropped
+    fn take_cached(&mut self) -> Option<CachedPage> {
+        let current = self.current_page;
+        self.cached_pages
+            .retain(|page, _| page.abs_diff(current) <= PAGE_CACHE_WINDOW);
+        let page = self.cached_pages.remove(&current)?;
+        self.next_token = page.next_token.clone();
+        self.has_more = page.next_token.is_some();
+        Some(page)
+    }
+}
+
 /// SSO Login dialog state
 #[derive(Debug, Clone)]
 pub enum SsoLoginState {
@@ -333,6 +637,137 @@ pub struct LogTailState {
     pub error: Option<String>,
 }
 
+/// Last checked SSM Change Calendar state
+#[derive(Debug, Clone)]
+pub struct ChangeCalendarState {
+    /// OPEN, CLOSED, or "-" when no calendars exist
+    pub state: String,
+    /// Calendars that were checked
+    pub calendars: Vec<String>,
+    /// When the state next changes ("-" if unknown)
+    pub next_transition: String,
+    /// Error from the last check
+    pub error: Option<String>,
+    /// Profile and region the state belongs to
+    pub profile: String,
+    pub region: String,
+    pub checked_at: std::time::Instant,
+}
+
+/// Where live refresh last read change events
+#[derive(Debug, Clone)]
+pub struct LiveRefreshState {
+    /// Profile and region the cursor belongs to
+    pub profile: String,
+    pub region: String,
+    /// Change event IDs already acted on, with when they were first seen
+    pub seen: HashMap<String, std::time::Instant>,
+    pub checked_at: std::time::Instant,
+}
+
+/// A fetch (or form action) in flight. The draw loop keeps a handle to it so
+/// it can redraw while the fetch holds the app
+#[derive(Debug, Clone)]
+pub struct FetchProgress {
+    /// What is happening, for the footer (e.g., "Loading EC2 Instances")
+    pub activity: String,
+    /// Display name of the resource being fetched
+    pub resource: String,
+    /// Visible columns, for the skeleton and streamed rows
+    pub columns: Vec<ColumnDef>,
+    pub formatter: crate::humanize::Formatter,
+    /// Breadcrumb of the view being fetched
+    pub crumb: String,
+    /// Rows above the table inside the content area (tab bar, filter bar)
+    pub table_offset: u16,
+    /// Whether the table is drawn from this progress: the rows loaded so far,
+    /// then skeleton rows, in place of the last frame's table
+    pub skeleton: bool,
+    /// Rows loaded so far, around the selection (`FETCH_STREAM_ROWS` at most)
+    pub rows: Vec<Value>,
+    /// Index of the selected row within `rows`
+    pub selected: Option<usize>,
+    /// Items loaded so far (None when nothing is being counted)
+    pub loaded: Option<usize>,
+    pub started: std::time::Instant,
+}
+
+/// Services the current profile's identity was denied, found by a background
+/// probe of one read-only list call per service
+#[derive(Debug, Default)]
+pub struct CapabilityState {
+    /// Profile and region the results belong to (regional services can differ)
+    pub profile: String,
+    pub region: String,
+    /// Identity (caller ARN) the probes ran as
+    pub identity: Option<String>,
+    /// Service (e.g., "ec2") -> the denied call's error
+    pub denied: HashMap<String, String>,
+    /// Whether every probe has reported
+    pub finished: bool,
+    receiver: Option<std::sync::mpsc::Receiver<CapabilityProbeResult>>,
+}
+
+/// Report of the background capability probe
+#[derive(Debug)]
+enum CapabilityProbeResult {
+    Identity(String),
+    Denied { service: String, reason: String },
+    Finished,
+}
+
+/// IAM policy simulation results for the current profile
+#[derive(Debug, Clone, Default)]
+pub struct PermissionCache {
+    /// Profile the decisions belong to
+    pub profile: String,
+    /// IAM action (e.g., "ec2:StopInstances") -> allowed
+    pub decisions: HashMap<String, bool>,
+}
+
+/// Live data of one incident dashboard panel
+#[derive(Debug, Clone)]
+pub struct IncidentPanelState {
+    /// What the panel shows
+    pub panel: IncidentPanel,
+    /// Rows of a resource panel
+    pub items: Vec<Value>,
+    /// Latest events of a log panel
+    pub log_events: Vec<LogEvent>,
+    /// Token to continue a log panel's stream from
+    pub next_forward_token: Option<String>,
+    /// Datapoints of a metric panel, oldest first
+    pub values: Vec<f64>,
+    /// Error from the last refresh
+    pub error: Option<String>,
+}
+
+impl IncidentPanelState {
+    fn new(panel: IncidentPanel) -> Self {
+        Self {
+            panel,
+            items: Vec::new(),
+            log_events: Vec::new(),
+            next_forward_token: None,
+            values: Vec::new(),
+            error: None,
+        }
+    }
+}
+
+/// State of the incident dashboard
+#[derive(Debug, Clone, Default)]
+pub struct IncidentState {
+    /// Layout the panels were loaded from or last saved as
+    pub layout_name: Option<String>,
+    /// Pinned panels in display order
+    pub panels: Vec<IncidentPanelState>,
+    /// Panel with keyboard focus
+    pub focused: usize,
+    /// Last time the panels were refreshed (None forces a refresh)
+    pub last_refresh: Option<std::time::Instant>,
+}
+
 impl App {
     /// Create App from pre-initialized components (used with splash screen)
     #[allow(clippy::too_many_arguments)]
@@ -340,7 +775,7 @@ impl App {
         clients: AwsClients,
         profile: String,
         region: String,
-        available_profiles: Vec<String>,
+        available_profiles: Vec<ProfileInfo>,
         available_regions: Vec<String>,
         initial_items: Vec<Value>,
         config: Config,
@@ -348,6 +783,7 @@ impl App {
         endpoint_url: Option<String>,
     ) -> Self {
         let filtered_items = initial_items.clone();
+        let redacted = config.redaction.enabled;
 
         Self {
             clients,
@@ -372,12 +808,19 @@ impl App {
             available_regions,
             profiles_selected: 0,
             regions_selected: 0,
+            contexts_selected: 0,
+            pending_context: None,
             pending_action: None,
+            marked_ids: HashSet::new(),
+            requester_pays_buckets: HashMap::new(),
             loading: false,
             error_message: None,
             describe_scroll: 0,
             describe_data: None,
             last_action_display_name: None,
+            describe_masked_fields: Vec::new(),
+            describe_revealed: false,
+            describe_reference_selected: None,
             describe_search_text: String::new(),
             describe_search_active: false,
             describe_match_lines: Vec::new(),
@@ -386,6 +829,7 @@ impl App {
             config,
             last_key_press: None,
             readonly,
+            redacted,
             warning_message: None,
             endpoint_url,
             sso_state: None,
@@ -395,14 +839,52 @@ impl App {
             pagination: PaginationState::default(),
             log_tail_state: None,
             ssm_connect_request: None,
+            external_request: None,
+            picker_request: None,
+            form_state: None,
+            cell_view: None,
+            columns_editor: None,
+            incident: IncidentState::default(),
+            change_calendar: None,
+            live_refresh: None,
+            cell_retry: None,
+            permission_cache: PermissionCache::default(),
+            capabilities: CapabilityState::default(),
+            fetch_progress: Arc::new(Mutex::new(None)),
+            tabs: vec![None],
+            active_tab: 0,
+            watched_jobs: Vec::new(),
+            job_clients: HashMap::new(),
+            background_tasks: Vec::new(),
+            jobs_polled_at: std::time::Instant::now(),
+            awaiting_approval: approval::load_pending(),
+            approvals_polled_at: std::time::Instant::now(),
             fuzzy_matcher: SkimMatcherV2::default().ignore_case(),
         }
     }
 
     /// Check if auto-refresh is needed
-    /// Auto-refresh is disabled - use 'R' to manually refresh
+    /// Auto-refresh is disabled - use 'R' to manually refresh - except while
+    /// a row has an in-progress status of one of the view's action watches
+    /// (e.g., a secret rotation or a nodegroup update)
     pub fn needs_refresh(&self) -> bool {
-        false
+        self.mode == Mode::Normal
+            && !self.loading
+            && self.last_refresh.elapsed() >= JOB_REFRESH_INTERVAL
+            && self.current_resource().is_some_and(|resource| {
+                let watches: Vec<&crate::resource::WatchDef> = resource
+                    .actions
+                    .iter()
+                    .filter_map(|action| action.watch.as_ref())
+                    .collect();
+                self.items.iter().any(|item| {
+                    watches.iter().any(|watch| {
+                        watch
+                            .in_progress
+                            .contains(&extract_json_value(item, &watch.status_field))
+                    })
+                })
+            })
     }
 
     /// Reset refresh timer
@@ -429,8 +911,16 @@ impl App {
         // Add profiles and regions commands
         commands.push("profiles".to_string());
         commands.push("regions".to_string());
+        commands.push("incident".to_string());
+        commands.push("ctx".to_string());
+        commands.extend(
+            self.config
+                .contexts
+                .iter()
+                .map(|c| format!("ctx {}", c.name)),
+        );
 
-        commands.sort();
+        sort_by_open_count(&mut commands, &self.config);
         commands
     }
 
@@ -440,12 +930,16 @@ impl App {
 
     /// Fetch data for current resource (first page or current page based on pagination state)
     pub async fn refresh_current(&mut self) -> Result<()> {
-        // Fetch the current page (uses pagination.next_token if set by next_page/prev_page)
-        self.fetch_page(self.pagination.next_token.clone()).await
+        // Refetch the page being shown; cached neighbours may be stale now
+        self.pagination.cached_pages.clear();
+        let skeleton = !self.pagination.loaded;
+        self.fetch_page(self.pagination.current_token.clone(), skeleton)
+            .await
     }
 
-    /// Fetch a specific page of resources
-    async fn fetch_page(&mut self, page_token: Option<String>) -> Result<()> {
+    /// Fetch a specific page of resources; `skeleton` when the table still
+    /// shows another view or page
+    async fn fetch_page(&mut self, page_token: Option<String>, skeleton: bool) -> Result<()> {
         if self.current_resource().is_none() {
             self.error_message = Some(format!("Unknown resource: {}", self.current_resource_key));
             return Ok(());
@@ -453,6 +947,7 @@ impl App {
 
         self.loading = true;
         self.error_message = None;
+        self.begin_fetch_progress(skeleton);
 
         // Build filters from parent context
         let filters = self.build_filters_from_context();
@@ -473,8 +968,11 @@ impl App {
                 self.apply_filter();
 
                 // Update pagination state
+                self.pagination.current_token = page_token;
                 self.pagination.has_more = result.next_token.is_some();
                 self.pagination.next_token = result.next_token;
+                self.pagination.loaded = true;
+                self.schedule_cell_retry(0);
 
                 // Try to keep the same selection index
                 if prev_selected < self.filtered_items.len() {
@@ -484,33 +982,165 @@ impl App {
                 }
             }
             Err(e) => {
-                self.error_message = Some(aws::client::format_aws_error(&e));
+                let mut message = aws::client::format_aws_error(&e);
+                if let (Some(_), Some(identity), Some(resource)) = (
+                    self.denied_resource(&self.current_resource_key),
+                    &self.capabilities.identity,
+                    self.current_resource(),
+                ) {
+                    message = format!(
+                        "{} ({} has no {} access)",
+                        message, identity, resource.service
+                    );
+                }
+                self.error_message = Some(message);
                 // Clear items to prevent mismatch between current_resource_key and stale items
                 self.items.clear();
                 self.filtered_items.clear();
                 self.selected = 0;
                 self.pagination = PaginationState::default();
+                self.cell_retry = None;
+                self.offer_requester_pays(&filters, &e);
             }
         }
 
+        self.end_fetch_progress();
         self.loading = false;
         self.mark_refreshed();
         Ok(())
     }
 
+    /// Publish the fetch that is starting so the draw loop can show it
+    fn begin_fetch_progress(&self, skeleton: bool) {
+        let Some(resource) = self.current_resource() else {
+            return;
+        };
+        let show_filter =
+            self.filter_active || !self.filter_text.is_empty() || self.aws_filters.is_some();
+        let progress = FetchProgress {
+            activity: format!("Loading {}", resource.display_name),
+            resource: resource.display_name.clone(),
+            columns: self.visible_columns(),
+            formatter: (&self.config.formatting).into(),
+            crumb: self.get_breadcrumb().join(" > "),
+            table_offset: u16::from(self.tabs.len() > 1) + u16::from(show_filter),
+            skeleton: skeleton && self.mode == Mode::Normal,
+            rows: Vec::new(),
+            selected: None,
+            loaded: Some(if skeleton { 0 } else { self.items.len() }),
+            started: std::time::Instant::now(),
+        };
+        if let Ok(mut slot) = self.fetch_progress.lock() {
+            *slot = Some(progress);
+        }
+    }
+
+    /// Publish a long-running call that isn't a listing (e.g., a form action)
+    fn begin_activity(&self, activity: String) {
+        let progress = FetchProgress {
+            activity,
+            resource: String::new(),
+            columns: Vec::new(),
+            formatter: (&self.config.formatting).into(),
+            crumb: self.get_breadcrumb().join(" > "),
+            table_offset: 0,
+            skeleton: false,
+            rows: Vec::new(),
+            selected: None,
+            loaded: None,
+            started: std::time::Instant::now(),
+        };
+        if let Ok(mut slot) = self.fetch_progress.lock() {
+            *slot = Some(progress);
+        }
+    }
+
+    /// Count items that arrived while the fetch continues
+    fn add_fetch_progress(&self, count: usize) {
+        if let Ok(mut slot) = self.fetch_progress.lock() {
+            if let Some(loaded) = slot.as_mut().and_then(|p| p.loaded.as_mut()) {
+                *loaded += count;
+            }
+        }
+    }
+
+    /// Hand the filtered rows to the draw loop so the table fills in page by
+    /// page, with skeleton rows below it until the last page arrives
+    fn stream_fetch_rows(&self) {
+        if self.mode != Mode::Normal {
+            return;
+        }
+        let start = self.selected.saturating_sub(FETCH_STREAM_ROWS / 2);
+        let rows: Vec<Value> = self
+            .filtered_items
+            .iter()
+            .skip(start)
+            .take(FETCH_STREAM_ROWS)
+            .cloned()
+            .collect();
+        let selected = (self.selected < self.filtered_items.len()).then(|| self.selected - start);
+        if let Ok(mut slot) = self.fetch_progress.lock() {
+            if let Some(progress) = slot.as_mut() {
+                progress.skeleton = true;
+                progress.rows = rows;
+                progress.selected = selected;
+            }
+        }
+    }
+
+    fn end_fetch_progress(&self) {
+        if let Ok(mut slot) = self.fetch_progress.lock() {
+            *slot = None;
+        }
+    }
+
+    /// Ask once per bucket whether to retry a denied object listing as a
+    /// Requester Pays request, since other accounts are denied without the header
+    fn offer_requester_pays(&mut self, filters: &[ResourceFilter], error: &anyhow::Error) {
+        if self.current_resource_key != "s3-objects"
+            || self.mode != Mode::Normal
+            || !error.to_string().contains("AccessDenied")
+        {
+            return;
+        }
+        let Some(bucket) = s3_bucket_filter(filters) else {
+            return;
+        };
+        if self.requester_pays_buckets.contains_key(&bucket) {
+            return;
+        }
+        self.requester_pays_buckets.insert(bucket.clone(), false);
+
+        self.pending_action = Some(PendingAction {
+            service: "s3".to_string(),
+            sdk_method: "list_objects_v2".to_string(),
+            resource_ids: vec![bucket.clone()],
+            message: format!(
+                "Access to '{}' denied. Retry as Requester Pays? Request and transfer charges are billed to your account.",
+                bucket
+            ),
+            default_no: true,
+            destructive: false,
+            selected_yes: false,
+            requires_approval: false,
+            result_title: None,
+            sensitive_fields: Vec::new(),
+            requester_pays_bucket: Some(bucket),
+            form: None,
+            watch: None,
+        });
+        self.mode = Mode::Confirm;
+    }
+
     /// Fetch next page of resources
     pub async fn next_page(&mut self) -> Result<()> {
         if !self.pagination.has_more {
             return Ok(());
         }
 
-        // Save current token to stack for going back
-        let current_token = self.pagination.next_token.clone();
-        self.pagination.token_stack.push(current_token.clone());
-        self.pagination.current_page += 1;
-
-        // Fetch next page
-        self.fetch_page(current_token).await
+        self.pagination.cache_current(&self.items);
+        let token = self.pagination.advance();
+        self.show_page(token).await
     }
 
     /// Fetch previous page of resources
@@ -519,13 +1149,133 @@ impl App {
             return Ok(());
         }
 
-        // Pop the previous token from stack
-        self.pagination.token_stack.pop(); // Remove current page's token
-        let prev_token = self.pagination.token_stack.pop().flatten(); // Get previous page's token
-        self.pagination.current_page -= 1;
+        self.pagination.cache_current(&self.items);
+        let token = self.pagination.go_back();
+        self.show_page(token).await
+    }
+
+    /// Append the remaining pages to the current one, stopping after
+    /// `pagination.fetch_all_limit` new items so huge listings aren't pulled by accident
+    pub async fn fetch_remaining_pages(&mut self) -> Result<()> {
+        if !self.pagination.has_more {
+            return Ok(());
+        }
+
+        self.loading = true;
+        self.error_message = None;
+        self.begin_fetch_progress(false);
+        self.stream_fetch_rows();
+        let filters = self.build_filters_from_context();
+        let limit = self.config.pagination.fetch_all_limit.max(1);
+        let prev_selected = self.selected;
+        let mut loaded = 0;
+        let mut next_token = self.pagination.next_token.clone();
+        while loaded < limit {
+            let Some(token) = next_token.clone() else {
+                break;
+            };
+            match fetch_resources_paginated(
+                &self.current_resource_key,
+                &self.clients,
+                &filters,
+                Some(&token),
+            )
+            .await
+            {
+                Ok(result) => {
+                    loaded += result.items.len();
+                    self.add_fetch_progress(result.items.len());
+                    self.items.extend(result.items);
+                    next_token = result.next_token;
+                    self.apply_filter();
+                    if prev_selected < self.filtered_items.len() {
+                        self.selected = prev_selected;
+                    }
+                    self.stream_fetch_rows();
+                }
+                Err(e) => {
+                    // Keep what was loaded; pressing again resumes from the failed page
+                    self.error_message = Some(aws::client::format_aws_error(&e));
+                    break;
+                }
+            }
+        }
+        self.pagination.absorb_following(next_token);
+        self.schedule_cell_retry(0);
+
+        self.apply_filter();
+        if prev_selected < self.filtered_items.len() {
+            self.selected = prev_selected;
+        }
+        self.end_fetch_progress();
+        self.loading = false;
+        Ok(())
+    }
+
+    /// Show the current page from the page cache, or fetch it with `token`
+    async fn show_page(&mut self, token: Option<String>) -> Result<()> {
+        let Some(page) = self.pagination.take_cached() else {
+            return self.fetch_page(token, true).await;
+        };
+        self.items = page.items;
+        self.error_message = None;
+        self.schedule_cell_retry(0);
+        self.apply_filter();
+        if self.selected >= self.filtered_items.len() {
+            self.selected = 0;
+        }
+        Ok(())
+    }
+
+    /// Plan a retry of the current page's failed cells (none if nothing failed
+    /// or every retry was used up)
+    fn schedule_cell_retry(&mut self, attempt: usize) {
+        self.cell_retry = CELL_RETRY_DELAYS
+            .get(attempt)
+            .filter(|_| has_cell_errors(&self.items))
+            .map(|delay| CellRetry {
+                attempt,
+                due: std::time::Instant::now() + *delay,
+            });
+    }
+
+    /// Whether failed cells of the current page are due for a retry
+    pub fn cell_retry_due(&self) -> bool {
+        self.mode == Mode::Normal
+            && !self.loading
+            && self
+                .cell_retry
+                .as_ref()
+                .is_some_and(|retry| retry.due <= std::time::Instant::now())
+    }
 
-        // Fetch previous page
-        self.fetch_page(prev_token).await
+    /// Refetch the current page in the background and fill in the cells that
+    /// failed to load; other cells and the selection stay as they are
+    pub async fn retry_failed_cells(&mut self) {
+        let Some(retry) = self.cell_retry.take() else {
+            return;
+        };
+        let Some(resource) = self.current_resource() else {
+            return;
+        };
+        let filters = self.build_filters_from_context();
+        match fetch_resources_paginated(
+            &self.current_resource_key,
+            &self.clients,
+            &filters,
+            self.pagination.current_token.as_deref(),
+        )
+        .await
+        {
+            Ok(result) => {
+                merge_retried_cells(&mut self.items, &result.items, &resource.id_field);
+                let selected = self.selected;
+                self.apply_filter();
+                self.selected = selected.min(self.filtered_items.len().saturating_sub(1));
+            }
+            Err(e) => tracing::warn!("Failed to retry cells that failed to load: {}", e),
+        }
+        self.schedule_cell_retry(retry.attempt + 1);
     }
 
     /// Reset pagination state (call when navigating to new resource)
@@ -540,24 +1290,7 @@ impl App {
 
         // Add AWS API filters if present (unified filter system)
         if let Some(ref aws_filters) = self.aws_filters {
-            for (key, value) in &aws_filters.filters {
-                // Special handling for "owner" - uses Owner.N param, not Filter
-                if key.to_lowercase() == "owner" {
-                    filters.push(ResourceFilter::new(
-                        &format!("owner:{}", value),
-                        vec![value.clone()],
-                    ));
-                } else if key.starts_with("tag:") {
-                    // Tag filters: tag:Key=Value -> Filter.N.Name=tag:Key, Filter.N.Value.1=Value
-                    filters.push(ResourceFilter::new(key, vec![value.clone()]));
-                } else {
-                    // Regular filters: key=value -> Filter.N.Name=key, Filter.N.Value.1=value
-                    filters.push(ResourceFilter::new(
-                        &format!("filter:{}", key),
-                        vec![value.clone()],
-                    ));
-                }
-            }
+            filters.extend(aws_filters.to_resource_filters());
         }
 
         let Some(parent) = &self.parent_context else {
@@ -571,9 +1304,10 @@ impl App {
         // For S3 objects, we need to collect filters from entire navigation stack
         // to preserve bucket_names while adding prefix
         if self.current_resource_key == "s3-objects" {
-            // First, check navigation stack for bucket_names (from s3-buckets -> s3-objects)
+            // First, check navigation stack for bucket_names (from s3-buckets or any
+            // other resource linking to s3-objects)
             for ctx in &self.navigation_stack {
-                if ctx.resource_key == "s3-buckets" {
+                if ctx.resource_key != "s3-objects" {
                     if let Some(parent_resource) = get_resource(&ctx.resource_key) {
                         for sub in &parent_resource.sub_resources {
                             if sub.resource_key == "s3-objects" {
@@ -591,8 +1325,8 @@ impl App {
                 }
             }
 
-            // If parent is s3-buckets, get bucket_names from it
-            if parent.resource_key == "s3-buckets" {
+            // If parent links to s3-objects by bucket (e.g., s3-buckets), get bucket_names from it
+            if parent.resource_key != "s3-objects" {
                 if let Some(parent_resource) = get_resource(&parent.resource_key) {
                     for sub in &parent_resource.sub_resources {
                         if sub.resource_key == "s3-objects" {
@@ -626,6 +1360,16 @@ impl App {
                 }
             }
 
+            // Bill requests to the caller for buckets accepted as Requester Pays
+            if let Some(bucket) = s3_bucket_filter(&filters) {
+                if self.requester_pays_buckets.get(&bucket) == Some(&true) {
+                    filters.push(ResourceFilter::new(
+                        "requester_pays",
+                        vec!["true".to_string()],
+                    ));
+                }
+            }
+
             return filters;
         }
 
@@ -707,6 +1451,21 @@ impl App {
             self.filtered_items = scored_items.into_iter().map(|(_, item)| item).collect();
         }
 
+        // A sort chosen for the view wins over API and match order
+        let sort_column = self.current_resource().and_then(|resource| {
+            let sort = self
+                .config
+                .views
+                .get(&self.current_resource_key)?
+                .sort
+                .as_ref()?;
+            let col = resource.columns.iter().find(|c| c.header == sort.column)?;
+            Some((col.json_path.as_str(), sort.descending))
+        });
+        if let Some((json_path, descending)) = sort_column {
+            sort_rows(&mut self.filtered_items, json_path, descending);
+        }
+
         // Adjust selection
         if self.selected >= self.filtered_items.len() && !self.filtered_items.is_empty() {
             self.selected = self.filtered_items.len() - 1;
@@ -788,6 +1547,10 @@ impl App {
     pub fn selected_item_json(&self) -> Option<String> {
         // Use describe_data if available (full details), otherwise fall back to list data
         if let Some(ref data) = self.describe_data {
+            if !self.describe_revealed && !self.describe_masked_fields.is_empty() {
+                let masked = mask_fields(data, &self.describe_masked_fields);
+                return Some(serde_json::to_string_pretty(&masked).unwrap_or_default());
+            }
             return Some(serde_json::to_string_pretty(data).unwrap_or_default());
         }
         self.selected_item()
@@ -827,13 +1590,94 @@ impl App {
         self.describe_scroll = total.saturating_sub(visible_lines);
     }
 
-    /// Clear describe search
-    pub fn clear_describe_search(&mut self) {
-        self.describe_search_text.clear();
-        self.describe_search_active = false;
-        self.describe_match_lines.clear();
-        self.describe_current_match = 0;
-    }
+    /// IDs and ARNs of other resources in the describe content (not the item itself)
+    pub fn describe_references(&self) -> Vec<ResourceRef> {
+        let Some(json) = self.selected_item_json() else {
+            return Vec::new();
+        };
+        let own = self
+            .current_resource()
+            .zip(self.selected_item())
+            .filter(|_| self.last_action_display_name.is_none());
+        find_references(&json)
+            .into_iter()
+            .filter(|reference| match own {
+                Some((resource, item)) => {
+                    reference.resource_key != self.current_resource_key
+                        || !row_matches_target(item, resource, &reference.target)
+                }
+                None => true,
+            })
+            .collect()
+    }
+
+    /// Highlight the next (or previous) link in the describe view and scroll to it
+    pub fn describe_cycle_reference(&mut self, forward: bool) {
+        let references = self.describe_references();
+        if references.is_empty() {
+            self.error_message = Some("No linked resources in this document".to_string());
+            return;
+        }
+        let count = references.len();
+        let index = match (self.describe_reference_selected, forward) {
+            (None, true) => 0,
+            (None, false) => count - 1,
+            (Some(i), true) => (i + 1) % count,
+            (Some(i), false) => (i + count - 1) % count,
+        };
+        self.describe_reference_selected = Some(index);
+        self.describe_scroll = references[index].line;
+    }
+
+    /// Open the highlighted link: list its resource type, select it and describe it
+    pub async fn follow_describe_reference(&mut self) -> Result<()> {
+        let Some(reference) = self
+            .describe_reference_selected
+            .and_then(|i| self.describe_references().into_iter().nth(i))
+        else {
+            return Ok(());
+        };
+        if let Some(region) = reference.region.as_ref().filter(|r| **r != self.region) {
+            self.error_message = Some(format!(
+                "{} is in {}; switch region to open it",
+                reference.value, region
+            ));
+            return Ok(());
+        }
+
+        self.clear_describe_search();
+        self.exit_mode();
+        // Filters of the current view don't apply to the linked resource
+        self.aws_filters = None;
+        self.navigate_to_resource(reference.resource_key).await?;
+
+        let Some(resource) = self.current_resource() else {
+            return Ok(());
+        };
+        match self
+            .filtered_items
+            .iter()
+            .position(|item| row_matches_target(item, resource, &reference.target))
+        {
+            Some(index) => {
+                self.selected = index;
+                self.enter_describe_mode().await;
+            }
+            None => self.show_warning(&format!(
+                "{} not found in {}",
+                reference.target, resource.display_name
+            )),
+        }
+        Ok(())
+    }
+
+    /// Clear describe search
+    pub fn clear_describe_search(&mut self) {
+        self.describe_search_text.clear();
+        self.describe_search_active = false;
+        self.describe_match_lines.clear();
+        self.describe_current_match = 0;
+    }
 
     /// Update describe search matches
     pub fn update_describe_search(&mut self) {
@@ -897,6 +1741,12 @@ impl App {
                         (self.regions_selected + 1).min(self.available_regions.len() - 1);
                 }
             }
+            Mode::Contexts => {
+                if !self.config.contexts.is_empty() {
+                    self.contexts_selected =
+                        (self.contexts_selected + 1).min(self.config.contexts.len() - 1);
+                }
+            }
             _ => {
                 if !self.filtered_items.is_empty() {
                     self.selected = (self.selected + 1).min(self.filtered_items.len() - 1);
@@ -913,6 +1763,9 @@ impl App {
             Mode::Regions => {
                 self.regions_selected = self.regions_selected.saturating_sub(1);
             }
+            Mode::Contexts => {
+                self.contexts_selected = self.contexts_selected.saturating_sub(1);
+            }
             _ => {
                 self.selected = self.selected.saturating_sub(1);
             }
@@ -923,6 +1776,7 @@ impl App {
         match self.mode {
             Mode::Profiles => self.profiles_selected = 0,
             Mode::Regions => self.regions_selected = 0,
+            Mode::Contexts => self.contexts_selected = 0,
             _ => self.selected = 0,
         }
     }
@@ -939,6 +1793,11 @@ impl App {
                     self.regions_selected = self.available_regions.len() - 1;
                 }
             }
+            Mode::Contexts => {
+                if !self.config.contexts.is_empty() {
+                    self.contexts_selected = self.config.contexts.len() - 1;
+                }
+            }
             _ => {
                 if !self.filtered_items.is_empty() {
                     self.selected = self.filtered_items.len() - 1;
@@ -961,6 +1820,12 @@ impl App {
                         (self.regions_selected + page_size).min(self.available_regions.len() - 1);
                 }
             }
+            Mode::Contexts => {
+                if !self.config.contexts.is_empty() {
+                    self.contexts_selected =
+                        (self.contexts_selected + page_size).min(self.config.contexts.len() - 1);
+                }
+            }
             _ => {
                 if !self.filtered_items.is_empty() {
                     self.selected = (self.selected + page_size).min(self.filtered_items.len() - 1);
@@ -977,6 +1842,9 @@ impl App {
             Mode::Regions => {
                 self.regions_selected = self.regions_selected.saturating_sub(page_size);
             }
+            Mode::Contexts => {
+                self.contexts_selected = self.contexts_selected.saturating_sub(page_size);
+            }
             _ => {
                 self.selected = self.selected.saturating_sub(page_size);
             }
@@ -1060,6 +1928,230 @@ impl App {
         self.mode = Mode::Help;
     }
 
+    /// Pop the selected row's longest value (the one most likely truncated) into a popup
+    pub fn enter_cell_view_mode(&mut self) {
+        let Some(item) = self.selected_item() else {
+            return;
+        };
+        let columns = self.visible_columns();
+        // A cell that failed to load is what needs explaining
+        let failed = columns
+            .iter()
+            .position(|col| cell_error(item, &col.json_path).is_some());
+        let column = failed
+            .or_else(|| {
+                columns
+                    .iter()
+                    .enumerate()
+                    .max_by_key(|(i, col)| {
+                        (
+                            extract_json_value(item, &col.json_path).chars().count(),
+                            std::cmp::Reverse(*i),
+                        )
+                    })
+                    .map(|(i, _)| i)
+            })
+            .unwrap_or(0);
+        self.cell_view = Some(CellViewState {
+            column,
+            ..Default::default()
+        });
+        self.mode = Mode::CellView;
+    }
+
+    /// Header and full value of the expanded cell (JSON documents are pretty-printed)
+    pub fn cell_view_content(&self) -> Option<(String, String)> {
+        let state = self.cell_view.as_ref()?;
+        let col = self.visible_columns().into_iter().nth(state.column)?;
+        let item = self.selected_item()?;
+        if let Some(reason) = cell_error(item, &col.json_path) {
+            return Some((
+                format!("{} (failed to load)", col.header),
+                format!(
+                    "{}\n\nRetried automatically in the background; R reloads the page.",
+                    reason
+                ),
+            ));
+        }
+        let value = extract_json_value(item, &col.json_path);
+        Some((col.header, expand_cell_value(&value)))
+    }
+
+    /// Show the next or previous column of the selected row
+    pub fn cell_view_cycle_column(&mut self, forward: bool) {
+        let count = self.visible_columns().len();
+        let Some(state) = self.cell_view.as_mut() else {
+            return;
+        };
+        if count == 0 {
+            return;
+        }
+        state.column = if forward {
+            (state.column + 1) % count
+        } else {
+            (state.column + count - 1) % count
+        };
+        state.scroll = 0;
+        state.copied = false;
+    }
+
+    pub fn cell_view_scroll_down(&mut self, amount: usize) {
+        // Estimate the wrapped line count (~100 columns wide, ~20 lines visible)
+        let max_scroll = self
+            .cell_view_content()
+            .map(|(_, value)| {
+                let lines: usize = value
+                    .lines()
+                    .map(|line| line.chars().count().div_ceil(100).max(1))
+                    .sum();
+                lines.saturating_sub(20)
+            })
+            .unwrap_or(0);
+        if let Some(state) = self.cell_view.as_mut() {
+            state.scroll = state.scroll.saturating_add(amount).min(max_scroll);
+        }
+    }
+
+    pub fn cell_view_scroll_up(&mut self, amount: usize) {
+        if let Some(state) = self.cell_view.as_mut() {
+            state.scroll = state.scroll.saturating_sub(amount);
+        }
+    }
+
+    /// Copy the expanded cell's value to the clipboard
+    pub fn copy_cell_value(&mut self) {
+        let Some((_, value)) = self.cell_view_content() else {
+            return;
+        };
+        match crate::clipboard::copy(&value) {
+            Ok(()) => {
+                if let Some(state) = self.cell_view.as_mut() {
+                    state.copied = true;
+                }
+            }
+            Err(e) => self.error_message = Some(format!("Failed to copy: {}", e)),
+        }
+    }
+
+    // =========================================================================
+    // View Columns (visibility, width and sort, persisted per resource type)
+    // =========================================================================
+
+    /// Column customizations of the current resource type
+    pub fn current_view(&self) -> ViewConfig {
+        self.config
+            .views
+            .get(&self.current_resource_key)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Columns shown for the current resource, with customized widths
+    pub fn visible_columns(&self) -> Vec<ColumnDef> {
+        self.current_resource()
+            .map(|resource| self.current_view().columns(&resource.columns))
+            .unwrap_or_default()
+    }
+
+    pub fn enter_columns_mode(&mut self) {
+        if self
+            .current_resource()
+            .is_some_and(|r| !r.columns.is_empty())
+        {
+            self.columns_editor = Some(ColumnsEditorState::default());
+            self.mode = Mode::Columns;
+        }
+    }
+
+    /// Column under the editor's cursor
+    fn selected_editor_column(&self) -> Option<&'static ColumnDef> {
+        let state = self.columns_editor.as_ref()?;
+        self.current_resource()?.columns.get(state.selected)
+    }
+
+    pub fn columns_editor_move(&mut self, forward: bool) {
+        let count = self
+            .current_resource()
+            .map(|r| r.columns.len())
+            .unwrap_or(0);
+        if let Some(state) = self.columns_editor.as_mut() {
+            state.selected = if forward {
+                (state.selected + 1).min(count.saturating_sub(1))
+            } else {
+                state.selected.saturating_sub(1)
+            };
+        }
+    }
+
+    /// Show or hide the selected column (the last shown column can't be hidden)
+    pub fn toggle_column_visibility(&mut self) {
+        let Some(col) = self.selected_editor_column() else {
+            return;
+        };
+        if self.visible_columns().len() == 1 && !self.current_view().is_hidden(&col.header) {
+            return;
+        }
+        self.update_view(|view| {
+            if view.is_hidden(&col.header) {
+                view.hidden.retain(|h| h != &col.header);
+            } else {
+                view.hidden.push(col.header.clone());
+            }
+        });
+    }
+
+    /// Sort by the selected column: ascending, then descending, then unsorted
+    pub fn cycle_column_sort(&mut self) {
+        let Some(col) = self.selected_editor_column() else {
+            return;
+        };
+        self.update_view(|view| {
+            view.sort = match view.sort.take() {
+                Some(sort) if sort.column == col.header && !sort.descending => Some(ViewSort {
+                    descending: true,
+                    ..sort
+                }),
+                Some(sort) if sort.column == col.header => None,
+                _ => Some(ViewSort {
+                    column: col.header.clone(),
+                    descending: false,
+                }),
+            };
+        });
+    }
+
+    /// Widen (positive) or narrow (negative) the selected column by `delta` percent
+    pub fn resize_column(&mut self, delta: i16) {
+        let Some(col) = self.selected_editor_column() else {
+            return;
+        };
+        self.update_view(|view| {
+            let current = view.widths.get(&col.header).copied().unwrap_or(col.width);
+            let width = current.saturating_add_signed(delta).clamp(3, 100);
+            if width == col.width {
+                view.widths.remove(&col.header);
+            } else {
+                view.widths.insert(col.header.clone(), width);
+            }
+        });
+    }
+
+    /// Drop all customizations of the current view
+    pub fn reset_view(&mut self) {
+        self.update_view(|view| *view = ViewConfig::default());
+    }
+
+    /// Change the current view's customizations, save them and re-sort the list
+    fn update_view(&mut self, change: impl FnOnce(&mut ViewConfig)) {
+        let mut view = self.current_view();
+        change(&mut view);
+        let key = self.current_resource_key.clone();
+        if let Err(e) = self.config.save_view(&key, view) {
+            self.error_message = Some(format!("Failed to save view: {}", e));
+        }
+        self.apply_filter();
+    }
+
     pub async fn enter_describe_mode(&mut self) {
         if self.filtered_items.is_empty() {
             return;
@@ -1068,6 +2160,7 @@ impl App {
         self.mode = Mode::Describe;
         self.describe_scroll = 0;
         self.describe_data = None;
+        self.describe_reference_selected = None;
 
         // Get the selected item's ID
         if let Some(item) = self.selected_item().cloned() {
@@ -1132,7 +2225,15 @@ impl App {
     }
 
     /// Enter confirmation mode for an action
-    pub fn enter_confirm_mode(&mut self, pending: PendingAction) {
+    pub fn enter_confirm_mode(&mut self, mut pending: PendingAction) {
+        if self.change_freeze_active() {
+            pending.message = format!("{} {}", self.change_freeze_message(), pending.message);
+            pending.destructive = true;
+            pending.selected_yes = false;
+        }
+        if self.config.approval.enabled && pending.requires_approval {
+            pending.message = format!("{} (requires approval)", pending.message);
+        }
         self.pending_action = Some(pending);
         self.mode = Mode::Confirm;
     }
@@ -1188,101 +2289,492 @@ impl App {
         Some(PendingAction {
             service: self.current_resource()?.service.clone(),
             sdk_method: action.sdk_method.clone(),
-            resource_id: resource_id.to_string(),
+            resource_ids: vec![resource_id.to_string()],
             message: format!("{} '{}'?", message, resource_name),
             default_no,
             destructive: config.destructive,
             selected_yes: config.default_yes, // Start with default selection
+            requires_approval: config.destructive,
+            result_title: action.show_result.then(|| action.display_name.clone()),
+            sensitive_fields: action.sensitive_fields.clone(),
+            requester_pays_bucket: None,
+            form: None,
+            watch: self.job_watch(action),
         })
     }
 
-    pub fn enter_profiles_mode(&mut self) {
-        self.profiles_selected = self
-            .available_profiles
+    /// Create a pending action covering all marked items
+    pub fn create_bulk_pending_action(
+        &self,
+        action: &crate::resource::ActionDef,
+    ) -> Option<PendingAction> {
+        if self.marked_ids.is_empty() {
+            return None;
+        }
+        let config = action.get_confirm_config()?;
+        let message = config
+            .message
+            .unwrap_or_else(|| action.display_name.clone());
+
+        // Keep list order so the dialog and execution are predictable
+        let resource_def = self.current_resource()?;
+        let resource_ids: Vec<String> = self
+            .items
             .iter()
-            .position(|p| p == &self.profile)
-            .unwrap_or(0);
-        self.mode = Mode::Profiles;
+            .map(|item| extract_json_value(item, &resource_def.id_field))
+            .filter(|id| self.marked_ids.contains(id))
+            .collect();
+
+        Some(PendingAction {
+            service: resource_def.service.clone(),
+            sdk_method: action.sdk_method.clone(),
+            message: format!("{} {} marked items?", message, resource_ids.len()),
+            resource_ids,
+            default_no: !config.default_yes,
+            destructive: config.destructive,
+            selected_yes: config.default_yes,
+            requires_approval: config.destructive,
+            result_title: None,
+            sensitive_fields: Vec::new(),
+            requester_pays_bucket: None,
+            form: None,
+            watch: self.job_watch(action),
+        })
     }
 
-    pub fn enter_regions_mode(&mut self) {
-        self.regions_selected = self
-            .available_regions
-            .iter()
-            .position(|r| r == &self.region)
-            .unwrap_or(0);
-        self.mode = Mode::Regions;
+    // =========================================================================
+    // Multi-select
+    // =========================================================================
+
+    /// Toggle the bulk-action mark on the selected item
+    pub fn toggle_mark_selected(&mut self) {
+        let Some(resource) = self.current_resource() else {
+            return;
+        };
+        let Some(item) = self.selected_item() else {
+            return;
+        };
+        let id = extract_json_value(item, &resource.id_field);
+        if id == "-" || id.is_empty() {
+            return;
+        }
+        if !self.marked_ids.remove(&id) {
+            self.marked_ids.insert(id);
+        }
     }
 
-    pub fn exit_mode(&mut self) {
-        self.mode = Mode::Normal;
-        self.pending_action = None;
-        self.describe_data = None; // Clear describe data when exiting
-        self.last_action_display_name = None;
+    /// Check whether an item is marked for bulk actions
+    pub fn is_marked(&self, item: &Value) -> bool {
+        if self.marked_ids.is_empty() {
+            return false;
+        }
+        self.current_resource()
+            .map(|r| {
+                self.marked_ids
+                    .contains(&extract_json_value(item, &r.id_field))
+            })
+            .unwrap_or(false)
+    }
+
+    pub fn clear_marks(&mut self) {
+        self.marked_ids.clear();
     }
 
     // =========================================================================
-    // Resource Navigation
+    // Action Forms
     // =========================================================================
 
-    /// Navigate to a resource (top-level)
-    pub async fn navigate_to_resource(&mut self, resource_key: &str) -> Result<()> {
-        if get_resource(resource_key).is_none() {
-            self.error_message = Some(format!("Unknown resource: {}", resource_key));
-            return Ok(());
-        }
+    /// Load the form fields for an action and open the input form
+    pub async fn enter_form_mode(&mut self, action: &ActionDef, resource_id: &str) {
+        let Some(resource) = self.current_resource() else {
+            return;
+        };
+        let service = resource.service.clone();
+        let row = self.selected_item().cloned().unwrap_or(Value::Null);
 
-        // Clear parent context when navigating to top-level resource
-        self.parent_context = None;
-        self.navigation_stack.clear();
-        self.current_resource_key = resource_key.to_string();
-        self.selected = 0;
-        self.filter_text.clear();
-        self.filter_active = false;
-        self.mode = Mode::Normal;
+        // Marked items share one form; defaults come from the selected row
+        let marked: Vec<String> = self
+            .items
+            .iter()
+            .map(|item| extract_json_value(item, &resource.id_field))
+            .filter(|id| self.marked_ids.contains(id))
+            .collect();
+        let (title, resource_ids) = if marked.is_empty() {
+            (
+                format!("{} {}", action.display_name, resource_id),
+                vec![resource_id.to_string()],
+            )
+        } else {
+            (
+                format!("{} ({} marked items)", action.display_name, marked.len()),
+                marked,
+            )
+        };
 
-        // Reset pagination for new resource
-        self.reset_pagination();
+        match crate::resource::get_form_fields(
+            &service,
+            &action.sdk_method,
+            &self.clients,
+            resource_id,
+            &row,
+            &self.filter_text,
+        )
+        .await
+        {
+            Ok(fields) => {
+                self.form_state = Some(FormState {
+                    title,
+                    service,
+                    sdk_method: action.sdk_method.clone(),
+                    resource_ids,
+                    fields,
+                    selected: 0,
+                    freeze_confirmed: false,
+                    confirm: action.get_confirm_config(),
+                    watch: self.job_watch(action),
+                });
+                self.mode = Mode::Form;
+            }
+            Err(e) => {
+                self.error_message = Some(format!("Action failed: {}", e));
+            }
+        }
+    }
 
-        self.refresh_current().await?;
-        Ok(())
+    /// Move to the next form field
+    pub fn form_next_field(&mut self) {
+        if let Some(form) = &mut self.form_state {
+            if !form.fields.is_empty() {
+                form.selected = (form.selected + 1) % form.fields.len();
+            }
+        }
     }
 
-    /// Navigate to sub-resource with parent context
-    pub async fn navigate_to_sub_resource(&mut self, sub_resource_key: &str) -> Result<()> {
-        let Some(selected_item) = self.selected_item().cloned() else {
-            return Ok(());
-        };
+    /// Move to the previous form field
+    pub fn form_previous_field(&mut self) {
+        if let Some(form) = &mut self.form_state {
+            if !form.fields.is_empty() {
+                form.selected = (form.selected + form.fields.len() - 1) % form.fields.len();
+            }
+        }
+    }
 
-        let Some(current_resource) = self.current_resource() else {
+    /// Edit the selected form field (None deletes the last character)
+    pub fn form_edit(&mut self, c: Option<char>) {
+        if let Some(field) = self
+            .form_state
+            .as_mut()
+            .and_then(|form| form.fields.get_mut(form.selected))
+        {
+            match c {
+                Some(c) => field.value.push(c),
+                None => {
+                    field.value.pop();
+                }
+            }
+        }
+    }
+
+    /// Submit the form: confirm it first when the action asks for confirmation
+    /// (where approval mode applies too), otherwise execute it
+    pub async fn submit_form(&mut self) -> Result<()> {
+        let Some(form) = self.form_state.clone() else {
             return Ok(());
         };
+        self.error_message = None;
 
-        // Verify this is a valid sub-resource
-        let is_valid = current_resource
-            .sub_resources
+        if let Some(missing) = form
+            .fields
             .iter()
-            .any(|s| s.resource_key == sub_resource_key);
+            .find(|f| f.required && f.value.trim().is_empty())
+        {
+            self.error_message = Some(format!("{} is required", missing.name));
+            return Ok(());
+        }
 
-        if !is_valid {
+        if self.change_freeze_active() && !form.freeze_confirmed {
+            if let Some(form) = &mut self.form_state {
+                form.freeze_confirmed = true;
+            }
             self.error_message = Some(format!(
-                "{} is not a sub-resource of {}",
-                sub_resource_key, self.current_resource_key
+                "{} Press Enter again to submit anyway",
+                self.change_freeze_message()
             ));
             return Ok(());
         }
 
-        // Special handling for S3 folder navigation
-        // Only allow navigating into folders, not files
-        if self.current_resource_key == "s3-objects" && sub_resource_key == "s3-objects" {
-            let is_folder = selected_item
-                .get("IsFolder")
-                .and_then(|v| v.as_bool())
-                .unwrap_or(false);
+        if let Some(config) = form.confirm.clone() {
+            let target = match form.resource_ids.as_slice() {
+                [resource_id] => format!("'{}'", resource_id),
+                ids => format!("{} marked items", ids.len()),
+            };
+            let message = config.message.unwrap_or_else(|| form.title.clone());
+            let pending = PendingAction {
+                service: form.service.clone(),
+                sdk_method: form.sdk_method.clone(),
+                resource_ids: form.resource_ids.clone(),
+                message: format!("{} {}?", message, target),
+                default_no: !config.default_yes,
+                destructive: config.destructive,
+                selected_yes: config.default_yes,
+                requires_approval: config.destructive,
+                result_title: None,
+                sensitive_fields: Vec::new(),
+                requester_pays_bucket: None,
+                watch: form.watch.clone(),
+                form: Some(form),
+            };
+            self.enter_confirm_mode(pending);
+            return Ok(());
+        }
+        self.execute_form(form).await
+    }
 
-            if !is_folder {
-                // Don't navigate into files - could show a message or do nothing
-                return Ok(());
+    /// Execute a submitted form action and refresh the current view (the form
+    /// stays open with the error when a single item fails)
+    pub async fn execute_form(&mut self, form: FormState) -> Result<()> {
+        if runs_in_background(&form.service, &form.sdk_method) {
+            for resource_id in &form.resource_ids {
+                self.spawn_form_action(
+                    &form.title,
+                    &form.service,
+                    &form.sdk_method,
+                    resource_id,
+                    &form.fields,
+                    form.watch.clone(),
+                );
+            }
+            self.exit_mode();
+            self.clear_marks();
+            self.show_warning(&format!(
+                "{} started for {}. taws reports the result when it finishes.",
+                form.title,
+                form.resource_ids.join(", ")
+            ));
+            return Ok(());
+        }
+
+        if let [resource_id] = form.resource_ids.as_slice() {
+            self.begin_activity(format!("{} · {}", form.title, resource_id));
+            let result = crate::resource::execute_form_action(
+                &form.service,
+                &form.sdk_method,
+                &self.clients,
+                resource_id,
+                &form.fields,
+            )
+            .await;
+            self.end_fetch_progress();
+            match result {
+                Ok(message) => {
+                    self.watch_job(form.watch.as_ref(), resource_id);
+                    self.exit_mode();
+                    self.refresh_current().await?;
+                    self.show_warning(&message);
+                }
+                Err(e) => {
+                    // Keep the form open so the input can be corrected
+                    self.form_state = Some(form.clone());
+                    self.mode = Mode::Form;
+                    self.error_message = Some(format!("Action failed: {}", e));
+                }
+            }
+            return Ok(());
+        }
+
+        let mut failures = Vec::new();
+        self.begin_activity(format!(
+            "{} · {} marked items",
+            form.title,
+            form.resource_ids.len()
+        ));
+        for resource_id in &form.resource_ids {
+            match crate::resource::execute_form_action(
+                &form.service,
+                &form.sdk_method,
+                &self.clients,
+                resource_id,
+                &form.fields,
+            )
+            .await
+            {
+                Ok(_) => self.watch_job(form.watch.as_ref(), resource_id),
+                Err(e) => failures.push(format!("{}: {}", resource_id, e)),
+            }
+        }
+        self.end_fetch_progress();
+        self.exit_mode();
+        self.clear_marks();
+        self.refresh_current().await?;
+        if failures.is_empty() {
+            self.show_warning(&format!(
+                "Done for {} marked items",
+                form.resource_ids.len()
+            ));
+        } else {
+            self.error_message = Some(format!(
+                "Action failed for {}/{} items: {}",
+                failures.len(),
+                form.resource_ids.len(),
+                failures.join("; ")
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn enter_profiles_mode(&mut self) {
+        self.profiles_selected = self
+            .available_profiles
+            .iter()
+            .position(|p| p.name == self.profile)
+            .unwrap_or(0);
+        self.mode = Mode::Profiles;
+    }
+
+    pub fn enter_regions_mode(&mut self) {
+        self.regions_selected = self
+            .available_regions
+            .iter()
+            .position(|r| r == &self.region)
+            .unwrap_or(0);
+        self.mode = Mode::Regions;
+    }
+
+    pub fn enter_contexts_mode(&mut self) {
+        if self.config.contexts.is_empty() {
+            self.show_warning("No contexts configured (add `contexts` to config.yaml)");
+            return;
+        }
+        let active = self.active_context().map(|c| c.name.clone());
+        self.contexts_selected = self
+            .config
+            .contexts
+            .iter()
+            .position(|c| Some(&c.name) == active.as_ref())
+            .unwrap_or(0);
+        self.mode = Mode::Contexts;
+    }
+
+    pub fn exit_mode(&mut self) {
+        self.mode = Mode::Normal;
+        self.pending_action = None;
+        self.form_state = None;
+        self.cell_view = None;
+        self.columns_editor = None;
+        self.describe_data = None; // Clear describe data when exiting
+        self.last_action_display_name = None;
+        self.describe_masked_fields.clear();
+        self.describe_revealed = false;
+        self.describe_reference_selected = None;
+    }
+
+    /// Show an action's result in the describe view, masking sensitive fields
+    pub fn show_action_result(
+        &mut self,
+        mut data: Value,
+        title: &str,
+        sensitive_fields: &[String],
+    ) {
+        // Results can name further fields to mask (e.g., secret-looking user data lines)
+        let mut sensitive_fields = sensitive_fields.to_vec();
+        if let Some(Value::Array(fields)) = data
+            .as_object_mut()
+            .and_then(|fields| fields.remove(SENSITIVE_FIELDS_FIELD))
+        {
+            sensitive_fields.extend(fields.iter().filter_map(|f| f.as_str()).map(String::from));
+        }
+        self.describe_data = Some(data);
+        self.describe_scroll = 0;
+        self.last_action_display_name = Some(title.to_string());
+        self.describe_masked_fields = sensitive_fields;
+        self.describe_revealed = false;
+        self.describe_reference_selected = None;
+        self.mode = Mode::Describe;
+    }
+
+    // =========================================================================
+    // Resource Navigation
+    // =========================================================================
+
+    /// Navigate to a resource (top-level)
+    pub async fn navigate_to_resource(&mut self, resource_key: &str) -> Result<()> {
+        if get_resource(resource_key).is_none() {
+            self.error_message = Some(format!("Unknown resource: {}", resource_key));
+            return Ok(());
+        }
+
+        if let Err(e) = self.config.record_resource_open(resource_key) {
+            tracing::warn!("Failed to save resource usage to config: {}", e);
+        }
+
+        // Clear parent context when navigating to top-level resource
+        self.parent_context = None;
+        self.clear_marks();
+        self.navigation_stack.clear();
+        self.current_resource_key = resource_key.to_string();
+        self.selected = 0;
+        self.filter_text.clear();
+        self.filter_active = false;
+        self.mode = Mode::Normal;
+
+        // Reset pagination for new resource
+        self.reset_pagination();
+
+        self.refresh_current().await?;
+        Ok(())
+    }
+
+    /// Navigate to sub-resource with parent context
+    pub async fn navigate_to_sub_resource(&mut self, sub_resource_key: &str) -> Result<()> {
+        let Some(selected_item) = self.selected_item().cloned() else {
+            return Ok(());
+        };
+
+        let Some(current_resource) = self.current_resource() else {
+            return Ok(());
+        };
+
+        // Verify this is a valid sub-resource
+        let is_valid = current_resource
+            .sub_resources
+            .iter()
+            .any(|s| s.resource_key == sub_resource_key);
+
+        if !is_valid {
+            self.error_message = Some(format!(
+                "{} is not a sub-resource of {}",
+                sub_resource_key, self.current_resource_key
+            ));
+            return Ok(());
+        }
+
+        // Don't navigate when the selected row has no value to filter by
+        // (e.g., a mixed-type view where only some rows link to this resource)
+        if let Some(sub) = current_resource
+            .sub_resources
+            .iter()
+            .find(|s| s.resource_key == sub_resource_key)
+        {
+            if extract_json_value(&selected_item, &sub.parent_id_field) == "-" {
+                self.error_message = Some(format!(
+                    "{} is not available for the selected item",
+                    sub.display_name
+                ));
+                return Ok(());
+            }
+        }
+
+        // Special handling for S3 folder navigation
+        // Only allow navigating into folders, not files
+        if self.current_resource_key == "s3-objects" && sub_resource_key == "s3-objects" {
+            let is_folder = selected_item
+                .get("IsFolder")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false);
+
+            if !is_folder {
+                // Don't navigate into files - could show a message or do nothing
+                return Ok(());
             }
         }
 
@@ -1310,6 +2802,7 @@ impl App {
 
         // Navigate
         self.current_resource_key = sub_resource_key.to_string();
+        self.clear_marks();
         self.selected = 0;
         self.filter_text.clear();
         self.filter_active = false;
@@ -1321,6 +2814,100 @@ impl App {
         Ok(())
     }
 
+    /// Jump to the resource named by the selected row's TargetResource/TargetName
+    /// fields (e.g., the instance behind a CloudWatch alarm) and pre-select it
+    pub async fn goto_target_resource(&mut self) -> Result<()> {
+        let Some(item) = self.selected_item() else {
+            return Ok(());
+        };
+        let resource_key = extract_json_value(item, "TargetResource");
+        let target_name = extract_json_value(item, "TargetName");
+        if resource_key == "-" || target_name == "-" {
+            self.error_message = Some("No linked resource for the selected item".to_string());
+            return Ok(());
+        }
+
+        // Filters of the current view don't apply to the target resource
+        self.aws_filters = None;
+        self.navigate_to_resource(&resource_key).await?;
+
+        let Some(resource) = self.current_resource() else {
+            return Ok(());
+        };
+        match self
+            .filtered_items
+            .iter()
+            .position(|item| row_matches_target(item, resource, &target_name))
+        {
+            Some(index) => self.selected = index,
+            None => self.show_warning(&format!(
+                "{} not found in {}",
+                target_name, resource.display_name
+            )),
+        }
+        Ok(())
+    }
+
+    /// Jump to the SQS queue named by the selected row's DeadLetterArn (a Lambda
+    /// function's or SNS subscription's DLQ), select it and peek at its messages
+    pub async fn view_dead_letter_queue(&mut self) -> Result<()> {
+        let Some(item) = self.selected_item() else {
+            return Ok(());
+        };
+        let arn = extract_json_value(item, "DeadLetterArn");
+        let Some((region, queue_name)) = sqs_queue_from_arn(&arn) else {
+            self.error_message = Some(if arn == "-" {
+                "No dead-letter queue configured for the selected item".to_string()
+            } else {
+                format!("Dead-letter target {} is not an SQS queue", arn)
+            });
+            return Ok(());
+        };
+        if region != self.region {
+            self.error_message = Some(format!(
+                "Dead-letter queue {} is in {}; switch region to open it",
+                queue_name, region
+            ));
+            return Ok(());
+        }
+
+        self.aws_filters = Some(AwsFilters {
+            filters: vec![("prefix".to_string(), queue_name.clone())],
+        });
+        self.navigate_to_resource("sqs-queues").await?;
+
+        let Some(resource) = self.current_resource() else {
+            return Ok(());
+        };
+        let Some(index) = self
+            .filtered_items
+            .iter()
+            .position(|item| row_matches_target(item, resource, &queue_name))
+        else {
+            self.show_warning(&format!("{} not found in SQS Queues", queue_name));
+            return Ok(());
+        };
+        self.selected = index;
+
+        let queue_url = extract_json_value(&self.filtered_items[index], "QueueUrl");
+        match crate::resource::execute_action_with_result(
+            "sqs",
+            "peek_messages",
+            &self.clients,
+            &queue_url,
+        )
+        .await
+        {
+            Ok(data) => self.show_action_result(
+                data,
+                &format!("DLQ {} (q: back to queue, r: redrive)", queue_name),
+                &[],
+            ),
+            Err(e) => self.error_message = Some(format!("Action failed: {}", e)),
+        }
+        Ok(())
+    }
+
     /// Navigate back to parent resource
     pub async fn navigate_back(&mut self) -> Result<()> {
         if let Some(parent) = self.parent_context.take() {
@@ -1329,6 +2916,7 @@ impl App {
 
             // Navigate to parent resource
             self.current_resource_key = parent.resource_key;
+            self.clear_marks();
             self.selected = parent.saved_selected;
             self.filter_text.clear();
             self.filter_active = false;
@@ -1393,6 +2981,101 @@ impl App {
         Ok(())
     }
 
+    // =========================================================================
+    // Tabs
+    // =========================================================================
+
+    /// Exchange the active view and credentials with a stored tab
+    fn swap_tab_state(&mut self, tab: &mut Tab) {
+        std::mem::swap(&mut self.clients, &mut tab.clients);
+        std::mem::swap(&mut self.profile, &mut tab.profile);
+        std::mem::swap(&mut self.region, &mut tab.region);
+        std::mem::swap(
+            &mut self.current_resource_key,
+            &mut tab.current_resource_key,
+        );
+        std::mem::swap(&mut self.items, &mut tab.items);
+        std::mem::swap(&mut self.filtered_items, &mut tab.filtered_items);
+        std::mem::swap(&mut self.selected, &mut tab.selected);
+        std::mem::swap(&mut self.filter_text, &mut tab.filter_text);
+        std::mem::swap(&mut self.aws_filters, &mut tab.aws_filters);
+        std::mem::swap(&mut self.parent_context, &mut tab.parent_context);
+        std::mem::swap(&mut self.navigation_stack, &mut tab.navigation_stack);
+        std::mem::swap(&mut self.pagination, &mut tab.pagination);
+        std::mem::swap(&mut self.marked_ids, &mut tab.marked_ids);
+        self.error_message = None;
+        self.schedule_cell_retry(0);
+    }
+
+    /// Open a tab next to the active one, starting from the same profile, region and resource
+    ///
+    /// The new tab gets its own clients, so switching its profile or region
+    /// leaves the other tabs untouched.
+    pub async fn open_tab(&mut self) -> Result<()> {
+        let (clients, _) =
+            AwsClients::new(&self.profile, &self.region, self.endpoint_url.clone()).await?;
+        let mut tab = Tab::new(clients, &self.current_resource_key);
+        self.swap_tab_state(&mut tab);
+        self.tabs[self.active_tab] = Some(tab);
+        self.active_tab += 1;
+        self.tabs.insert(self.active_tab, None);
+        self.refresh_current().await
+    }
+
+    /// Make the tab at `index` active
+    pub fn switch_tab(&mut self, index: usize) {
+        if index == self.active_tab {
+            return;
+        }
+        let Some(mut tab) = self.tabs.get_mut(index).and_then(Option::take) else {
+            return;
+        };
+        self.swap_tab_state(&mut tab);
+        self.tabs[self.active_tab] = Some(tab);
+        self.active_tab = index;
+    }
+
+    /// Cycle to the next (or previous) tab
+    pub fn cycle_tab(&mut self, forward: bool) {
+        let count = self.tabs.len();
+        let index = if forward {
+            (self.active_tab + 1) % count
+        } else {
+            (self.active_tab + count - 1) % count
+        };
+        self.switch_tab(index);
+    }
+
+    /// Close the active tab and activate its neighbour
+    pub fn close_tab(&mut self) {
+        if self.tabs.len() == 1 {
+            self.show_warning("Can't close the last tab");
+            return;
+        }
+        let closing = self.active_tab;
+        let next = if closing + 1 < self.tabs.len() {
+            closing + 1
+        } else {
+            closing - 1
+        };
+        self.switch_tab(next);
+        self.tabs.remove(closing);
+        if self.active_tab > closing {
+            self.active_tab -= 1;
+        }
+    }
+
+    /// Profile and region of each tab, in display order
+    pub fn tab_labels(&self) -> Vec<(String, String)> {
+        self.tabs
+            .iter()
+            .map(|tab| match tab {
+                Some(tab) => (tab.profile.clone(), tab.region.clone()),
+                None => (self.profile.clone(), self.region.clone()),
+            })
+            .collect()
+    }
+
     /// Switch profile with SSO/Console login check - returns login required if needed
     pub async fn switch_profile_with_sso_check(
         &mut self,
@@ -1400,9 +3083,15 @@ impl App {
     ) -> Result<ProfileSwitchResult> {
         use crate::aws::client::ClientResult;
 
-        match AwsClients::new_with_sso_check(profile, &self.region, self.endpoint_url.clone())
-            .await?
-        {
+        // Switch to the profile's own default region when it has one
+        let region = self
+            .available_profiles
+            .iter()
+            .find(|p| p.name == profile)
+            .and_then(|p| p.region.clone())
+            .unwrap_or_else(|| self.region.clone());
+
+        match AwsClients::new_with_sso_check(profile, &region, self.endpoint_url.clone()).await? {
             ClientResult::Ok(new_clients, actual_region) => {
                 self.clients = new_clients;
                 self.profile = profile.to_string();
@@ -1439,8 +3128,9 @@ impl App {
 
     /// Select profile - returns true if login (SSO or Console) is required
     pub async fn select_profile(&mut self) -> Result<bool> {
+        self.pending_context = None;
         if let Some(profile) = self.available_profiles.get(self.profiles_selected) {
-            let profile = profile.clone();
+            let profile = profile.name.clone();
             match self.switch_profile_with_sso_check(&profile).await? {
                 ProfileSwitchResult::Success => {
                     self.refresh_current().await?;
@@ -1470,6 +3160,75 @@ impl App {
         }
     }
 
+    pub async fn select_context(&mut self) -> Result<()> {
+        self.exit_mode();
+        if let Some(context) = self.config.contexts.get(self.contexts_selected) {
+            let name = context.name.clone();
+            self.switch_context(&name).await?;
+        }
+        Ok(())
+    }
+
+    /// Last context switched to, while the session is still on its profile and region
+    pub fn active_context(&self) -> Option<&NamedContext> {
+        self.config
+            .last_context
+            .as_deref()
+            .and_then(|name| self.config.context(name))
+            .filter(|c| c.profile == self.profile)
+            .filter(|c| c.region.as_ref().is_none_or(|r| r == &self.region))
+    }
+
+    /// Switch to a named context's profile, region and view in one go
+    pub async fn switch_context(&mut self, name: &str) -> Result<()> {
+        let Some(context) = self.config.context(name).cloned() else {
+            self.error_message = Some(format!("Unknown context: {}", name));
+            return Ok(());
+        };
+
+        if context.profile != self.profile {
+            match self.switch_profile_with_sso_check(&context.profile).await? {
+                ProfileSwitchResult::Success => {}
+                ProfileSwitchResult::SsoRequired {
+                    profile,
+                    sso_session,
+                } => {
+                    self.pending_context = Some(context.name);
+                    self.enter_sso_login_mode(&profile, &sso_session);
+                    return Ok(());
+                }
+                ProfileSwitchResult::ConsoleLoginRequired {
+                    profile,
+                    login_session,
+                } => {
+                    self.pending_context = Some(context.name);
+                    self.enter_console_login_mode(&profile, &login_session);
+                    return Ok(());
+                }
+            }
+        }
+        if let Some(region) = context.region.as_ref().filter(|r| **r != self.region) {
+            self.switch_region(region).await?;
+        }
+
+        if let Err(e) = self.config.set_last_context(&context.name) {
+            tracing::warn!("Failed to save context to config: {}", e);
+        }
+
+        match &context.resource {
+            Some(resource) => self.navigate_to_resource(resource).await,
+            None => self.refresh_current().await,
+        }
+    }
+
+    /// Reload after a login-interrupted profile switch, finishing a pending context
+    pub async fn resume_after_login(&mut self) -> Result<()> {
+        match self.pending_context.take() {
+            Some(name) => self.switch_context(&name).await,
+            None => self.refresh_current().await,
+        }
+    }
+
     pub async fn select_region(&mut self) -> Result<()> {
         if let Some(region) = self.available_regions.get(self.regions_selected) {
             let region = region.clone();
@@ -1518,6 +3277,13 @@ impl App {
             "regions" => {
                 self.enter_regions_mode();
             }
+            "ctx" | "context" => match parts.get(1) {
+                Some(name) => self.switch_context(name).await?,
+                None => self.enter_contexts_mode(),
+            },
+            "incident" => {
+                self.execute_incident_command(&parts[1..]).await?;
+            }
             "region" if parts.len() > 1 => {
                 self.switch_region(parts[1]).await?;
                 self.refresh_current().await?;
@@ -1573,24 +3339,1058 @@ impl App {
     }
 
     // =========================================================================
-    // Log Tail Mode
+    // Permission Preflight
     // =========================================================================
 
-    /// Enter log tail mode for the selected log stream
-    pub async fn enter_log_tail_mode(&mut self) -> Result<()> {
-        // Get the selected log stream item
-        let Some(item) = self.selected_item().cloned() else {
-            return Ok(());
+    /// IAM actions of the current view's actions that haven't been simulated yet
+    fn unsimulated_iam_actions(&self) -> Vec<String> {
+        if !self.config.permission_preflight {
+            return Vec::new();
+        }
+        let Some(resource) = self.current_resource() else {
+            return Vec::new();
+        };
+        let same_profile = self.permission_cache.profile == self.profile;
+        let mut names: Vec<String> = resource
+            .actions
+            .iter()
+            .flat_map(|action| required_iam_actions(resource, action))
+            .filter(|name| !same_profile || !self.permission_cache.decisions.contains_key(name))
+            .collect();
+        names.sort();
+        names.dedup();
+        names
+    }
+
+    /// Check if the current view has actions whose permissions are unknown
+    pub fn permission_preflight_needed(&self) -> bool {
+        !self.unsimulated_iam_actions().is_empty()
+    }
+
+    /// Simulate the current view's action permissions for the current identity
+    pub async fn run_permission_preflight(&mut self) {
+        let names = self.unsimulated_iam_actions();
+        if names.is_empty() {
+            return;
+        }
+        if self.permission_cache.profile != self.profile {
+            self.permission_cache = PermissionCache {
+                profile: self.profile.clone(),
+                decisions: HashMap::new(),
+            };
+        }
+
+        let params = serde_json::json!({ "action_names": names });
+        match crate::resource::invoke_sdk(
+            "iam",
+            "simulate_principal_policy",
+            &self.clients,
+            &params,
+        )
+        .await
+        {
+            Ok(response) => {
+                for decision in response
+                    .get("decisions")
+                    .and_then(|d| d.as_array())
+                    .into_iter()
+                    .flatten()
+                {
+                    let action = extract_json_value(decision, "action");
+                    // Simulated without a resource ARN, so an implicit deny only means no
+                    // policy allows it on every resource; it is treated as unknown
+                    let allowed = extract_json_value(decision, "decision") != "explicitDeny";
+                    self.permission_cache.decisions.insert(action, allowed);
+                }
+            }
+            Err(e) => tracing::warn!("Permission preflight failed: {}", e),
+        }
+
+        // Unanswered or failed simulations are treated as allowed so they aren't retried
+        for name in names {
+            self.permission_cache.decisions.entry(name).or_insert(true);
+        }
+    }
+
+    /// First IAM action the policy simulator denied for an action, if any
+    pub fn denied_permission(&self, resource: &ResourceDef, action: &ActionDef) -> Option<String> {
+        if !self.config.permission_preflight || self.permission_cache.profile != self.profile {
+            return None;
+        }
+        required_iam_actions(resource, action)
+            .into_iter()
+            .find(|name| self.permission_cache.decisions.get(name) == Some(&false))
+    }
+
+    // =========================================================================
+    // Capability Probe
+    // =========================================================================
+
+    /// Start probing the current profile's access in the background (once per
+    /// profile and region, when `capability_probe` is enabled)
+    pub fn start_capability_probe_if_needed(&mut self) {
+        if !self.config.capability_probe || self.capabilities_current() {
+            return;
+        }
+        let (tx, rx) = std::sync::mpsc::channel();
+        self.capabilities = CapabilityState {
+            profile: self.profile.clone(),
+            region: self.region.clone(),
+            receiver: Some(rx),
+            ..Default::default()
+        };
+
+        let profile = self.profile.clone();
+        let region = self.region.clone();
+        let endpoint_url = self.endpoint_url.clone();
+        tokio::spawn(async move {
+            // Own clients, so the UI's clients stay free while probing
+            let clients = match AwsClients::new(&profile, &region, endpoint_url).await {
+                Ok((clients, _)) => clients,
+                Err(e) => {
+                    tracing::warn!("Capability probe for {} failed to start: {}", profile, e);
+                    let _ = tx.send(CapabilityProbeResult::Finished);
+                    return;
+                }
+            };
+            if let Ok(identity) =
+                fetch_resources_paginated("sts-caller-identity", &clients, &[], None).await
+            {
+                if let Some(item) = identity.items.first() {
+                    let _ = tx.send(CapabilityProbeResult::Identity(extract_json_value(
+                        item, "Arn",
+                    )));
+                }
+            }
+            for (service, call) in CAPABILITY_PROBES {
+                if let Err(e) = run_probe(&clients, service, *call).await {
+                    let reason = e.to_string();
+                    if is_access_denied(&reason) {
+                        let denied = CapabilityProbeResult::Denied {
+                            service: service.to_string(),
+                            reason,
+                        };
+                        if tx.send(denied).is_err() {
+                            // The profile changed; a new probe took over
+                            return;
+                        }
+                    }
+                }
+            }
+            let _ = tx.send(CapabilityProbeResult::Finished);
+        });
+    }
+
+    /// Take in what the background capability probe found so far
+    pub fn poll_capability_probe(&mut self) {
+        let Some(receiver) = &self.capabilities.receiver else {
+            return;
+        };
+        let results: Vec<CapabilityProbeResult> = receiver.try_iter().collect();
+        for result in results {
+            match result {
+                CapabilityProbeResult::Identity(arn) => self.capabilities.identity = Some(arn),
+                CapabilityProbeResult::Denied { service, reason } => {
+                    self.capabilities.denied.insert(service, reason);
+                }
+                CapabilityProbeResult::Finished => {
+                    self.capabilities.finished = true;
+                    self.capabilities.receiver = None;
+                }
+            }
+        }
+    }
+
+    /// Check if the capability results are for the current profile and region
+    fn capabilities_current(&self) -> bool {
+        self.capabilities.profile == self.profile && self.capabilities.region == self.region
+    }
+
+    /// Why a resource type is likely inaccessible: its service's probe was denied
+    pub fn denied_resource(&self, resource_key: &str) -> Option<&str> {
+        if !self.config.capability_probe || !self.capabilities_current() {
+            return None;
+        }
+        let resource = get_resource(resource_key)?;
+        self.capabilities
+            .denied
+            .get(&resource.service)
+            .map(String::as_str)
+    }
+
+    // =========================================================================
+    // Job Notifications
+    // =========================================================================
+
+    /// What to watch after an action of the current view runs, if it has a
+    /// watch definition
+    pub fn job_watch(&self, action: &ActionDef) -> Option<JobWatch> {
+        Some(JobWatch {
+            action: action.display_name.clone(),
+            resource_key: self.current_resource_key.clone(),
+            filters: self.build_filters_from_context(),
+            watch: action.watch.clone()?,
+        })
+    }
+
+    /// Start watching the job an action started, when a notification webhook
+    /// is configured
+    pub fn watch_job(&mut self, watch: Option<&JobWatch>, resource_id: &str) {
+        let Some(watch) = watch else {
+            return;
+        };
+        if self.config.notifications.webhook_url.is_none() {
+            return;
+        }
+        // Name the row if its view is still open
+        let resource_name = get_resource(&watch.resource_key)
+            .filter(|_| self.current_resource_key == watch.resource_key)
+            .and_then(|resource| {
+                self.items
+                    .iter()
+                    .find(|item| extract_json_value(item, &resource.id_field) == resource_id)
+                    .map(|item| extract_json_value(item, &resource.name_field))
+            })
+            .filter(|name| name != "-")
+            .unwrap_or_else(|| resource_id.to_string());
+
+        self.watched_jobs.push(WatchedJob {
+            action: watch.action.clone(),
+            resource_key: watch.resource_key.clone(),
+            filters: watch.filters.clone(),
+            resource_id: resource_id.to_string(),
+            resource_name,
+            watch: watch.watch.clone(),
+            profile: self.profile.clone(),
+            region: self.region.clone(),
+            started_at: chrono::Utc::now(),
+            started: std::time::Instant::now(),
+            seen_running: false,
+        });
+    }
+
+    /// Run a form action on its own task with its own clients, so waiting for it
+    /// doesn't freeze the UI
+    pub fn spawn_form_action(
+        &mut self,
+        title: &str,
+        service: &str,
+        sdk_method: &str,
+        resource_id: &str,
+        fields: &[FormField],
+        watch: Option<JobWatch>,
+    ) {
+        let (tx, rx) = std::sync::mpsc::channel();
+        self.background_tasks.push(BackgroundTask {
+            description: format!("{} · {}", title, resource_id),
+            resource_id: resource_id.to_string(),
+            watch,
+            receiver: rx,
+        });
+
+        let profile = self.profile.clone();
+        let region = self.region.clone();
+        let endpoint_url = self.endpoint_url.clone();
+        let service = service.to_string();
+        let sdk_method = sdk_method.to_string();
+        let resource_id = resource_id.to_string();
+        let fields = fields.to_vec();
+        tokio::spawn(async move {
+            let result = match AwsClients::new(&profile, &region, endpoint_url).await {
+                Ok((clients, _)) => {
+                    crate::resource::execute_form_action(
+                        &service,
+                        &sdk_method,
+                        &clients,
+                        &resource_id,
+                        &fields,
+                    )
+                    .await
+                }
+                Err(e) => Err(e),
+            };
+            let _ = tx.send(result.map_err(|e| e.to_string()));
+        });
+    }
+
+    /// Report background actions that finished
+    pub async fn poll_background_tasks(&mut self) {
+        let mut notices = Vec::new();
+        let mut finished = Vec::new();
+        for task in std::mem::take(&mut self.background_tasks) {
+            match task.receiver.try_recv() {
+                Ok(Ok(message)) => {
+                    notices.push(format!("{}: {}", task.description, message));
+                    finished.push(task);
+                }
+                Ok(Err(e)) => {
+                    notices.push(format!("{} failed: {}", task.description, e));
+                }
+                Err(std::sync::mpsc::TryRecvError::Empty) => self.background_tasks.push(task),
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                    notices.push(format!("{} stopped without a result", task.description));
+                }
+            }
+        }
+        if notices.is_empty() {
+            return;
+        }
+        for task in finished {
+            self.watch_job(task.watch.as_ref(), &task.resource_id);
+        }
+        let _ = self.refresh_current().await;
+        self.show_warning(&notices.join("\n\n"));
+    }
+
+    /// Check if watched jobs are due for a status check
+    pub fn jobs_need_poll(&self) -> bool {
+        !self.watched_jobs.is_empty() && self.jobs_polled_at.elapsed() >= JOB_WATCH_INTERVAL
+    }
+
+    /// Check watched jobs and notify the webhook about finished ones
+    pub async fn poll_jobs(&mut self) {
+        self.jobs_polled_at = std::time::Instant::now();
+        let Some(webhook_url) = self.config.notifications.webhook_url.clone() else {
+            self.watched_jobs.clear();
+            return;
+        };
+
+        for mut job in std::mem::take(&mut self.watched_jobs) {
+            if job.timed_out() {
+                let payload = job.notification_payload(&JobProgress::TimedOut);
+                if let Err(e) = crate::jobs::send_webhook(&webhook_url, &payload).await {
+                    self.error_message = Some(format!("Job notification failed: {}", e));
+                }
+                continue;
+            }
+            // Jobs are only visible with the credentials and region they were
+            // started in, which may not be the current tab's
+            let key = (job.profile.clone(), job.region.clone());
+            let current = job.profile == self.profile && job.region == self.region;
+            if !current && !self.job_clients.contains_key(&key) {
+                match AwsClients::new(&job.profile, &job.region, self.endpoint_url.clone()).await {
+                    Ok((clients, _)) => {
+                        self.job_clients.insert(key.clone(), clients);
+                    }
+                    Err(e) => {
+                        tracing::warn!(
+                            "Failed to load credentials for job {} ({}/{}): {}",
+                            job.resource_id,
+                            job.profile,
+                            job.region,
+                            e
+                        );
+                        self.watched_jobs.push(job);
+                        continue;
+                    }
+                }
+            }
+            let clients = match self.job_clients.get(&key) {
+                Some(clients) if !current => clients,
+                _ => &self.clients,
+            };
+            let status = match Self::find_job_status(clients, &job).await {
+                Ok(status) => status,
+                Err(e) => {
+                    tracing::warn!("Failed to check job {}: {}", job.resource_id, e);
+                    self.watched_jobs.push(job);
+                    continue;
+                }
+            };
+            let progress = job.observe(status.as_deref());
+            if progress == JobProgress::Running {
+                self.watched_jobs.push(job);
+                continue;
+            }
+            let payload = job.notification_payload(&progress);
+            if let Err(e) = crate::jobs::send_webhook(&webhook_url, &payload).await {
+                self.error_message = Some(format!("Job notification failed: {}", e));
+            }
+        }
+
+        // Drop clients no remaining job needs
+        let jobs = &self.watched_jobs;
+        self.job_clients.retain(|(profile, region), _| {
+            jobs.iter()
+                .any(|job| job.profile == *profile && job.region == *region)
+        });
+    }
+
+    /// Current status of a watched job's row (None if the row is gone)
+    async fn find_job_status(clients: &AwsClients, job: &WatchedJob) -> Result<Option<String>> {
+        let Some(resource) = get_resource(&job.resource_key) else {
+            return Ok(None);
+        };
+        let mut page_token = None;
+        for _ in 0..JOB_WATCH_MAX_PAGES {
+            let page = fetch_resources_paginated(
+                &job.resource_key,
+                clients,
+                &job.filters,
+                page_token.as_deref(),
+            )
+            .await?;
+            if let Some(item) = page
+                .items
+                .iter()
+                .find(|item| extract_json_value(item, &resource.id_field) == job.resource_id)
+            {
+                return Ok(Some(extract_json_value(item, &job.watch.status_field)));
+            }
+            page_token = page.next_token;
+            if page_token.is_none() {
+                break;
+            }
+        }
+        Ok(None)
+    }
+
+    // =========================================================================
+    // Approvals
+    // =========================================================================
+
+    /// Write an action request for a second operator to approve
+    pub async fn request_approval(
+        &mut self,
+        pending: &PendingAction,
+    ) -> Result<std::path::PathBuf> {
+        let requested_by = approval::caller_arn(&self.clients).await?;
+        let form_fields = pending.form.as_ref().map(|form| form.fields.as_slice());
+        let mut request = ActionRequest::new(
+            RequestDraft {
+                description: &pending.message,
+                service: &pending.service,
+                sdk_method: &pending.sdk_method,
+                resource_ids: &pending.resource_ids,
+                form_fields,
+                profile: &self.profile,
+                region: &self.region,
+                requested_by: &requested_by,
+            },
+            chrono::Utc::now(),
+            self.config.approval.expiry_minutes,
+        );
+        request.watch = pending.watch.clone();
+        let path = approval::requests_dir().join(format!("{}.json", request.id));
+        request.save(&path)?;
+        self.awaiting_approval.push(PendingRequest {
+            path: path.clone(),
+            request,
+        });
+        approval::save_pending(&self.awaiting_approval)?;
+        Ok(path)
+    }
+
+    /// Check if awaited action requests are due for an approval check
+    pub fn approvals_need_poll(&self) -> bool {
+        !self.awaiting_approval.is_empty()
+            && self.approvals_polled_at.elapsed() >= APPROVAL_POLL_INTERVAL
+    }
+
+    /// Execute approved action requests and drop invalid or expired ones
+    pub async fn poll_approvals(&mut self) {
+        self.approvals_polled_at = std::time::Instant::now();
+        let now = chrono::Utc::now();
+        let mut notices = Vec::new();
+        let mut executed = false;
+        let before = self.awaiting_approval.len();
+
+        for pending in std::mem::take(&mut self.awaiting_approval) {
+            // Approved requests only run against the account they were made for
+            if pending.request.profile != self.profile || pending.request.region != self.region {
+                self.awaiting_approval.push(pending);
+                continue;
+            }
+            let checked = match pending.approved_request() {
+                Ok(request) => {
+                    request
+                        .verified_approver(&self.clients, &self.config.approval.approvers, now)
+                        .await
+                }
+                Err(e) => Err(e),
+            };
+            let approved_by = match checked {
+                Ok(Some(approver)) => approver,
+                Ok(None) => {
+                    self.awaiting_approval.push(pending);
+                    continue;
+                }
+                Err(e) => {
+                    notices.push(format!("Dropped request {}: {}", pending.request.id, e));
+                    continue;
+                }
+            };
+
+            let request = &pending.request;
+            let mut failures = Vec::new();
+            for resource_id in &request.resource_ids {
+                if let (Some(fields), true) = (
+                    &request.form_fields,
+                    runs_in_background(&request.service, &request.sdk_method),
+                ) {
+                    self.spawn_form_action(
+                        &request.description,
+                        &request.service,
+                        &request.sdk_method,
+                        resource_id,
+                        fields,
+                        request.watch.clone(),
+                    );
+                    continue;
+                }
+                let result = match &request.form_fields {
+                    Some(fields) => crate::resource::execute_form_action(
+                        &request.service,
+                        &request.sdk_method,
+                        &self.clients,
+                        resource_id,
+                        fields,
+                    )
+                    .await
+                    .map(|_| ()),
+                    None => {
+                        crate::resource::execute_action(
+                            &request.service,
+                            &request.sdk_method,
+                            &self.clients,
+                            resource_id,
+                        )
+                        .await
+                    }
+                };
+                if let Err(e) = result {
+                    failures.push(format!("{}: {}", resource_id, e));
+                } else {
+                    self.watch_job(request.watch.as_ref(), resource_id);
+                }
+            }
+            if let Ok(mut shared) = ActionRequest::load(&pending.path) {
+                shared.executed_at = Some(chrono::Utc::now().to_rfc3339());
+                if let Err(e) = shared.save(&pending.path) {
+                    tracing::warn!("Failed to record execution of {}: {}", request.id, e);
+                }
+            }
+            executed = true;
+
+            if failures.is_empty() {
+                notices.push(format!(
+                    "Request {} approved by {} and executed: {}",
+                    request.id, approved_by, request.description
+                ));
+            } else {
+                notices.push(format!(
+                    "Request {} approved by {}, but failed for {}/{} items: {}",
+                    request.id,
+                    approved_by,
+                    failures.len(),
+                    request.resource_ids.len(),
+                    failures.join("; ")
+                ));
+            }
+        }
+
+        if self.awaiting_approval.len() != before {
+            if let Err(e) = approval::save_pending(&self.awaiting_approval) {
+                tracing::warn!("Failed to save pending approvals: {}", e);
+            }
+        }
+        if executed {
+            let _ = self.refresh_current().await;
+        }
+        if !notices.is_empty() {
+            self.show_warning(&notices.join("\n\n"));
+        }
+    }
+
+    // =========================================================================
+    // Change Calendar
+    // =========================================================================
+
+    /// Check if the Change Calendar state is missing, stale, or for another profile/region
+    pub fn change_calendar_needs_refresh(&self) -> bool {
+        self.change_calendar.as_ref().is_none_or(|c| {
+            c.profile != self.profile
+                || c.region != self.region
+                || c.checked_at.elapsed() >= CHANGE_CALENDAR_REFRESH_INTERVAL
+        })
+    }
+
+    /// Re-check the SSM Change Calendar state
+    pub async fn refresh_change_calendar(&mut self) {
+        let params = serde_json::json!({
+            "calendars": self.config.change_calendar.calendars,
+        });
+        let mut state = ChangeCalendarState {
+            state: "-".to_string(),
+            calendars: Vec::new(),
+            next_transition: "-".to_string(),
+            error: None,
+            profile: self.profile.clone(),
+            region: self.region.clone(),
+            checked_at: std::time::Instant::now(),
+        };
+        match crate::resource::invoke_sdk("ssm", "get_calendar_state", &self.clients, &params).await
+        {
+            Ok(response) => {
+                state.state = extract_json_value(&response, "State");
+                state.next_transition = extract_json_value(&response, "NextTransitionTime");
+                state.calendars = response
+                    .get("Calendars")
+                    .and_then(|v| serde_json::from_value(v.clone()).ok())
+                    .unwrap_or_default();
+            }
+            Err(e) => {
+                tracing::warn!("Failed to get change calendar state: {}", e);
+                state.error = Some(e.to_string());
+            }
+        }
+        self.change_calendar = Some(state);
+    }
+
+    /// Whether a Change Calendar is closed (change freeze)
+    pub fn change_freeze_active(&self) -> bool {
+        self.change_calendar
+            .as_ref()
+            .is_some_and(|c| c.state == "CLOSED")
+    }
+
+    /// Whether mutating actions are refused during the current freeze
+    pub fn change_freeze_blocks(&self) -> bool {
+        self.change_freeze_active()
+            && self.config.change_calendar.freeze_policy == FreezePolicy::Block
+    }
+
+    /// Explanation of the active change freeze for dialogs
+    pub fn change_freeze_message(&self) -> String {
+        let Some(calendar) = &self.change_calendar else {
+            return String::new();
+        };
+        if calendar.next_transition == "-" {
+            format!("CHANGE FREEZE: {} closed.", calendar.calendars.join(", "))
+        } else {
+            format!(
+                "CHANGE FREEZE: {} closed until {}.",
+                calendar.calendars.join(", "),
+                calendar.next_transition
+            )
+        }
+    }
+
+    // =========================================================================
+    // Live Refresh
+    // =========================================================================
+
+    /// Check if live refresh is enabled and due for a change event check
+    pub fn live_refresh_due(&self) -> bool {
+        let interval = std::time::Duration::from_secs(self.config.live_refresh.interval_seconds);
+        self.config.live_refresh.source.is_some()
+            && self.live_refresh.as_ref().is_none_or(|l| {
+                l.profile != self.profile
+                    || l.region != self.region
+                    || l.checked_at.elapsed() >= interval
+            })
+    }
+
+    /// Read new change events and refresh the current view if its service changed
+    pub async fn poll_live_refresh(&mut self) {
+        let Some(source) = self.config.live_refresh.source else {
+            return;
+        };
+        let (mut state, first_poll) = match self.live_refresh.take() {
+            Some(state) if state.profile == self.profile && state.region == self.region => {
+                (state, false)
+            }
+            _ => (
+                LiveRefreshState {
+                    profile: self.profile.clone(),
+                    region: self.region.clone(),
+                    seen: HashMap::new(),
+                    checked_at: std::time::Instant::now(),
+                },
+                true,
+            ),
+        };
+        let (service, method, params) = match source {
+            LiveRefreshSource::Cloudtrail => (
+                "cloudtrail",
+                "lookup_write_events",
+                serde_json::json!({
+                    "since": chrono::Utc::now().timestamp() - LIVE_REFRESH_LOOKBACK.as_secs() as i64,
+                    "limit": self.config.pagination.fetch_all_limit,
+                }),
+            ),
+            LiveRefreshSource::Sqs => (
+                "sqs",
+                "receive_change_events",
+                serde_json::json!({ "queue_url": self.config.live_refresh.queue_url }),
+            ),
+        };
+        state.checked_at = std::time::Instant::now();
+        state
+            .seen
+            .retain(|_, seen_at| seen_at.elapsed() < LIVE_REFRESH_LOOKBACK * 2);
+
+        let mut changed = HashSet::new();
+        match crate::resource::invoke_sdk(service, method, &self.clients, &params).await {
+            Ok(response) => {
+                for event in response
+                    .get("events")
+                    .and_then(|v| v.as_array())
+                    .into_iter()
+                    .flatten()
+                {
+                    let id = extract_json_value(event, "id");
+                    if state.seen.insert(id, std::time::Instant::now()).is_none() {
+                        changed.insert(extract_json_value(event, "service"));
+                    }
+                }
+            }
+            Err(e) => tracing::warn!("Live refresh check failed: {}", e),
+        }
+        self.live_refresh = Some(state);
+
+        // Events from before taws switched to this account or region are history
+        if first_poll && source == LiveRefreshSource::Cloudtrail {
+            return;
+        }
+        let current_changed = self
+            .current_resource()
+            .is_some_and(|r| changed.contains(&r.service));
+        if current_changed && self.mode == Mode::Normal && !self.loading {
+            let _ = self.refresh_current().await;
+            self.mark_refreshed();
+        }
+    }
+
+    // =========================================================================
+    // Incident Dashboard
+    // =========================================================================
+
+    /// Handle `:incident [save <name> | clear | <name>]`
+    async fn execute_incident_command(&mut self, args: &[&str]) -> Result<()> {
+        match args {
+            [] => self.enter_incident_mode(),
+            ["clear"] => {
+                self.incident = IncidentState::default();
+                self.enter_incident_mode();
+            }
+            ["save", name] => {
+                if self.incident.panels.is_empty() {
+                    self.error_message = Some("Nothing pinned to save".to_string());
+                    return Ok(());
+                }
+                let layout = IncidentLayout {
+                    name: name.to_string(),
+                    panels: self
+                        .incident
+                        .panels
+                        .iter()
+                        .map(|p| p.panel.clone())
+                        .collect(),
+                };
+                if let Err(e) = self.config.save_incident_layout(layout) {
+                    self.error_message = Some(format!("Failed to save layout: {}", e));
+                    return Ok(());
+                }
+                self.incident.layout_name = Some(name.to_string());
+                self.enter_incident_mode();
+            }
+            [name] => {
+                let Some(layout) = self.config.incident_layout(name).cloned() else {
+                    self.error_message = Some(format!("Unknown incident layout: {}", name));
+                    return Ok(());
+                };
+                self.incident = IncidentState {
+                    layout_name: Some(layout.name),
+                    panels: layout
+                        .panels
+                        .into_iter()
+                        .map(IncidentPanelState::new)
+                        .collect(),
+                    ..Default::default()
+                };
+                self.enter_incident_mode();
+            }
+            _ => {
+                self.error_message =
+                    Some("Usage: incident [save <name> | clear | <name>]".to_string());
+            }
+        }
+        Ok(())
+    }
+
+    /// Show the incident dashboard; panels refresh on the next tick
+    pub fn enter_incident_mode(&mut self) {
+        self.incident.focused = self
+            .incident
+            .focused
+            .min(self.incident.panels.len().saturating_sub(1));
+        self.incident.last_refresh = None;
+        self.mode = Mode::Incident;
+    }
+
+    /// Leave the incident dashboard, keeping the pinned panels
+    pub fn exit_incident_mode(&mut self) {
+        self.mode = Mode::Normal;
+    }
+
+    /// Pin a panel unless an identical one is already pinned
+    fn pin_panel(&mut self, panel: IncidentPanel) -> bool {
+        if self.incident.panels.iter().any(|p| p.panel == panel) {
+            return false;
+        }
+        self.incident.panels.push(IncidentPanelState::new(panel));
+        true
+    }
+
+    /// Check if the current resource defines an action bound to `shortcut`
+    pub fn has_action_shortcut(&self, shortcut: &str) -> bool {
+        self.current_resource().is_some_and(|r| {
+            r.actions
+                .iter()
+                .any(|a| a.shortcut.as_deref() == Some(shortcut))
+        })
+    }
+
+    /// Pin the current listing with its parent and server-side filters
+    pub fn pin_current_view(&mut self) {
+        let Some(resource) = self.current_resource() else {
+            return;
+        };
+        let mut title = resource.display_name.clone();
+        if let Some(parent) = &self.parent_context {
+            title = format!("{} · {}", title, parent.display_name);
+        }
+        if let Some(filters) = &self.aws_filters {
+            title = format!("{} · {}", title, filters.display());
+        }
+        let panel = IncidentPanel::Resource {
+            resource_key: self.current_resource_key.clone(),
+            title: title.clone(),
+            filters: self.build_filters_from_context(),
+        };
+        let pinned = self.pin_panel(panel);
+        self.show_pin_result(&title, pinned);
+    }
+
+    /// Pin the stream being tailed
+    pub fn pin_log_tail(&mut self) {
+        let Some(state) = &self.log_tail_state else {
+            return;
+        };
+        let panel = IncidentPanel::Logs {
+            log_group: state.log_group.clone(),
+            log_stream: state.log_stream.clone(),
+        };
+        self.pin_panel(panel);
+    }
+
+    /// Whether the stream being tailed is pinned
+    pub fn log_tail_pinned(&self) -> bool {
+        self.log_tail_state.as_ref().is_some_and(|state| {
+            self.incident.panels.iter().any(|p| {
+                matches!(&p.panel, IncidentPanel::Logs { log_group, log_stream }
+                    if *log_group == state.log_group && *log_stream == state.log_stream)
+            })
+        })
+    }
+
+    /// Pin the metric behind the selected CloudWatch alarm
+    pub fn pin_alarm_metric(&mut self) {
+        let Some(item) = self.selected_item() else {
+            return;
+        };
+        if extract_json_value(item, "Type") == "Composite" {
+            self.show_warning("Composite alarms have no metric to pin; pin one of their children");
+            return;
+        }
+        let panel = IncidentPanel::Metric {
+            namespace: extract_json_value(item, "Namespace"),
+            metric_name: extract_json_value(item, "MetricName"),
+            stat: extract_json_value(item, "Statistic"),
+            dimensions: item
+                .get("MetricDimensions")
+                .and_then(|v| serde_json::from_value(v.clone()).ok())
+                .unwrap_or_default(),
+        };
+        let title = panel.title();
+        let pinned = self.pin_panel(panel);
+        self.show_pin_result(&title, pinned);
+    }
+
+    fn show_pin_result(&mut self, title: &str, pinned: bool) {
+        let count = self.incident.panels.len();
+        if pinned {
+            self.show_warning(&format!(
+                "Pinned '{}' to the incident dashboard ({} panel{}). Open it with :incident",
+                title,
+                count,
+                if count == 1 { "" } else { "s" }
+            ));
+        } else {
+            self.show_warning(&format!("'{}' is already pinned", title));
+        }
+    }
+
+    /// Remove the focused panel from the dashboard
+    pub fn unpin_focused_panel(&mut self) {
+        if self.incident.focused < self.incident.panels.len() {
+            self.incident.panels.remove(self.incident.focused);
+            self.incident.focused = self
+                .incident
+                .focused
+                .min(self.incident.panels.len().saturating_sub(1));
+        }
+    }
+
+    /// Move focus to the next panel (wrapping)
+    pub fn incident_focus_next(&mut self) {
+        if !self.incident.panels.is_empty() {
+            self.incident.focused = (self.incident.focused + 1) % self.incident.panels.len();
+        }
+    }
+
+    /// Move focus to the previous panel (wrapping)
+    pub fn incident_focus_prev(&mut self) {
+        let len = self.incident.panels.len();
+        if len > 0 {
+            self.incident.focused = (self.incident.focused + len - 1) % len;
+        }
+    }
+
+    /// Check if the incident dashboard is due for a refresh
+    pub fn incident_needs_refresh(&self) -> bool {
+        self.mode == Mode::Incident
+            && self
+                .incident
+                .last_refresh
+                .is_none_or(|t| t.elapsed() >= INCIDENT_REFRESH_INTERVAL)
+    }
+
+    /// Refresh every pinned panel
+    pub async fn refresh_incident_panels(&mut self) {
+        for panel in self.incident.panels.iter_mut() {
+            panel.error = None;
+            match panel.panel.clone() {
+                IncidentPanel::Resource {
+                    resource_key,
+                    filters,
+                    ..
+                } => {
+                    match fetch_resources_paginated(&resource_key, &self.clients, &filters, None)
+                        .await
+                    {
+                        Ok(result) => panel.items = result.items,
+                        Err(e) => panel.error = Some(e.to_string()),
+                    }
+                }
+                IncidentPanel::Logs {
+                    log_group,
+                    log_stream,
+                } => {
+                    let mut params = serde_json::json!({
+                        "log_group_name": [log_group],
+                        "log_stream_name": [log_stream],
+                    });
+                    if let Some(ref token) = panel.next_forward_token {
+                        params["next_forward_token"] = serde_json::json!(token);
+                    }
+                    match crate::resource::invoke_sdk(
+                        "cloudwatchlogs",
+                        "get_log_events",
+                        &self.clients,
+                        &params,
+                    )
+                    .await
+                    {
+                        Ok(response) => {
+                            for event in response
+                                .get("events")
+                                .and_then(|v| v.as_array())
+                                .into_iter()
+                                .flatten()
+                            {
+                                panel.log_events.push(LogEvent {
+                                    timestamp: event
+                                        .get("timestamp")
+                                        .and_then(|v| v.as_i64())
+                                        .unwrap_or(0),
+                                    message: event
+                                        .get("message")
+                                        .and_then(|v| v.as_str())
+                                        .unwrap_or("")
+                                        .to_string(),
+                                });
+                            }
+                            let excess = panel.log_events.len().saturating_sub(INCIDENT_LOG_LINES);
+                            panel.log_events.drain(0..excess);
+                            if let Some(token) =
+                                response.get("nextForwardToken").and_then(|v| v.as_str())
+                            {
+                                panel.next_forward_token = Some(token.to_string());
+                            }
+                        }
+                        Err(e) => panel.error = Some(e.to_string()),
+                    }
+                }
+                IncidentPanel::Metric {
+                    namespace,
+                    metric_name,
+                    stat,
+                    dimensions,
+                } => {
+                    let params = serde_json::json!({
+                        "namespace": namespace,
+                        "metric_name": metric_name,
+                        "stat": stat,
+                        "dimensions": dimensions,
+                    });
+                    match crate::resource::invoke_sdk(
+                        "cloudwatch",
+                        "get_metric_series",
+                        &self.clients,
+                        &params,
+                    )
+                    .await
+                    {
+                        Ok(response) => {
+                            panel.values = response
+                                .get("values")
+                                .and_then(|v| v.as_array())
+                                .map(|values| values.iter().filter_map(|v| v.as_f64()).collect())
+                                .unwrap_or_default();
+                        }
+                        Err(e) => panel.error = Some(e.to_string()),
+                    }
+                }
+            }
+        }
+        self.incident.last_refresh = Some(std::time::Instant::now());
+    }
+
+    // =========================================================================
+    // Log Tail Mode
+    // =========================================================================
+
+    /// Enter log tail mode for the selected log stream
+    pub async fn enter_log_tail_mode(&mut self) -> Result<()> {
+        // Get the selected log stream item
+        let Some(item) = self.selected_item().cloned() else {
+            return Ok(());
         };
 
         // Extract log stream name from selected item
         let log_stream = extract_json_value(&item, "logStreamName");
 
-        // Extract log group name from parent context (log group)
-        let log_group = self
-            .parent_context
-            .as_ref()
-            .map(|ctx| extract_json_value(&ctx.item, "logGroupName"))
+        // Rows that carry their own log group (e.g., CodeBuild builds) are used as is,
+        // otherwise the log group comes from the parent context (log group)
+        let log_group = Some(extract_json_value(&item, "logGroupName"))
+            .filter(|group| group != "-" && !group.is_empty())
+            .or_else(|| {
+                self.parent_context
+                    .as_ref()
+                    .map(|ctx| extract_json_value(&ctx.item, "logGroupName"))
+            })
             .unwrap_or_else(|| "-".to_string());
 
         if log_group == "-" || log_stream == "-" {
@@ -1799,12 +4599,974 @@ impl App {
     pub fn take_ssm_connect_request(&mut self) -> Option<SsmConnectRequest> {
         self.ssm_connect_request.take()
     }
+
+    // =========================================================================
+    // External Pager and Editor
+    // =========================================================================
+
+    /// Classes to mask in views while redaction is on
+    pub fn redact_classes(&self) -> Option<crate::redact::RedactClasses> {
+        self.redacted.then(|| (&self.config.redaction).into())
+    }
+
+    /// Why content can't leave the TUI right now (redaction only masks the screen)
+    fn external_tools_blocked(&self) -> Option<&'static str> {
+        if self.redacted {
+            Some("Redaction is on; turn it off (Ctrl+r) to open content outside taws")
+        } else {
+            None
+        }
+    }
+
+    /// Open the describe view's JSON in the pager (masked fields stay masked)
+    pub fn open_describe_in_pager(&mut self) {
+        if let Some(reason) = self.external_tools_blocked() {
+            self.show_warning(reason);
+            return;
+        }
+        let Some(content) = self.selected_item_json() else {
+            return;
+        };
+        self.external_request = Some(ExternalRequest {
+            tool: ExternalTool::Pager,
+            content,
+            extension: "json",
+            edit: None,
+        });
+    }
+
+    /// Open the describe view's JSON in the editor; changes to parameter and
+    /// secret values and to Tags are applied when the editor exits
+    pub fn open_describe_in_editor(&mut self) {
+        if let Some(reason) = self.external_tools_blocked() {
+            self.show_warning(reason);
+            return;
+        }
+        if !self.describe_masked_fields.is_empty() && !self.describe_revealed {
+            self.show_warning("Reveal the values (v) before editing them");
+            return;
+        }
+        let Some(original) = self
+            .describe_data
+            .clone()
+            .or_else(|| self.selected_item().cloned())
+        else {
+            return;
+        };
+        let edit = self.current_resource().and_then(|resource| {
+            let item = self.selected_item()?;
+            Some(EditTarget {
+                resource_key: self.current_resource_key.clone(),
+                resource_id: extract_json_value(item, &resource.id_field),
+                arn: row_arn(item, resource),
+                original: original.clone(),
+            })
+        });
+        self.external_request = Some(ExternalRequest {
+            tool: ExternalTool::Editor,
+            content: serde_json::to_string_pretty(&original).unwrap_or_default(),
+            extension: "json",
+            edit,
+        });
+    }
+
+    /// Open the tailed log events in the pager
+    pub fn open_log_tail_in_pager(&mut self) {
+        if let Some(reason) = self.external_tools_blocked() {
+            self.show_warning(reason);
+            return;
+        }
+        let Some(state) = self.log_tail_state.as_ref() else {
+            return;
+        };
+        let content = state
+            .events
+            .iter()
+            .map(|event| {
+                format!(
+                    "[{}] {}\n",
+                    crate::resource::format_log_timestamp(event.timestamp),
+                    event.message.trim_end()
+                )
+            })
+            .collect();
+        self.external_request = Some(ExternalRequest {
+            tool: ExternalTool::Pager,
+            content,
+            extension: "log",
+            edit: None,
+        });
+    }
+
+    /// Take the external pager/editor request (clears it)
+    pub fn take_external_request(&mut self) -> Option<ExternalRequest> {
+        self.external_request.take()
+    }
+
+    /// Hand the rows of the current list to the external fuzzy finder
+    pub fn open_picker(&mut self) {
+        if self.config.picker.is_none() {
+            self.show_warning("Set `picker` in the config (e.g., picker: fzf) to pick rows with an external fuzzy finder");
+            return;
+        }
+        if let Some(reason) = self.external_tools_blocked() {
+            self.show_warning(reason);
+            return;
+        }
+        let Some(resource) = self.current_resource() else {
+            return;
+        };
+        if self.filtered_items.is_empty() {
+            return;
+        }
+        let lines = self
+            .filtered_items
+            .iter()
+            .map(|item| picker_line(item, resource))
+            .collect();
+        self.picker_request = Some(lines);
+    }
+
+    /// Take the fuzzy finder request (clears it)
+    pub fn take_picker_request(&mut self) -> Option<Vec<String>> {
+        self.picker_request.take()
+    }
+
+    /// Select the row the fuzzy finder returned
+    pub fn select_picked_row(&mut self, line: &str) {
+        let Some(resource) = self.current_resource() else {
+            return;
+        };
+        let id = picked_id(line);
+        if let Some(index) = self
+            .filtered_items
+            .iter()
+            .position(|item| extract_json_value(item, &resource.id_field) == id)
+        {
+            self.selected = index;
+        }
+    }
+
+    /// Apply a detail document edited in the external editor
+    pub async fn apply_edited_document(&mut self, target: EditTarget, edited: &str) {
+        let edited: Value = match serde_json::from_str(edited) {
+            Ok(edited) => edited,
+            Err(e) => {
+                self.error_message = Some(format!("Edits not applied: invalid JSON ({})", e));
+                return;
+            }
+        };
+        if edited == target.original {
+            return;
+        }
+        let edits = match crate::resource::document_edits(
+            &target.resource_key,
+            &target.original,
+            &edited,
+        ) {
+            Ok(edits) => edits,
+            Err(e) => {
+                self.error_message = Some(format!("Edits not applied: {}", e));
+                return;
+            }
+        };
+        if edits.is_empty() {
+            self.show_warning(
+                "Only parameter values, secret values and Tags can be changed here; edits were discarded",
+            );
+            return;
+        }
+        if self.readonly {
+            self.error_message =
+                Some("This operation is not supported in read-only mode".to_string());
+            return;
+        }
+        if self.change_freeze_blocks() {
+            self.error_message = Some(self.change_freeze_message());
+            return;
+        }
+
+        // Confirmed (and approved, in approval mode) like any other write
+        let mut lines = Vec::new();
+        if let Some(ref value) = edits.parameter_value {
+            lines.push(format!("Value: {}", preview_value(value)));
+        }
+        if let Some(ref secret) = edits.secret_string {
+            lines.push(format!(
+                "Secret value (new version): {}",
+                preview_value(secret)
+            ));
+        }
+        if !edits.tags_set.is_empty() {
+            let tags: Vec<String> = edits
+                .tags_set
+                .iter()
+                .map(|(key, value)| format!("{}={}", key, value))
+                .collect();
+            lines.push(format!("Set tags: {}", tags.join(", ")));
+        }
+        if !edits.tags_removed.is_empty() {
+            lines.push(format!("Remove tags: {}", edits.tags_removed.join(", ")));
+        }
+        if edits.other_changes {
+            lines.push("Other edited fields can't be changed here and are discarded.".to_string());
+        }
+        let service = get_resource(&target.resource_key)
+            .map(|resource| resource.service.clone())
+            .unwrap_or_default();
+        let fields = crate::resource::document_edit_fields(target.arn.as_deref(), &edits);
+        let pending = PendingAction {
+            service: service.clone(),
+            sdk_method: "apply_document_edits".to_string(),
+            resource_ids: vec![target.resource_id.clone()],
+            message: format!(
+                "Apply edits to '{}'?\n\n{}",
+                target.resource_id,
+                lines.join("\n")
+            ),
+            default_no: true,
+            destructive: true,
+            selected_yes: false,
+            requires_approval: true,
+            result_title: None,
+            sensitive_fields: Vec::new(),
+            requester_pays_bucket: None,
+            form: Some(FormState {
+                title: format!("Edit {}", target.resource_id),
+                service,
+                sdk_method: "apply_document_edits".to_string(),
+                resource_ids: vec![target.resource_id],
+                fields,
+                selected: 0,
+                freeze_confirmed: true,
+                confirm: None,
+                watch: None,
+            }),
+            watch: None,
+        };
+        self.enter_confirm_mode(pending);
+    }
+}
+
+/// Check if an action is defined to run on a background task
+fn runs_in_background(service: &str, sdk_method: &str) -> bool {
+    crate::resource::get_registry()
+        .resources
+        .values()
+        .filter(|resource| resource.service == service)
+        .flat_map(|resource| &resource.actions)
+        .any(|action| action.sdk_method == sdk_method && action.background)
+}
+
+/// Whether a row's ID or name refers to the target name; ARNs and URLs match on
+/// their last segment (e.g., a queue URL ending in `/orders`)
+fn row_matches_target(item: &Value, resource: &ResourceDef, target_name: &str) -> bool {
+    [&resource.id_field, &resource.name_field]
+        .iter()
+        .map(|field| extract_json_value(item, field))
+        .any(|value| {
+            value == target_name
+                || value.ends_with(&format!("/{}", target_name))
+                || value.ends_with(&format!(":{}", target_name))
+        })
+}
+
+/// First line of an edited value, shortened for the confirmation dialog
+fn preview_value(value: &str) -> String {
+    const PREVIEW_CHARS: usize = 60;
+    let first_line = value.lines().next().unwrap_or_default();
+    if first_line.chars().count() > PREVIEW_CHARS || value.lines().count() > 1 {
+        let preview: String = first_line.chars().take(PREVIEW_CHARS).collect();
+        format!("{}…", preview)
+    } else {
+        first_line.to_string()
+    }
+}
+
+/// Most opened resource types first, then alphabetically (alphabetical only
+/// with adaptive ordering off)
+fn sort_by_open_count(commands: &mut [String], config: &Config) {
+    commands.sort_by(|a, b| {
+        config
+            .resource_open_count(b)
+            .cmp(&config.resource_open_count(a))
+            .then_with(|| a.cmp(b))
+    });
+}
+
+/// Line of a row for the fuzzy finder: its ID, then its name when that differs
+fn picker_line(item: &Value, resource: &ResourceDef) -> String {
+    let id = extract_json_value(item, &resource.id_field);
+    let name = extract_json_value(item, &resource.name_field);
+    if name == id || name == "-" || name.is_empty() {
+        id
+    } else {
+        format!("{}\t{}", id, name)
+    }
+}
+
+/// ID of the row a fuzzy finder line was built from
+fn picked_id(line: &str) -> &str {
+    line.split('\t').next().unwrap_or(line)
+}
+
+/// ARN of a row for tag changes: its ID when that is an ARN, else an `...Arn`
+/// field of the resource's own service (e.g., a queue's `QueueArn`)
+fn row_arn(item: &Value, resource: &ResourceDef) -> Option<String> {
+    let id = extract_json_value(item, &resource.id_field);
+    if id.starts_with("arn:") {
+        return Some(id);
+    }
+    let service = format!(":{}:", resource.service);
+    item.as_object()?.iter().find_map(|(key, value)| {
+        let value = value.as_str()?;
+        let own = value.starts_with("arn:") && value[3..].contains(&service);
+        (own && (key.ends_with("Arn") || key.ends_with("ARN"))).then(|| value.to_string())
+    })
+}
+
+/// Region and name of an SQS queue ARN (`arn:aws:sqs:us-east-1:123456789012:orders-dlq`)
+fn sqs_queue_from_arn(arn: &str) -> Option<(String, String)> {
+    let parts: Vec<&str> = arn.split(':').collect();
+    match parts.as_slice() {
+        ["arn", _, "sqs", region, _, name] if !region.is_empty() && !name.is_empty() => {
+            Some((region.to_string(), name.to_string()))
+        }
+        _ => None,
+    }
+}
+
+/// Pretty-print JSON documents (including URL-encoded IAM policies) for the cell popup
+fn expand_cell_value(value: &str) -> String {
+    let decoded = if value.starts_with("%7B") {
+        urlencoding::decode(value)
+            .map(|d| d.into_owned())
+            .unwrap_or_else(|_| value.to_string())
+    } else {
+        value.to_string()
+    };
+    match serde_json::from_str::<Value>(&decoded) {
+        Ok(json @ (Value::Object(_) | Value::Array(_))) => {
+            serde_json::to_string_pretty(&json).unwrap_or(decoded)
+        }
+        _ => value.to_string(),
+    }
+}
+
+/// Replace the values of sensitive fields (dotted paths, e.g.
+/// "Parameter.Value") until they are revealed
+fn mask_fields(data: &Value, fields: &[String]) -> Value {
+    let mut masked = data.clone();
+    for field in fields {
+        let pointer = format!("/{}", field.replace('.', "/"));
+        if let Some(value) = masked.pointer_mut(&pointer) {
+            *value = Value::String("******** (press v to reveal)".to_string());
+        }
+    }
+    masked
+}
+
+/// Stable sort of rows by a column's value, numerically when both values are
+/// numbers; empty values ("-") stay last in either direction
+fn sort_rows(rows: &mut Vec<Value>, json_path: &str, descending: bool) {
+    let mut keyed: Vec<(String, Value)> = std::mem::take(rows)
+        .into_iter()
+        .map(|row| (extract_json_value(&row, json_path), row))
+        .collect();
+    keyed.sort_by(|(a, _), (b, _)| {
+        let missing = |v: &str| v.is_empty() || v == "-";
+        match (missing(a), missing(b)) {
+            (true, true) => return std::cmp::Ordering::Equal,
+            (true, false) => return std::cmp::Ordering::Greater,
+            (false, true) => return std::cmp::Ordering::Less,
+            (false, false) => {}
+        }
+        let ordering = match (a.parse::<f64>(), b.parse::<f64>()) {
+            (Ok(x), Ok(y)) => x.total_cmp(&y),
+            _ => a.to_lowercase().cmp(&b.to_lowercase()),
+        };
+        if descending {
+            ordering.reverse()
+        } else {
+            ordering
+        }
+    });
+    *rows = keyed.into_iter().map(|(_, row)| row).collect();
+}
+
+/// A single cheap call that tells whether the identity may use a service
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ProbeCall {
+    /// Query protocol action and parameters
+    Query(&'static str, &'static [(&'static str, &'static str)]),
+    /// JSON protocol target and body
+    Json(&'static str, &'static str),
+    /// REST-JSON method, path and body
+    RestJson(&'static str, &'static str, Option<&'static str>),
+    /// REST-XML GET path
+    RestXml(&'static str),
+}
+
+/// One list call per service, asking for as few items as the API allows
+const CAPABILITY_PROBES: &[(&str, ProbeCall)] = &[
+    (
+        "accessanalyzer",
+        ProbeCall::RestJson("GET", "/analyzer?maxResults=1", None),
+    ),
+    (
+        "account",
+        ProbeCall::RestJson("POST", "/listRegions", Some(r#"{"MaxResults":1}"#)),
+    ),
+    (
+        "acm",
+        ProbeCall::Json("ListCertificates", r#"{"MaxItems":1}"#),
+    ),
+    (
+        "apigateway",
+        ProbeCall::RestJson("GET", "/restapis?limit=1", None),
+    ),
+    (
+        "application-autoscaling",
+        ProbeCall::Json(
+            "DescribeScalableTargets",
+            r#"{"ServiceNamespace":"ecs","MaxResults":1}"#,
+        ),
+    ),
+    (
+        "apprunner",
+        ProbeCall::Json("ListServices", r#"{"MaxResults":1}"#),
+    ),
+    (
+        "athena",
+        ProbeCall::Json("ListWorkGroups", r#"{"MaxResults":1}"#),
+    ),
+    (
+        "autoscaling",
+        ProbeCall::Query("DescribeAutoScalingGroups", &[("MaxRecords", "1")]),
+    ),
+    (
+        "backup",
+        ProbeCall::RestJson("GET", "/backup-vaults/?maxResults=1", None),
+    ),
+    (
+        "batch",
+        ProbeCall::RestJson(
+            "POST",
+            "/v1/describecomputeenvironments",
+            Some(r#"{"maxResults":1}"#),
+        ),
+    ),
+    ("cloudformation", ProbeCall::Query("DescribeStacks", &[])),
+    (
+        "cloudfront",
+        ProbeCall::RestXml("/2020-05-31/distribution?MaxItems=1"),
+    ),
+    ("cloudtrail", ProbeCall::Json("DescribeTrails", "{}")),
+    (
+        "cloudwatch",
+        ProbeCall::Query("DescribeAlarms", &[("MaxRecords", "1")]),
+    ),
+    (
+        "cloudwatchlogs",
+        ProbeCall::Json("DescribeLogGroups", r#"{"limit":1}"#),
+    ),
+    ("codebuild", ProbeCall::Json("ListProjects", "{}")),
+    (
+        "codepipeline",
+        ProbeCall::Json("ListPipelines", r#"{"maxResults":1}"#),
+    ),
+    (
+        "cognitoidentityprovider",
+        ProbeCall::Json("ListUserPools", r#"{"MaxResults":1}"#),
+    ),
+    ("config", ProbeCall::Json("DescribeConfigRules", "{}")),
+    ("dynamodb", ProbeCall::Json("ListTables", r#"{"Limit":1}"#)),
+    (
+        "ec2",
+        ProbeCall::Query("DescribeVpcs", &[("MaxResults", "5")]),
+    ),
+    (
+        "ecr",
+        ProbeCall::Json("DescribeRepositories", r#"{"maxResults":1}"#),
+    ),
+    (
+        "ecs",
+        ProbeCall::Json("ListClusters", r#"{"maxResults":1}"#),
+    ),
+    (
+        "efs",
+        ProbeCall::RestJson("GET", "/2015-02-01/file-systems?MaxItems=1", None),
+    ),
+    (
+        "eks",
+        ProbeCall::RestJson("GET", "/clusters?maxResults=1", None),
+    ),
+    (
+        "elasticache",
+        ProbeCall::Query("DescribeCacheClusters", &[("MaxRecords", "20")]),
+    ),
+    (
+        "elasticbeanstalk",
+        ProbeCall::Query("DescribeApplications", &[]),
+    ),
+    (
+        "elbv2",
+        ProbeCall::Query("DescribeLoadBalancers", &[("PageSize", "1")]),
+    ),
+    (
+        "eventbridge",
+        ProbeCall::Json("ListEventBuses", r#"{"Limit":1}"#),
+    ),
+    (
+        "fsx",
+        ProbeCall::Json("DescribeFileSystems", r#"{"MaxResults":1}"#),
+    ),
+    (
+        "glue",
+        ProbeCall::Json("GetDatabases", r#"{"MaxResults":1}"#),
+    ),
+    ("iam", ProbeCall::Query("ListRoles", &[("MaxItems", "1")])),
+    (
+        "kafka",
+        ProbeCall::RestJson("GET", "/api/v2/clusters?maxResults=1", None),
+    ),
+    ("kms", ProbeCall::Json("ListKeys", r#"{"Limit":1}"#)),
+    (
+        "lambda",
+        ProbeCall::RestJson("GET", "/2015-03-31/functions?MaxItems=1", None),
+    ),
+    (
+        "mq",
+        ProbeCall::RestJson("GET", "/v1/brokers?maxResults=1", None),
+    ),
+    (
+        "opensearch",
+        ProbeCall::RestJson("GET", "/2021-01-01/domain", None),
+    ),
+    (
+        "organizations",
+        ProbeCall::Json("DescribeOrganization", "{}"),
+    ),
+    (
+        "rds",
+        ProbeCall::Query("DescribeDBInstances", &[("MaxRecords", "20")]),
+    ),
+    (
+        "redshift",
+        ProbeCall::Query("DescribeClusters", &[("MaxRecords", "20")]),
+    ),
+    (
+        "redshift-serverless",
+        ProbeCall::Json("ListWorkgroups", r#"{"maxResults":1}"#),
+    ),
+    (
+        "route53",
+        ProbeCall::RestXml("/2013-04-01/hostedzone?maxitems=1"),
+    ),
+    ("s3", ProbeCall::RestXml("/?max-buckets=1")),
+    (
+        "sagemaker",
+        ProbeCall::Json("ListNotebookInstances", r#"{"MaxResults":1}"#),
+    ),
+    (
+        "secretsmanager",
+        ProbeCall::Json("ListSecrets", r#"{"MaxResults":1}"#),
+    ),
+    (
+        "servicequotas",
+        ProbeCall::Json("ListServices", r#"{"MaxResults":1}"#),
+    ),
+    ("sns", ProbeCall::Query("ListTopics", &[])),
+    (
+        "sqs",
+        ProbeCall::Query("ListQueues", &[("MaxResults", "1")]),
+    ),
+    (
+        "ssm",
+        ProbeCall::Json("DescribeParameters", r#"{"MaxResults":1}"#),
+    ),
+    (
+        "stepfunctions",
+        ProbeCall::Json("ListStateMachines", r#"{"maxResults":1}"#),
+    ),
+    (
+        "wafv2",
+        ProbeCall::Json("ListWebACLs", r#"{"Scope":"REGIONAL","Limit":1}"#),
+    ),
+];
+
+/// Make a service's probe call
+async fn run_probe(clients: &AwsClients, service: &str, call: ProbeCall) -> Result<String> {
+    match call {
+        ProbeCall::Query(action, params) => {
+            clients.http.query_request(service, action, params).await
+        }
+        ProbeCall::Json(target, body) => clients.http.json_request(service, target, body).await,
+        ProbeCall::RestJson(method, path, body) => {
+            clients
+                .http
+                .rest_json_request(service, method, path, body)
+                .await
+        }
+        ProbeCall::RestXml(path) => {
+            clients
+                .http
+                .rest_xml_request(service, "GET", path, None)
+                .await
+        }
+    }
+}
+
+/// Whether an error says the identity isn't allowed to make the call
+/// (as opposed to throttling, a missing resource or a network problem)
+fn is_access_denied(error: &str) -> bool {
+    [
+        "AccessDenied",
+        "UnauthorizedOperation",
+        "UnauthorizedAccess",
+        "AuthorizationError",
+        "not authorized to perform",
+    ]
+    .iter()
+    .any(|marker| error.contains(marker))
+}
+
+/// Copy the cells that failed to load in `items` from a refetch of the same
+/// page, matching rows by id; cells that failed again keep their new reason
+fn merge_retried_cells(items: &mut [Value], retried: &[Value], id_field: &str) {
+    for item in items.iter_mut() {
+        let Some(errors) = item
+            .get(CELL_ERRORS_FIELD)
+            .and_then(|e| e.as_object())
+            .cloned()
+        else {
+            continue;
+        };
+        let id = extract_json_value(item, id_field);
+        let Some(fresh) = retried
+            .iter()
+            .find(|row| extract_json_value(row, id_field) == id)
+        else {
+            continue;
+        };
+
+        let mut still_failing = serde_json::Map::new();
+        for field in errors.keys() {
+            item[field] = fresh.get(field).cloned().unwrap_or(Value::Null);
+            if let Some(reason) = cell_error(fresh, field) {
+                still_failing.insert(field.clone(), Value::from(reason));
+            }
+        }
+        if let Some(row) = item.as_object_mut() {
+            if still_failing.is_empty() {
+                row.remove(CELL_ERRORS_FIELD);
+            } else {
+                row.insert(CELL_ERRORS_FIELD.to_string(), Value::Object(still_failing));
+            }
+        }
+    }
+}
+
+/// Bucket (or access point alias) an S3 object listing is filtered to
+fn s3_bucket_filter(filters: &[ResourceFilter]) -> Option<String> {
+    filters
+        .iter()
+        .find(|f| f.name == "bucket_names")
+        .and_then(|f| f.values.first().cloned())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_capability_probes() {
+        let services: Vec<&str> = CAPABILITY_PROBES.iter().map(|(s, _)| *s).collect();
+        // One probe per known service, none for STS
+        let mut unique = services.clone();
+        unique.sort();
+        unique.dedup();
+        assert_eq!(unique.len(), services.len());
+        assert!(!services.contains(&"sts"));
+        for service in &services {
+            assert!(
+                crate::aws::http::get_service(service).is_some(),
+                "{}",
+                service
+            );
+        }
+        // Every service with resources is probed
+        for key in get_all_resource_keys() {
+            let service = &get_resource(key).unwrap().service;
+            assert!(
+                service == "sts" || services.contains(&service.as_str()),
+                "{}",
+                service
+            );
+        }
+
+        assert!(is_access_denied(
+            "User: arn:aws:sts::1:assumed-role/ro/x is not authorized to perform: kms:ListKeys"
+        ));
+        assert!(is_access_denied(
+            "UnauthorizedOperation: You are not authorized"
+        ));
+        assert!(!is_access_denied("ThrottlingException: Rate exceeded"));
+    }
+
+    #[test]
+    fn test_merge_retried_cells() {
+        use serde_json::json;
+
+        let mut items = vec![
+            json!({ "Name": "a", "Targets": "2" }),
+            json!({ "Name": "b", "Targets": "error", "State": "error",
+                    "_errors": { "Targets": "Throttling", "State": "Throttling" } }),
+            json!({ "Name": "c", "Targets": "error", "_errors": { "Targets": "Throttling" } }),
+        ];
+        let retried = vec![
+            json!({ "Name": "a", "Targets": "3" }),
+            json!({ "Name": "b", "Targets": "5", "State": "error",
+                    "_errors": { "State": "AccessDenied" } }),
+        ];
+        merge_retried_cells(&mut items, &retried, "Name");
+
+        // Rows that loaded fine are left alone, even if the refetch differs
+        assert_eq!(items[0]["Targets"], "2");
+        assert_eq!(items[1]["Targets"], "5");
+        assert_eq!(cell_error(&items[1], "Targets"), None);
+        assert_eq!(cell_error(&items[1], "State"), Some("AccessDenied"));
+        // Rows missing from the refetch keep their errors
+        assert_eq!(cell_error(&items[2], "Targets"), Some("Throttling"));
+        assert!(has_cell_errors(&items));
+
+        let retried = vec![
+            json!({ "Name": "b", "State": "ENABLED" }),
+            json!({ "Name": "c", "Targets": "1" }),
+        ];
+        merge_retried_cells(&mut items, &retried, "Name");
+        assert!(!has_cell_errors(&items));
+        assert_eq!(items[1]["State"], "ENABLED");
+    }
+
+    #[test]
+    fn test_pagination_absorb_following() {
+        let mut pagination = PaginationState::default();
+        for n in 1..=3 {
+            pagination.next_token = Some(format!("t{}", n + 1));
+            pagination.cache_current(&[serde_json::json!({ "page": n })]);
+            pagination.advance();
+        }
+        // Back on page 3 with pages 1, 2 and 4 cached, then load everything after it
+        pagination.cache_current(&[serde_json::json!({ "page": 4 })]);
+        pagination.go_back();
+        pagination.take_cached();
+        pagination.absorb_following(Some("t9".to_string()));
+        assert_eq!(pagination.current_page, 3);
+        assert!(pagination.has_more);
+        assert_eq!(pagination.next_token.as_deref(), Some("t9"));
+        assert_eq!(
+            pagination.cached_pages.keys().copied().collect::<Vec<_>>(),
+            vec![1, 2]
+        );
+
+        pagination.absorb_following(None);
+        assert!(!pagination.has_more);
+        assert!(pagination.next_token.is_none());
+    }
+
+    #[test]
+    fn test_pagination_cache_window() {
+        let mut pagination = PaginationState::default();
+        let page = |n: usize| vec![serde_json::json!({ "page": n })];
+
+        // Page forward to page 8, caching each page left behind
+        for n in 1..8 {
+            pagination.next_token = Some(format!("t{}", n + 1));
+            pagination.cache_current(&page(n));
+            assert_eq!(pagination.advance(), Some(format!("t{}", n + 1)));
+            assert!(pagination.take_cached().is_none());
+        }
+        assert_eq!(pagination.current_page, 8);
+        pagination.next_token = None;
+
+        // Going back is served from the cache, with the next token it had
+        pagination.cache_current(&page(8));
+        assert_eq!(pagination.go_back(), Some("t7".to_string()));
+        let cached = pagination.take_cached().expect("page 7 is cached");
+        assert_eq!(cached.items, page(7));
+        assert!(pagination.has_more);
+        assert_eq!(pagination.next_token.as_deref(), Some("t8"));
+
+        // Only pages within the window around the current page are kept
+        assert_eq!(
+            pagination.cached_pages.keys().copied().collect::<Vec<_>>(),
+            vec![3, 4, 5, 6, 8]
+        );
+
+        // Back to the first page, which is fetched without a token
+        for _ in 0..6 {
+            pagination.go_back();
+        }
+        assert_eq!(pagination.current_page, 1);
+        assert_eq!(pagination.current_token, None);
+        assert!(pagination.token_stack.is_empty());
+    }
+
+    #[test]
+    fn test_sort_rows() {
+        let mut rows = vec![
+            serde_json::json!({ "Name": "beta", "Size": "10" }),
+            serde_json::json!({ "Name": "Alpha", "Size": "-" }),
+            serde_json::json!({ "Name": "gamma", "Size": "9" }),
+            serde_json::json!({ "Name": "delta", "Size": "100" }),
+        ];
+        let names = |rows: &[Value]| -> Vec<String> {
+            rows.iter()
+                .map(|r| r["Name"].as_str().unwrap().to_string())
+                .collect()
+        };
+
+        sort_rows(&mut rows, "Size", false);
+        assert_eq!(names(&rows), ["gamma", "beta", "delta", "Alpha"]);
+        sort_rows(&mut rows, "Size", true);
+        assert_eq!(names(&rows), ["delta", "beta", "gamma", "Alpha"]);
+        sort_rows(&mut rows, "Name", false);
+        assert_eq!(names(&rows), ["Alpha", "beta", "delta", "gamma"]);
+    }
+
+    #[test]
+    fn test_mask_fields() {
+        let data = serde_json::json!({ "Name": "db", "SecretString": "hunter2" });
+        let masked = mask_fields(
+            &data,
+            &["SecretString".to_string(), "SecretBinary".to_string()],
+        );
+        assert_eq!(masked["Name"], "db");
+        assert_ne!(masked["SecretString"], "hunter2");
+        assert!(masked.get("SecretBinary").is_none());
+
+        let parameter =
+            serde_json::json!({ "Parameter": { "Name": "/db/password", "Value": "hunter2" } });
+        let masked = mask_fields(&parameter, &["Parameter.Value".to_string()]);
+        assert_eq!(masked["Parameter"]["Name"], "/db/password");
+        assert_ne!(masked["Parameter"]["Value"], "hunter2");
+    }
+
+    #[test]
+    fn test_expand_cell_value() {
+        assert_eq!(
+            expand_cell_value("%7B%22Version%22%3A%222012-10-17%22%7D"),
+            "{\n  \"Version\": \"2012-10-17\"\n}"
+        );
+        assert_eq!(expand_cell_value("[1]"), "[\n  1\n]");
+        assert_eq!(
+            expand_cell_value("arn:aws:iam::123456789012:role/Ops"),
+            "arn:aws:iam::123456789012:role/Ops"
+        );
+        assert_eq!(expand_cell_value("42"), "42");
+    }
+
+    #[test]
+    fn test_row_matches_target() {
+        let queues = get_resource("sqs-queues").unwrap();
+        let queue = serde_json::json!({
+            "QueueUrl": "https://sqs.us-east-1.amazonaws.com/111122223333/orders"
+        });
+        assert!(row_matches_target(&queue, queues, "orders"));
+        assert!(!row_matches_target(&queue, queues, "ord"));
+
+        let instances = get_resource("ec2-instances").unwrap();
+        let instance = serde_json::json!({ "InstanceId": "i-0abc" });
+        assert!(row_matches_target(&instance, instances, "i-0abc"));
+    }
+
+    #[test]
+    fn test_row_arn() {
+        let queues = get_resource("sqs-queues").unwrap();
+        let queue = serde_json::json!({
+            "QueueUrl": "https://sqs.us-east-1.amazonaws.com/111122223333/orders",
+            "DeadLetterArn": "arn:aws:lambda:us-east-1:111122223333:function:f",
+            "QueueArn": "arn:aws:sqs:us-east-1:111122223333:orders"
+        });
+        assert_eq!(
+            row_arn(&queue, queues).as_deref(),
+            Some("arn:aws:sqs:us-east-1:111122223333:orders")
+        );
+
+        let secrets = get_resource("secretsmanager-secrets").unwrap();
+        let secret = serde_json::json!({ "ARN": "arn:aws:secretsmanager:us-east-1:1:secret:db" });
+        assert!(row_arn(&secret, secrets).is_some());
+
+        let instances = get_resource("ec2-instances").unwrap();
+        assert_eq!(
+            row_arn(&serde_json::json!({ "InstanceId": "i-0abc" }), instances),
+            None
+        );
+    }
+
+    #[test]
+    fn test_sort_by_open_count() {
+        let mut config = Config::default();
+        config.usage.open_counts = std::collections::BTreeMap::from([
+            ("lambda-functions".to_string(), 3),
+            ("sqs-queues".to_string(), 9),
+        ]);
+        let commands = || {
+            [
+                "ec2-instances",
+                "lambda-functions",
+                "profiles",
+                "sqs-queues",
+            ]
+            .map(String::from)
+            .to_vec()
+        };
+
+        let mut adaptive = commands();
+        sort_by_open_count(&mut adaptive, &config);
+        assert_eq!(
+            adaptive,
+            [
+                "sqs-queues",
+                "lambda-functions",
+                "ec2-instances",
+                "profiles"
+            ]
+        );
+
+        config.usage.adaptive_ordering = false;
+        let mut alphabetical = commands();
+        sort_by_open_count(&mut alphabetical, &config);
+        assert_eq!(alphabetical, commands());
+    }
+
+    #[test]
+    fn test_picker_line() {
+        let instances = get_resource("ec2-instances").unwrap();
+        let named = serde_json::json!({ "InstanceId": "i-0abc", "Tags": { "Name": "web" } });
+        let line = picker_line(&named, instances);
+        assert_eq!(line, "i-0abc\tweb");
+        assert_eq!(picked_id(&line), "i-0abc");
+
+        let unnamed = serde_json::json!({ "InstanceId": "i-0def" });
+        assert_eq!(picker_line(&unnamed, instances), "i-0def");
+        assert_eq!(picked_id("i-0def"), "i-0def");
+    }
+
+    #[test]
+    fn test_sqs_queue_from_arn() {
+        assert_eq!(
+            sqs_queue_from_arn("arn:aws:sqs:eu-west-1:111122223333:orders-dlq.fifo"),
+            Some(("eu-west-1".to_string(), "orders-dlq.fifo".to_string()))
+        );
+        assert_eq!(
+            sqs_queue_from_arn("arn:aws:sns:eu-west-1:111122223333:failures"),
+            None
+        );
+        assert_eq!(sqs_queue_from_arn("-"), None);
+    }
+
     #[test]
     fn test_parse_aws_filters_valid() {
         let result = AwsFilters::parse("Filters: owner=amazon, architecture=arm64");
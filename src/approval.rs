@@ -0,0 +1,473 @@
+//! Two-person approval for destructive actions
+//!
+//! With approval mode enabled, confirming a destructive action writes an
+//! action request file instead of executing it. A second operator approves the
+//! file with `taws approve <file>`, and the requesting session then executes it.
+//!
+//! The approver proves who they are with a presigned STS GetCallerIdentity
+//! request that only their own credentials can produce. The requesting session
+//! sends it to STS itself, so AWS (not a shared secret) vouches for the
+//! approver. The proof is bound to the request's digest, and the requesting
+//! session runs its own stored copy of the request, so an edited request file
+//! can't change what is executed.
+
+use crate::aws::client::AwsClients;
+use crate::jobs::JobWatch;
+use crate::resource::FormField;
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Longest validity of a presigned identity proof (SigV4 query signing limit)
+const MAX_PROOF_SECONDS: i64 = 7 * 24 * 3600;
+
+/// A destructive action waiting for (or carrying) a second operator's approval
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActionRequest {
+    pub id: String,
+    /// What the action does, as shown in the confirmation dialog
+    pub description: String,
+    pub service: String,
+    pub sdk_method: String,
+    pub resource_ids: Vec<String>,
+    /// Submitted values of a form action (None for actions without a form)
+    #[serde(default)]
+    pub form_fields: Option<Vec<FormField>>,
+    pub profile: String,
+    pub region: String,
+    /// Role or user ARN of the requesting operator
+    pub requested_by: String,
+    /// RFC 3339 timestamps
+    pub requested_at: String,
+    pub expires_at: String,
+    /// SHA-256 of the request's content
+    pub digest: String,
+    #[serde(default)]
+    pub approval: Option<Approval>,
+    #[serde(default)]
+    pub executed_at: Option<String>,
+    /// Job to watch for completion notifications once the action ran
+    #[serde(default)]
+    pub watch: Option<JobWatch>,
+}
+
+/// A second operator's sign-off on a request
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Approval {
+    /// Role or user ARN of the approving operator
+    pub approved_by: String,
+    pub approved_at: String,
+    /// Presigned STS GetCallerIdentity URL signed by the approver and bound to
+    /// this request
+    pub identity_proof: String,
+}
+
+/// Fields of a request that are not derived from its content
+pub struct RequestDraft<'a> {
+    pub description: &'a str,
+    pub service: &'a str,
+    pub sdk_method: &'a str,
+    pub resource_ids: &'a [String],
+    pub form_fields: Option<&'a [FormField]>,
+    pub profile: &'a str,
+    pub region: &'a str,
+    pub requested_by: &'a str,
+}
+
+impl ActionRequest {
+    /// Create a request valid for `expiry_minutes`
+    pub fn new(
+        draft: RequestDraft<'_>,
+        now: chrono::DateTime<chrono::Utc>,
+        expiry_minutes: i64,
+    ) -> Self {
+        let requested_at = now.to_rfc3339();
+        let id_source = format!(
+            "{}\n{}\n{}",
+            draft.requested_by,
+            requested_at,
+            draft.resource_ids.join(",")
+        );
+        let mut request = Self {
+            id: hex(&Sha256::digest(id_source.as_bytes()))[..12].to_string(),
+            description: draft.description.to_string(),
+            service: draft.service.to_string(),
+            sdk_method: draft.sdk_method.to_string(),
+            resource_ids: draft.resource_ids.to_vec(),
+            form_fields: draft.form_fields.map(|fields| fields.to_vec()),
+            profile: draft.profile.to_string(),
+            region: draft.region.to_string(),
+            requested_by: normalize_identity(draft.requested_by),
+            requested_at,
+            expires_at: (now + chrono::Duration::minutes(expiry_minutes)).to_rfc3339(),
+            digest: String::new(),
+            approval: None,
+            executed_at: None,
+            watch: None,
+        };
+        request.digest = hex(&Sha256::digest(request.payload().as_bytes()));
+        request
+    }
+
+    /// Content the digest covers
+    fn payload(&self) -> String {
+        let form_fields = self
+            .form_fields
+            .iter()
+            .flatten()
+            .map(|f| format!("{}={}", f.name, f.value))
+            .collect::<Vec<_>>()
+            .join("\n");
+        [
+            self.id.as_str(),
+            &self.description,
+            &self.service,
+            &self.sdk_method,
+            &self.resource_ids.join(","),
+            &form_fields,
+            &self.profile,
+            &self.region,
+            &self.requested_by,
+            &self.requested_at,
+            &self.expires_at,
+        ]
+        .join("\n")
+    }
+
+    /// Value an approval's identity proof is bound to (this request's digest,
+    /// the approver and the approval time)
+    fn approval_binding(&self, approved_by: &str, approved_at: &str) -> String {
+        hex(&Sha256::digest(
+            format!("{}\n{}\n{}", self.digest, approved_by, approved_at).as_bytes(),
+        ))
+    }
+
+    /// Check the request digest and expiry
+    pub fn verify(&self, now: chrono::DateTime<chrono::Utc>) -> Result<()> {
+        if hex(&Sha256::digest(self.payload().as_bytes())) != self.digest {
+            return Err(anyhow!("Invalid request: the file was modified"));
+        }
+        let expires_at = chrono::DateTime::parse_from_rfc3339(&self.expires_at)
+            .map_err(|e| anyhow!("Invalid expiry: {}", e))?;
+        if now > expires_at {
+            return Err(anyhow!(
+                "Request {} expired at {}",
+                self.id,
+                self.expires_at
+            ));
+        }
+        Ok(())
+    }
+
+    /// Approve as the identity `clients` are signed in as, who must not be the
+    /// requester (and must be listed in `approvers` when that isn't empty)
+    pub async fn approve(
+        &mut self,
+        clients: &AwsClients,
+        approvers: &[String],
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> Result<()> {
+        self.verify(now)?;
+        if self.approval.is_some() {
+            return Err(anyhow!("Request {} is already approved", self.id));
+        }
+        let approver = normalize_identity(&caller_arn(clients).await?);
+        check_approver(&self.requested_by, &approver, approvers)?;
+
+        let approved_at = now.to_rfc3339();
+        let expires_at = chrono::DateTime::parse_from_rfc3339(&self.expires_at)
+            .map_err(|e| anyhow!("Invalid expiry: {}", e))?;
+        let valid_for = (expires_at.with_timezone(&chrono::Utc) - now)
+            .num_seconds()
+            .clamp(60, MAX_PROOF_SECONDS);
+        let identity_proof = clients.http.presign_caller_identity(
+            &self.approval_binding(&approver, &approved_at),
+            std::time::Duration::from_secs(valid_for as u64),
+        )?;
+        self.approval = Some(Approval {
+            approved_by: approver,
+            approved_at,
+            identity_proof,
+        });
+        Ok(())
+    }
+
+    /// Approver of this request whose identity proof STS accepted, if it has one
+    pub async fn verified_approver(
+        &self,
+        clients: &AwsClients,
+        approvers: &[String],
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> Result<Option<String>> {
+        self.verify(now)?;
+        let Some(approval) = &self.approval else {
+            return Ok(None);
+        };
+        let signer = clients
+            .http
+            .verify_presigned_identity(
+                &approval.identity_proof,
+                &self.approval_binding(&approval.approved_by, &approval.approved_at),
+            )
+            .await
+            .map_err(|e| anyhow!("Invalid approval on request {}: {}", self.id, e))?;
+        let signer = normalize_identity(&signer);
+        if signer != approval.approved_by {
+            return Err(anyhow!(
+                "Invalid approval on request {}: signed by {}, not {}",
+                self.id,
+                signer,
+                approval.approved_by
+            ));
+        }
+        check_approver(&self.requested_by, &signer, approvers)?;
+        Ok(Some(signer))
+    }
+
+    /// Read a request file
+    pub fn load(path: &Path) -> Result<Self> {
+        let contents = fs::read_to_string(path)
+            .map_err(|e| anyhow!("Failed to read {}: {}", path.display(), e))?;
+        Ok(serde_json::from_str(&contents)?)
+    }
+
+    /// Write the request file
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+}
+
+/// A request this operator made, waiting for approval across restarts
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PendingRequest {
+    /// Request file the approver signs
+    pub path: PathBuf,
+    /// Copy of the request as made; this is what runs once approved
+    pub request: ActionRequest,
+}
+
+impl PendingRequest {
+    /// Approval from the request file, if its request is the one that was made
+    pub fn approved_request(&self) -> Result<ActionRequest> {
+        let shared = ActionRequest::load(&self.path)?;
+        if shared.digest != self.request.digest {
+            return Err(anyhow!(
+                "Request file {} no longer matches request {}",
+                self.path.display(),
+                self.request.id
+            ));
+        }
+        Ok(ActionRequest {
+            approval: shared.approval,
+            ..self.request.clone()
+        })
+    }
+}
+
+/// Requests waiting for approval, as saved by `save_pending`
+pub fn load_pending() -> Vec<PendingRequest> {
+    fs::read_to_string(pending_path())
+        .ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+/// Save the requests waiting for approval
+pub fn save_pending(pending: &[PendingRequest]) -> Result<()> {
+    let path = pending_path();
+    if pending.is_empty() {
+        if path.exists() {
+            fs::remove_file(&path)?;
+        }
+        return Ok(());
+    }
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&path, serde_json::to_string_pretty(pending)?)?;
+    Ok(())
+}
+
+/// Role or user an identity ARN belongs to: assumed-role sessions become their
+/// role ("arn:aws:sts::1:assumed-role/Ops/alice" -> "arn:aws:iam::1:role/Ops"),
+/// so another session of the same role counts as the same identity
+pub fn normalize_identity(arn: &str) -> String {
+    let parts: Vec<&str> = arn.splitn(6, ':').collect();
+    let [_, partition, "sts", _, account, resource] = parts.as_slice() else {
+        return arn.to_string();
+    };
+    match resource.strip_prefix("assumed-role/") {
+        Some(rest) => {
+            let role = rest.split('/').next().unwrap_or(rest);
+            format!("arn:{}:iam::{}:role/{}", partition, account, role)
+        }
+        None => arn.to_string(),
+    }
+}
+
+/// Check that `approver` may approve a request made by `requested_by`
+fn check_approver(requested_by: &str, approver: &str, approvers: &[String]) -> Result<()> {
+    if approver == normalize_identity(requested_by) {
+        return Err(anyhow!(
+            "Requests must be approved by a different identity than {}",
+            approver
+        ));
+    }
+    if !approvers.is_empty() && !approvers.iter().any(|a| normalize_identity(a) == approver) {
+        return Err(anyhow!("{} is not an allowed approver", approver));
+    }
+    Ok(())
+}
+
+/// ARN of the identity the clients are signed in as
+pub async fn caller_arn(clients: &AwsClients) -> Result<String> {
+    let response = crate::resource::invoke_sdk(
+        "sts",
+        "get_caller_identity",
+        clients,
+        &serde_json::json!({}),
+    )
+    .await?;
+    let arn = crate::resource::extract_json_value(&response, "identity.0.Arn");
+    if arn.is_empty() || arn == "-" {
+        return Err(anyhow!("Could not determine caller identity"));
+    }
+    Ok(arn)
+}
+
+/// Directory request files are written to
+pub fn requests_dir() -> PathBuf {
+    dirs::config_dir()
+        .or_else(|| dirs::home_dir().map(|h| h.join(".taws")))
+        .unwrap_or_else(|| PathBuf::from(".taws"))
+        .join("taws")
+        .join("requests")
+}
+
+/// File listing this operator's requests that wait for approval
+fn pending_path() -> PathBuf {
+    requests_dir().with_file_name("pending-approvals.json")
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(now: chrono::DateTime<chrono::Utc>) -> ActionRequest {
+        let ids = vec!["i-0123456789abcdef0".to_string()];
+        ActionRequest::new(
+            RequestDraft {
+                description: "Terminate instance 'web-1'?",
+                service: "ec2",
+                sdk_method: "terminate_instance",
+                resource_ids: &ids,
+                form_fields: None,
+                profile: "prod",
+                region: "eu-west-1",
+                requested_by: "arn:aws:sts::123456789012:assumed-role/Ops/alice",
+            },
+            now,
+            60,
+        )
+    }
+
+    #[test]
+    fn test_request_digest_detects_tampering() {
+        let now = chrono::Utc::now();
+        let mut req = request(now);
+        assert!(req.verify(now).is_ok());
+        assert_eq!(req.requested_by, "arn:aws:iam::123456789012:role/Ops");
+
+        req.resource_ids = vec!["i-ffffffffffffffff0".to_string()];
+        assert!(req.verify(now).is_err());
+
+        let mut form = request(now);
+        form.form_fields = Some(vec![FormField {
+            name: "StartAfter".to_string(),
+            value: "yes".to_string(),
+            ..Default::default()
+        }]);
+        assert!(form.verify(now).is_err());
+    }
+
+    #[test]
+    fn test_request_expires() {
+        let now = chrono::Utc::now();
+        let req = request(now);
+        assert!(req.verify(now + chrono::Duration::minutes(61)).is_err());
+    }
+
+    #[test]
+    fn test_normalize_identity() {
+        assert_eq!(
+            normalize_identity("arn:aws:sts::123456789012:assumed-role/Ops/alice"),
+            "arn:aws:iam::123456789012:role/Ops"
+        );
+        assert_eq!(
+            normalize_identity("arn:aws-cn:sts::123456789012:assumed-role/Ops/bob"),
+            "arn:aws-cn:iam::123456789012:role/Ops"
+        );
+        assert_eq!(
+            normalize_identity("arn:aws:iam::123456789012:user/ops/carol"),
+            "arn:aws:iam::123456789012:user/ops/carol"
+        );
+    }
+
+    #[test]
+    fn test_approval_requires_second_identity() {
+        let requester = "arn:aws:iam::123456789012:role/Ops";
+        // Another session of the requester's role is the same identity
+        assert!(check_approver(
+            requester,
+            &normalize_identity("arn:aws:sts::123456789012:assumed-role/Ops/other-session"),
+            &[]
+        )
+        .is_err());
+        assert!(check_approver(requester, "arn:aws:iam::123456789012:role/Lead", &[]).is_ok());
+
+        let approvers = vec!["arn:aws:iam::123456789012:role/Lead".to_string()];
+        assert!(
+            check_approver(requester, "arn:aws:iam::123456789012:role/Lead", &approvers).is_ok()
+        );
+        assert!(
+            check_approver(requester, "arn:aws:iam::123456789012:user/eve", &approvers).is_err()
+        );
+    }
+
+    #[test]
+    fn test_pending_request_runs_its_own_copy() {
+        let dir = tempfile::tempdir().unwrap();
+        let now = chrono::Utc::now();
+        let pending = PendingRequest {
+            path: dir.path().join("request.json"),
+            request: request(now),
+        };
+
+        let mut shared = pending.request.clone();
+        shared.approval = Some(Approval {
+            approved_by: "arn:aws:iam::123456789012:role/Lead".to_string(),
+            approved_at: now.to_rfc3339(),
+            identity_proof: "https://sts.amazonaws.com/?Action=GetCallerIdentity".to_string(),
+        });
+        shared.save(&pending.path).unwrap();
+        let approved = pending.approved_request().unwrap();
+        assert_eq!(approved.approval, shared.approval);
+        assert_eq!(approved.resource_ids, pending.request.resource_ids);
+
+        // A request file changed to target something else is rejected
+        shared.resource_ids = vec!["i-ffffffffffffffff0".to_string()];
+        shared.digest = hex(&Sha256::digest(shared.payload().as_bytes()));
+        shared.save(&pending.path).unwrap();
+        assert!(pending.approved_request().is_err());
+    }
+}
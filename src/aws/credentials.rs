@@ -9,7 +9,9 @@
 //! - ECS container credentials (via credential_source = EcsContainer)
 //! - IMDSv2 (EC2 instance metadata)
 
+use super::ini::IniFile;
 use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::env;
 use std::fs;
@@ -76,7 +78,7 @@ static SSO_CACHE: OnceLock<std::sync::Mutex<HashMap<String, CachedImdsCredential
 static PROCESS_CACHE: OnceLock<std::sync::Mutex<HashMap<String, CachedImdsCredentials>>> =
     OnceLock::new();
 
-/// Global cache for Assume Role credentials (keyed by profile name)
+/// Global cache for Assume Role credentials (keyed by profile, role and session name)
 static ASSUME_ROLE_CACHE: OnceLock<std::sync::Mutex<HashMap<String, CachedImdsCredentials>>> =
     OnceLock::new();
 
@@ -98,6 +100,19 @@ const IMDS_TOKEN_TTL: u64 = 21600;
 const IMDS_TIMEOUT: Duration = Duration::from_secs(2);
 /// Refresh credentials 5 minutes before expiration
 const CREDENTIAL_REFRESH_BUFFER: Duration = Duration::from_secs(300);
+/// Role session name when neither the profile nor config.yaml sets one
+const DEFAULT_ROLE_SESSION_NAME: &str = "taws-session";
+
+/// Session name template from config.yaml (`role_session_name`)
+static ROLE_SESSION_NAME_TEMPLATE: std::sync::RwLock<Option<String>> = std::sync::RwLock::new(None);
+
+/// Set the role session name template used when a profile has no
+/// `role_session_name` ("{user}", "{host}" and "{profile}" are replaced)
+pub fn set_role_session_name_template(template: Option<String>) {
+    if let Ok(mut guard) = ROLE_SESSION_NAME_TEMPLATE.write() {
+        *guard = template;
+    }
+}
 
 /// Load credentials for a given profile
 pub fn load_credentials(profile: &str) -> Result<Credentials> {
@@ -309,44 +324,15 @@ pub fn get_aws_config_file_path() -> Result<PathBuf> {
         .ok_or_else(|| anyhow!("Could not find home directory"))
 }
 
-/// Parse an INI-style file into sections
-/// Returns (profiles, sso_sessions) where sso_sessions contains [sso-session X] sections
+/// Parse a config or credentials file into sections keyed by profile name
+/// (other sections keep their type prefix, e.g. "sso-session corp")
 fn parse_ini_file(content: &str) -> HashMap<String, HashMap<String, String>> {
-    let mut sections: HashMap<String, HashMap<String, String>> = HashMap::new();
-    let mut current_section = String::new();
-
-    for line in content.lines() {
-        let line = line.trim();
-
-        // Skip empty lines and comments
-        if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
-            continue;
-        }
-
-        // Section header
-        if line.starts_with('[') && line.ends_with(']') {
-            current_section = line[1..line.len() - 1].trim().to_string();
-            // Handle "profile name" format in config file
-            if current_section.starts_with("profile ") {
-                current_section = current_section["profile ".len()..].to_string();
-            }
-            // Keep sso-session sections with their prefix for identification
-            sections.entry(current_section.clone()).or_default();
-            continue;
-        }
-
-        // Key-value pair
-        if let Some((key, value)) = line.split_once('=') {
-            if !current_section.is_empty() {
-                sections
-                    .entry(current_section.clone())
-                    .or_default()
-                    .insert(key.trim().to_string(), value.trim().to_string());
-            }
-        }
-    }
+    IniFile::parse(content).flat_sections()
+}
 
-    sections
+/// Parse a credentials file into sections keyed by the literal header
+fn parse_credentials_file(content: &str) -> HashMap<String, HashMap<String, String>> {
+    IniFile::parse_credentials(content).flat_sections()
 }
 
 /// Load credentials from ~/.aws/credentials or AWS_SHARED_CREDENTIALS_FILE
@@ -360,7 +346,7 @@ fn load_from_credentials_file(profile: &str) -> Result<Credentials> {
     let content =
         fs::read_to_string(&creds_path).map_err(|_| anyhow!("Could not read {:?}", creds_path))?;
 
-    let sections = parse_ini_file(&content);
+    let sections = parse_credentials_file(&content);
 
     let section = sections
         .get(profile)
@@ -889,7 +875,7 @@ pub fn list_profiles() -> Vec<String> {
     if let Ok(config_dir) = aws_config_dir() {
         // Read from credentials file
         if let Ok(content) = fs::read_to_string(config_dir.join("credentials")) {
-            let sections = parse_ini_file(&content);
+            let sections = parse_credentials_file(&content);
             profiles.extend(sections.keys().cloned());
         }
 
@@ -1011,10 +997,11 @@ fn parse_credential_source(value: &str) -> Option<CredentialSource> {
 /// Load credentials by assuming a role using source profile or credential_source
 fn load_from_assume_role(profile: &str, config: &AssumeRoleConfig) -> Result<Credentials> {
     // Check cache first
+    let key = role_credentials_key(profile, config);
     let cache = ASSUME_ROLE_CACHE.get_or_init(|| std::sync::Mutex::new(HashMap::new()));
 
     if let Ok(guard) = cache.lock() {
-        if let Some(cached) = guard.get(profile) {
+        if let Some(cached) = guard.get(&key) {
             if cached.expiration > Instant::now() + CREDENTIAL_REFRESH_BUFFER {
                 trace!(
                     "Using cached assume role credentials for profile '{}'",
@@ -1025,6 +1012,24 @@ fn load_from_assume_role(profile: &str, config: &AssumeRoleConfig) -> Result<Cre
         }
     }
 
+    // Then credentials persisted by an earlier session, if the keychain is on
+    if let Some((credentials, expiration)) = load_persisted_role_credentials(&key) {
+        if let Ok(mut guard) = cache.lock() {
+            guard.insert(
+                key.clone(),
+                CachedImdsCredentials {
+                    credentials: credentials.clone(),
+                    expiration,
+                },
+            );
+        }
+        debug!(
+            "Using assume role credentials from the OS credential store for profile '{}'",
+            profile
+        );
+        return Ok(credentials);
+    }
+
     // Load source credentials based on configuration
     let source_creds = if let Some(ref source_profile) = config.source_profile {
         // Recursively load credentials from source profile
@@ -1067,12 +1072,13 @@ fn load_from_assume_role(profile: &str, config: &AssumeRoleConfig) -> Result<Cre
         .unwrap_or_else(|| "us-east-1".to_string());
 
     // Call STS AssumeRole
-    let (credentials, expiration) = call_sts_assume_role(config, &source_creds, &region)?;
+    let (credentials, expiration) = call_sts_assume_role(profile, config, &source_creds, &region)?;
+    persist_role_credentials(&key, &credentials, expiration);
 
     // Cache the credentials
     if let Ok(mut guard) = cache.lock() {
         guard.insert(
-            profile.to_string(),
+            key.clone(),
             CachedImdsCredentials {
                 credentials: credentials.clone(),
                 expiration,
@@ -1088,6 +1094,67 @@ fn load_from_assume_role(profile: &str, config: &AssumeRoleConfig) -> Result<Cre
     Ok(credentials)
 }
 
+/// Assumed-role credentials as stored in the OS credential store
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct PersistedCredentials {
+    access_key_id: String,
+    secret_access_key: String,
+    session_token: Option<String>,
+    /// Unix seconds
+    expiration: i64,
+}
+
+/// Cache key of assumed-role credentials; a different role or session name
+/// for the same profile (e.g., another context) must not reuse them
+fn role_credentials_key(profile: &str, config: &AssumeRoleConfig) -> String {
+    format!(
+        "assume-role:{}|{}|{}",
+        profile,
+        config.role_arn,
+        role_session_name(profile, config)
+    )
+}
+
+/// Keep assumed-role credentials across sessions (credential_store: keychain)
+fn persist_role_credentials(key: &str, credentials: &Credentials, expiration: Instant) {
+    if !super::keychain::is_enabled() {
+        return;
+    }
+    let remaining = expiration.saturating_duration_since(Instant::now());
+    let persisted = PersistedCredentials {
+        access_key_id: credentials.access_key_id.clone(),
+        secret_access_key: credentials.secret_access_key.clone(),
+        session_token: credentials.session_token.clone(),
+        expiration: chrono::Utc::now().timestamp() + remaining.as_secs() as i64,
+    };
+    if let Ok(value) = serde_json::to_string(&persisted) {
+        if let Err(e) = super::keychain::store(key, &value) {
+            debug!("Failed to store assume role credentials: {}", e);
+        }
+    }
+}
+
+/// Persisted assumed-role credentials that are still valid, with their expiry
+fn load_persisted_role_credentials(key: &str) -> Option<(Credentials, Instant)> {
+    if !super::keychain::is_enabled() {
+        return None;
+    }
+    let value = super::keychain::load(key)?;
+    let persisted: PersistedCredentials = serde_json::from_str(&value).ok()?;
+    let remaining = persisted.expiration - chrono::Utc::now().timestamp();
+    let remaining = Duration::from_secs(u64::try_from(remaining).ok()?);
+    if remaining <= CREDENTIAL_REFRESH_BUFFER {
+        return None;
+    }
+    let credentials = Credentials {
+        access_key_id: persisted.access_key_id,
+        secret_access_key: persisted.secret_access_key,
+        session_token: persisted.session_token,
+    };
+    Some((credentials, Instant::now() + remaining))
+}
+
 /// Load credentials from a credential_source
 fn load_from_credential_source(source: &CredentialSource) -> Result<Credentials> {
     match source {
@@ -1106,8 +1173,71 @@ fn load_from_credential_source(source: &CredentialSource) -> Result<Credentials>
     }
 }
 
+/// Session name for an assumed role, so CloudTrail entries can be traced
+/// back to who ran taws: the profile's `role_session_name`, else the
+/// config.yaml template, else "taws-session". Both may use "{user}",
+/// "{host}" and "{profile}".
+fn role_session_name(profile: &str, config: &AssumeRoleConfig) -> String {
+    let template = config.role_session_name.clone().or_else(|| {
+        ROLE_SESSION_NAME_TEMPLATE
+            .read()
+            .ok()
+            .and_then(|guard| guard.clone())
+    });
+    match template {
+        Some(template) => expand_session_name(&template, profile, &local_user(), &local_host()),
+        None => DEFAULT_ROLE_SESSION_NAME.to_string(),
+    }
+}
+
+/// Fill in a session name template and make it a valid RoleSessionName
+/// (2-64 characters of `[\w+=,.@-]`)
+fn expand_session_name(template: &str, profile: &str, user: &str, host: &str) -> String {
+    let expanded = template
+        .replace("{user}", user)
+        .replace("{host}", host)
+        .replace("{profile}", profile);
+    let name: String = expanded
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || "_+=,.@-".contains(c) {
+                c
+            } else {
+                '-'
+            }
+        })
+        .take(64)
+        .collect();
+    if name.len() < 2 {
+        DEFAULT_ROLE_SESSION_NAME.to_string()
+    } else {
+        name
+    }
+}
+
+fn local_user() -> String {
+    env::var("USER")
+        .or_else(|_| env::var("USERNAME"))
+        .unwrap_or_else(|_| "unknown".to_string())
+}
+
+fn local_host() -> String {
+    env::var("HOSTNAME")
+        .or_else(|_| env::var("COMPUTERNAME"))
+        .ok()
+        .or_else(|| {
+            fs::read_to_string("/etc/hostname")
+                .ok()
+                .map(|name| name.trim().to_string())
+        })
+        .filter(|name| !name.is_empty())
+        .map(|name| name.split('.').next().unwrap_or(&name).to_string())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
 /// Call STS AssumeRole API using signed HTTP request
 fn call_sts_assume_role(
+    profile: &str,
     config: &AssumeRoleConfig,
     source_creds: &Credentials,
     region: &str,
@@ -1117,10 +1247,7 @@ fn call_sts_assume_role(
     use aws_smithy_runtime_api::client::identity::Identity;
     use std::time::SystemTime;
 
-    let role_session_name = config
-        .role_session_name
-        .clone()
-        .unwrap_or_else(|| "taws-session".to_string());
+    let role_session_name = role_session_name(profile, config);
     let duration_seconds = config.duration_seconds.unwrap_or(3600);
 
     // Build STS endpoint - respect AWS_ENDPOINT_URL or TAWS_STS_ENDPOINT for LocalStack/testing
@@ -1802,6 +1929,51 @@ aws_secret_access_key = secret_dev
         assert!(error_msg.contains("not authorized"));
     }
 
+    #[test]
+    fn test_expand_session_name() {
+        assert_eq!(
+            expand_session_name("taws-{user}-{host}", "prod", "jane.doe", "laptop"),
+            "taws-jane.doe-laptop"
+        );
+        // Characters STS rejects are replaced, and the name is capped at 64
+        assert_eq!(
+            expand_session_name("{user}/{profile}", "prod admin", "DOMAIN\\jane", "h"),
+            "DOMAIN-jane-prod-admin"
+        );
+        assert_eq!(
+            expand_session_name(&"x".repeat(80), "p", "u", "h").len(),
+            64
+        );
+        assert_eq!(expand_session_name("{user}", "p", "", "h"), "taws-session");
+    }
+
+    #[test]
+    fn test_role_credentials_key() {
+        let config = AssumeRoleConfig {
+            role_arn: "arn:aws:iam::123456789012:role/ReadOnly".to_string(),
+            source_profile: Some("default".to_string()),
+            credential_source: None,
+            external_id: None,
+            role_session_name: Some("ops".to_string()),
+            duration_seconds: None,
+            region: None,
+        };
+        let key = role_credentials_key("prod", &config);
+
+        // Another role or session name for the same profile gets its own entry
+        let other_role = AssumeRoleConfig {
+            role_arn: "arn:aws:iam::123456789012:role/Admin".to_string(),
+            ..config.clone()
+        };
+        assert_ne!(role_credentials_key("prod", &other_role), key);
+        let other_session = AssumeRoleConfig {
+            role_session_name: Some("oncall".to_string()),
+            ..config.clone()
+        };
+        assert_ne!(role_credentials_key("prod", &other_session), key);
+        assert_eq!(role_credentials_key("prod", &config), key);
+    }
+
     #[test]
     fn test_assume_role_cache_is_profile_aware() {
         let cache = ASSUME_ROLE_CACHE.get_or_init(|| std::sync::Mutex::new(HashMap::new()));
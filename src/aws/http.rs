@@ -3,7 +3,10 @@
 //! Replaces 55 AWS SDK crates with a single HTTP client
 
 use anyhow::{anyhow, Result};
-use aws_sigv4::http_request::{sign, SignableBody, SignableRequest, SigningSettings};
+use aws_sigv4::http_request::{
+    sign, PercentEncodingMode, SignableBody, SignableRequest, SignatureLocation, SigningSettings,
+    UriPathNormalizationMode,
+};
 use aws_sigv4::sign::v4::SigningParams;
 use aws_smithy_runtime_api::client::identity::Identity;
 use reqwest::Client;
@@ -15,6 +18,30 @@ use super::credentials::Credentials;
 
 const EUSC_PREFIX: &str = "eusc-";
 
+/// Signed header binding a presigned caller identity request to one purpose
+const IDENTITY_BINDING_HEADER: &str = "x-taws-binding";
+
+/// Whether a host is an STS endpoint ("sts.amazonaws.com", "sts.eu-west-1.amazonaws.com",
+/// "sts.cn-north-1.amazonaws.com.cn", ...), checked strictly since other
+/// `amazonaws.com` hosts (e.g., S3 buckets) serve customer content
+fn is_sts_host(host: &str) -> bool {
+    let labels: Vec<&str> = host.split('.').collect();
+    let is_region = |label: &str| {
+        label.contains('-')
+            && label.ends_with(|c: char| c.is_ascii_digit())
+            && label
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+    };
+    match labels.as_slice() {
+        ["sts", "amazonaws", "com"] => true,
+        ["sts", region, "amazonaws", "com" | "eu"] | ["sts", region, "amazonaws", "com", "cn"] => {
+            is_region(region)
+        }
+        _ => false,
+    }
+}
+
 /// Extract region from S3 URL patterns like:
 /// - https://bucket.s3.us-west-1.amazonaws.com/
 /// - https://bucket.s3-us-west-1.amazonaws.com/
@@ -159,6 +186,14 @@ pub fn get_service(name: &str) -> Option<ServiceDefinition> {
             target_prefix: None,
             is_global: false,
         }),
+        "cloudwatch" | "monitoring" => Some(ServiceDefinition {
+            signing_name: "monitoring",
+            endpoint_prefix: "monitoring",
+            api_version: "2010-08-01",
+            protocol: Protocol::Query,
+            target_prefix: None,
+            is_global: false,
+        }),
         "cloudwatchlogs" | "logs" => Some(ServiceDefinition {
             signing_name: "logs",
             endpoint_prefix: "logs",
@@ -215,6 +250,14 @@ pub fn get_service(name: &str) -> Option<ServiceDefinition> {
             target_prefix: None,
             is_global: false,
         }),
+        "accessanalyzer" | "access-analyzer" => Some(ServiceDefinition {
+            signing_name: "access-analyzer",
+            endpoint_prefix: "access-analyzer",
+            api_version: "2019-11-01",
+            protocol: Protocol::RestJson,
+            target_prefix: None,
+            is_global: false,
+        }),
         "sts" => Some(ServiceDefinition {
             signing_name: "sts",
             endpoint_prefix: "sts",
@@ -303,6 +346,14 @@ pub fn get_service(name: &str) -> Option<ServiceDefinition> {
             target_prefix: Some("com.amazonaws.cloudtrail.v20131101.CloudTrail_20131101"),
             is_global: false,
         }),
+        "application-autoscaling" => Some(ServiceDefinition {
+            signing_name: "application-autoscaling",
+            endpoint_prefix: "application-autoscaling",
+            api_version: "2016-02-06",
+            protocol: Protocol::Json,
+            target_prefix: Some("AnyScaleFrontendService"),
+            is_global: false,
+        }),
         "autoscaling" => Some(ServiceDefinition {
             signing_name: "autoscaling",
             endpoint_prefix: "autoscaling",
@@ -319,6 +370,14 @@ pub fn get_service(name: &str) -> Option<ServiceDefinition> {
             target_prefix: None,
             is_global: false,
         }),
+        "stepfunctions" | "states" => Some(ServiceDefinition {
+            signing_name: "states",
+            endpoint_prefix: "states",
+            api_version: "2016-11-23",
+            protocol: Protocol::Json,
+            target_prefix: Some("AWSStepFunctions"),
+            is_global: false,
+        }),
         "athena" => Some(ServiceDefinition {
             signing_name: "athena",
             endpoint_prefix: "athena",
@@ -327,6 +386,103 @@ pub fn get_service(name: &str) -> Option<ServiceDefinition> {
             target_prefix: Some("AmazonAthena"),
             is_global: false,
         }),
+        "batch" => Some(ServiceDefinition {
+            signing_name: "batch",
+            endpoint_prefix: "batch",
+            api_version: "2016-08-10",
+            protocol: Protocol::RestJson,
+            target_prefix: None,
+            is_global: false,
+        }),
+        "sagemaker" => Some(ServiceDefinition {
+            signing_name: "sagemaker",
+            endpoint_prefix: "api.sagemaker",
+            api_version: "2017-07-24",
+            protocol: Protocol::Json,
+            target_prefix: Some("SageMaker"),
+            is_global: false,
+        }),
+        "config" => Some(ServiceDefinition {
+            signing_name: "config",
+            endpoint_prefix: "config",
+            api_version: "2014-11-12",
+            protocol: Protocol::Json,
+            target_prefix: Some("StarlingDoveService"),
+            is_global: false,
+        }),
+        "tagging" => Some(ServiceDefinition {
+            signing_name: "tagging",
+            endpoint_prefix: "tagging",
+            api_version: "2017-01-26",
+            protocol: Protocol::Json,
+            target_prefix: Some("ResourceGroupsTaggingAPI_20170126"),
+            is_global: false,
+        }),
+        "organizations" => Some(ServiceDefinition {
+            signing_name: "organizations",
+            endpoint_prefix: "organizations",
+            api_version: "2016-11-28",
+            protocol: Protocol::Json,
+            target_prefix: Some("AWSOrganizationsV20161128"),
+            is_global: true,
+        }),
+        "efs" => Some(ServiceDefinition {
+            signing_name: "elasticfilesystem",
+            endpoint_prefix: "elasticfilesystem",
+            api_version: "2015-02-01",
+            protocol: Protocol::RestJson,
+            target_prefix: None,
+            is_global: false,
+        }),
+        "backup" => Some(ServiceDefinition {
+            signing_name: "backup",
+            endpoint_prefix: "backup",
+            api_version: "2018-11-15",
+            protocol: Protocol::RestJson,
+            target_prefix: None,
+            is_global: false,
+        }),
+        "fsx" => Some(ServiceDefinition {
+            signing_name: "fsx",
+            endpoint_prefix: "fsx",
+            api_version: "2018-03-01",
+            protocol: Protocol::Json,
+            target_prefix: Some("AWSSimbaAPIService_v20180301"),
+            is_global: false,
+        }),
+        "servicequotas" => Some(ServiceDefinition {
+            signing_name: "servicequotas",
+            endpoint_prefix: "servicequotas",
+            api_version: "2019-06-24",
+            protocol: Protocol::Json,
+            target_prefix: Some("ServiceQuotasV20190624"),
+            is_global: false,
+        }),
+        "wafv2" => Some(ServiceDefinition {
+            signing_name: "wafv2",
+            endpoint_prefix: "wafv2",
+            api_version: "2019-07-29",
+            protocol: Protocol::Json,
+            target_prefix: Some("AWSWAF_20190729"),
+            is_global: false,
+        }),
+        // CLOUDFRONT-scoped web ACLs are only served from us-east-1
+        "wafv2-global" => Some(ServiceDefinition {
+            signing_name: "wafv2",
+            endpoint_prefix: "wafv2",
+            api_version: "2019-07-29",
+            protocol: Protocol::Json,
+            target_prefix: Some("AWSWAF_20190729"),
+            is_global: true,
+        }),
+        "glue" => Some(ServiceDefinition {
+            signing_name: "glue",
+            endpoint_prefix: "glue",
+            api_version: "2017-03-31",
+            protocol: Protocol::Json,
+            target_prefix: Some("AWSGlue"),
+            is_global: false,
+        }),
         "redshift" => Some(ServiceDefinition {
             signing_name: "redshift",
             endpoint_prefix: "redshift",
@@ -335,6 +491,78 @@ pub fn get_service(name: &str) -> Option<ServiceDefinition> {
             target_prefix: None,
             is_global: false,
         }),
+        "opensearch" => Some(ServiceDefinition {
+            signing_name: "es",
+            endpoint_prefix: "es",
+            api_version: "2021-01-01",
+            protocol: Protocol::RestJson,
+            target_prefix: None,
+            is_global: false,
+        }),
+        "kafka" => Some(ServiceDefinition {
+            signing_name: "kafka",
+            endpoint_prefix: "kafka",
+            api_version: "2018-11-14",
+            protocol: Protocol::RestJson,
+            target_prefix: None,
+            is_global: false,
+        }),
+        "mq" => Some(ServiceDefinition {
+            signing_name: "mq",
+            endpoint_prefix: "mq",
+            api_version: "2017-11-27",
+            protocol: Protocol::RestJson,
+            target_prefix: None,
+            is_global: false,
+        }),
+        "elasticbeanstalk" => Some(ServiceDefinition {
+            signing_name: "elasticbeanstalk",
+            endpoint_prefix: "elasticbeanstalk",
+            api_version: "2010-12-01",
+            protocol: Protocol::Query,
+            target_prefix: None,
+            is_global: false,
+        }),
+        "apprunner" => Some(ServiceDefinition {
+            signing_name: "apprunner",
+            endpoint_prefix: "apprunner",
+            api_version: "2020-05-15",
+            protocol: Protocol::Json,
+            target_prefix: Some("AppRunner"),
+            is_global: false,
+        }),
+        "redshift-serverless" => Some(ServiceDefinition {
+            signing_name: "redshift-serverless",
+            endpoint_prefix: "redshift-serverless",
+            api_version: "2021-04-21",
+            protocol: Protocol::Json,
+            target_prefix: Some("RedshiftServerless"),
+            is_global: false,
+        }),
+        "account" => Some(ServiceDefinition {
+            signing_name: "account",
+            endpoint_prefix: "account",
+            api_version: "2021-02-01",
+            protocol: Protocol::RestJson,
+            target_prefix: None,
+            is_global: true,
+        }),
+        "pricing" => Some(ServiceDefinition {
+            signing_name: "pricing",
+            endpoint_prefix: "api.pricing",
+            api_version: "2017-10-15",
+            protocol: Protocol::Json,
+            target_prefix: Some("AWSPriceListService"),
+            is_global: true,
+        }),
+        "support" => Some(ServiceDefinition {
+            signing_name: "support",
+            endpoint_prefix: "support",
+            api_version: "2013-04-15",
+            protocol: Protocol::Json,
+            target_prefix: Some("AWSSupport_20130415"),
+            is_global: true,
+        }),
         _ => None,
     }
 }
@@ -437,6 +665,15 @@ impl AwsHttpClient {
                 )),
                 "cloudfront" => Ok(format!("https://cloudfront.{}", domain)),
                 "route53" => Ok(format!("https://route53.{}", domain)),
+                // Global services whose only endpoint is in us-east-1
+                "account" | "support" | "wafv2" | "organizations" | "pricing"
+                    if !self.region.starts_with(EUSC_PREFIX) =>
+                {
+                    Ok(format!(
+                        "https://{}.us-east-1.{}",
+                        service.endpoint_prefix, domain
+                    ))
+                }
                 _ => Ok(format!("https://{}.{}", service.endpoint_prefix, domain)),
             };
         }
@@ -456,6 +693,108 @@ impl AwsHttpClient {
         }
     }
 
+    /// Presign an STS GetCallerIdentity request with `binding` in a signed header.
+    /// Whoever holds the URL can confirm who signed it (see
+    /// `verify_presigned_identity`), but only by sending the same binding, so
+    /// the proof can't be reused for anything else.
+    pub fn presign_caller_identity(
+        &self,
+        binding: &str,
+        expires_in: std::time::Duration,
+    ) -> Result<String> {
+        let service = get_service("sts").ok_or_else(|| anyhow!("Unknown service: sts"))?;
+        let region = self.effective_region(&service);
+        let mut url = url::Url::parse(&format!(
+            "{}/?Action=GetCallerIdentity&Version={}",
+            self.get_endpoint(&service)?,
+            service.api_version
+        ))?;
+        let host = url
+            .host_str()
+            .ok_or_else(|| anyhow!("Invalid URL"))?
+            .to_string();
+        let path_and_query = format!("{}?{}", url.path(), url.query().unwrap_or_default());
+
+        let creds = aws_credential_types::Credentials::new(
+            &self.credentials.access_key_id,
+            &self.credentials.secret_access_key,
+            self.credentials.session_token.clone(),
+            None,
+            "taws",
+        );
+        let identity: Identity = creds.into();
+        let mut settings = SigningSettings::default();
+        settings.signature_location = SignatureLocation::QueryParams;
+        settings.expires_in = Some(expires_in);
+        let signing_params = SigningParams::builder()
+            .identity(&identity)
+            .region(region)
+            .name(service.signing_name)
+            .time(SystemTime::now())
+            .settings(settings)
+            .build()?
+            .into();
+
+        let headers = [("host", host.as_str()), (IDENTITY_BINDING_HEADER, binding)];
+        let signable_request = SignableRequest::new(
+            "GET",
+            &path_and_query,
+            headers.into_iter(),
+            SignableBody::Bytes(&[]),
+        )?;
+        let (signing_instructions, _signature) =
+            sign(signable_request, &signing_params)?.into_parts();
+        {
+            let mut query = url.query_pairs_mut();
+            for (name, value) in signing_instructions.params() {
+                query.append_pair(name, value);
+            }
+        }
+        Ok(url.to_string())
+    }
+
+    /// Identity ARN that signed a URL from `presign_caller_identity` for `binding`
+    pub async fn verify_presigned_identity(
+        &self,
+        presigned_url: &str,
+        binding: &str,
+    ) -> Result<String> {
+        let url = url::Url::parse(presigned_url)?;
+        let host = url.host_str().unwrap_or_default();
+        let custom_host = self
+            .endpoint_url
+            .as_deref()
+            .and_then(|endpoint| url::Url::parse(endpoint).ok())
+            .and_then(|endpoint| endpoint.host_str().map(String::from));
+        // Only AWS can vouch for the identity; any other host could answer anything
+        let trusted =
+            url.scheme() == "https" && is_sts_host(host) || custom_host.as_deref() == Some(host);
+        let action = url
+            .query_pairs()
+            .find(|(name, _)| name == "Action")
+            .map(|(_, value)| value.to_string());
+        if !trusted || action.as_deref() != Some("GetCallerIdentity") {
+            return Err(anyhow!("Not an STS GetCallerIdentity URL: {}", host));
+        }
+
+        let response = self
+            .http_client
+            .get(url)
+            .header(IDENTITY_BINDING_HEADER, binding)
+            .send()
+            .await?;
+        let status = response.status();
+        let text = response.text().await?;
+        if !status.is_success() {
+            return Err(anyhow!("Identity proof rejected ({}): {}", status, text));
+        }
+        let json = xml_to_json(&text)?;
+        json.pointer("/GetCallerIdentityResponse/GetCallerIdentityResult/Arn")
+            .and_then(|arn| arn.as_str())
+            .map(String::from)
+            .ok_or_else(|| anyhow!("No caller identity in response"))
+    }
+
     /// Make a Query protocol request (EC2, IAM, RDS, etc.)
     pub async fn query_request(
         &self,
@@ -591,6 +930,7 @@ impl AwsHttpClient {
         bucket: &str,
         path: &str,
         body: Option<&str>,
+        headers: Option<HashMap<String, String>>,
         bucket_region: &str,
     ) -> Result<String> {
         debug!(
@@ -611,12 +951,80 @@ impl AwsHttpClient {
             method,
             &url,
             body.unwrap_or(""),
-            None,
+            headers,
             bucket_region,
         )
         .await
     }
 
+    /// Make a REST-XML request for an S3 object (key is encoded per path segment)
+    pub async fn rest_xml_request_s3_object(
+        &self,
+        method: &str,
+        bucket: &str,
+        key: &str,
+        query: &str,
+        headers: Option<HashMap<String, String>>,
+        bucket_region: &str,
+    ) -> Result<String> {
+        debug!(
+            "REST-XML S3 object request: bucket={}, key={}, region={}, method={}, query={}",
+            bucket, key, bucket_region, method, query
+        );
+
+        let service = get_service("s3").ok_or_else(|| anyhow!("Unknown service: s3"))?;
+
+        let domain = Self::endpoint_domain(bucket_region);
+        let url = format!(
+            "https://{}.s3.{}.{}/{}{}",
+            bucket,
+            bucket_region,
+            domain,
+            encode_s3_key(key),
+            query
+        );
+        debug!("URL: {}", url);
+
+        self.signed_request_with_region(&service, method, &url, "", headers, bucket_region)
+            .await
+    }
+
+    /// Make a REST-XML request to the S3 Control API (access points, batch jobs)
+    /// The endpoint is per account: `{account_id}.s3-control.{region}`
+    pub async fn rest_xml_request_s3_control(
+        &self,
+        method: &str,
+        account_id: &str,
+        path: &str,
+        body: Option<&str>,
+        region: &str,
+    ) -> Result<String> {
+        debug!(
+            "REST-XML S3 Control request: account={}, region={}, method={}, path={}",
+            account_id, region, method, path
+        );
+
+        let service = get_service("s3").ok_or_else(|| anyhow!("Unknown service: s3"))?;
+
+        let domain = Self::endpoint_domain(region);
+        let url = format!(
+            "https://{}.s3-control.{}.{}{}",
+            account_id, region, domain, path
+        );
+        debug!("URL: {}", url);
+
+        let headers = HashMap::from([("x-amz-account-id".to_string(), account_id.to_string())]);
+        self.signed_request_with_region(
+            &service,
+            method,
+            &url,
+            body.unwrap_or(""),
+            Some(headers),
+            region,
+        )
+        .await
+    }
+
     /// Get the region for an S3 bucket using HEAD request to check x-amz-bucket-region header
     pub async fn get_bucket_region(&self, bucket: &str) -> Result<String> {
         debug!("Getting bucket region for: {}", bucket);
@@ -872,13 +1280,20 @@ impl AwsHttpClient {
         );
         let identity: Identity = creds.into();
 
+        // S3 signs the path as sent (no double encoding or normalization)
+        let mut settings = SigningSettings::default();
+        if service.signing_name == "s3" {
+            settings.percent_encoding_mode = PercentEncodingMode::Single;
+            settings.uri_path_normalization_mode = UriPathNormalizationMode::Disabled;
+        }
+
         // Create signing params with explicit region
         let signing_params = SigningParams::builder()
             .identity(&identity)
             .region(region)
             .name(service.signing_name)
             .time(SystemTime::now())
-            .settings(SigningSettings::default())
+            .settings(settings)
             .build()?
             .into();
 
@@ -968,6 +1383,14 @@ impl AwsHttpClient {
     }
 }
 
+/// Percent-encode an S3 object key for use in a URL path, keeping `/` separators
+pub fn encode_s3_key(key: &str) -> String {
+    key.split('/')
+        .map(|segment| urlencoding::encode(segment).into_owned())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
 /// Parse XML response to JSON using quick-xml
 pub fn xml_to_json(xml: &str) -> Result<serde_json::Value> {
     use quick_xml::events::Event;
@@ -1050,7 +1473,8 @@ pub fn xml_to_json(xml: &str) -> Result<serde_json::Value> {
 
 #[cfg(test)]
 mod tests {
-    use super::{get_service, AwsHttpClient, Credentials};
+    use super::{encode_s3_key, get_service, is_sts_host, AwsHttpClient, Credentials};
+    use std::collections::HashMap;
 
     fn dummy_credentials() -> Credentials {
         Credentials {
@@ -1080,6 +1504,59 @@ mod tests {
         assert_eq!(endpoint, "https://route53.amazonaws.com");
     }
 
+    #[test]
+    fn account_uses_us_east_1_endpoint() {
+        let client = client_with_region("eu-west-1");
+        let service = get_service("account").expect("account service definition");
+        let endpoint = client.get_endpoint(&service).expect("account endpoint");
+        assert_eq!(endpoint, "https://account.us-east-1.amazonaws.com");
+    }
+
+    #[test]
+    fn presigned_caller_identity_is_bound_to_sts() {
+        assert!(is_sts_host("sts.amazonaws.com"));
+        assert!(is_sts_host("sts.eu-west-1.amazonaws.com"));
+        assert!(is_sts_host("sts.cn-north-1.amazonaws.com.cn"));
+        assert!(is_sts_host("sts.eusc-de-east-1.amazonaws.eu"));
+        assert!(!is_sts_host("sts.s3.amazonaws.com"));
+        assert!(!is_sts_host("sts.us-east-1.s3.amazonaws.com"));
+        assert!(!is_sts_host("sts.example.com"));
+
+        let client = client_with_region("eu-west-1");
+        let url = client
+            .presign_caller_identity("binding", std::time::Duration::from_secs(900))
+            .expect("presigned url");
+        let parsed = url::Url::parse(&url).unwrap();
+        assert_eq!(parsed.host_str(), Some("sts.eu-west-1.amazonaws.com"));
+        let query: HashMap<String, String> = parsed.query_pairs().into_owned().collect();
+        assert_eq!(query["Action"], "GetCallerIdentity");
+        assert_eq!(query["X-Amz-Expires"], "900");
+        assert_eq!(query["X-Amz-SignedHeaders"], "host;x-taws-binding");
+    }
+
+    #[test]
+    fn pricing_uses_us_east_1_endpoint() {
+        let client = client_with_region("ap-southeast-2");
+        let service = get_service("pricing").expect("pricing service definition");
+        let endpoint = client.get_endpoint(&service).expect("pricing endpoint");
+        assert_eq!(endpoint, "https://api.pricing.us-east-1.amazonaws.com");
+    }
+
+    #[test]
+    fn wafv2_cloudfront_scope_uses_us_east_1_endpoint() {
+        let client = client_with_region("eu-west-1");
+        let global = get_service("wafv2-global").expect("wafv2-global service definition");
+        assert_eq!(
+            client.get_endpoint(&global).expect("wafv2-global endpoint"),
+            "https://wafv2.us-east-1.amazonaws.com"
+        );
+        let regional = get_service("wafv2").expect("wafv2 service definition");
+        assert_eq!(
+            client.get_endpoint(&regional).expect("wafv2 endpoint"),
+            "https://wafv2.eu-west-1.amazonaws.com"
+        );
+    }
+
     #[test]
     fn iam_includes_region_for_esc() {
         let client = client_with_region("eusc-de-east-1");
@@ -1116,4 +1593,13 @@ mod tests {
         let endpoint = client.get_endpoint(&service).expect("cloudfront endpoint");
         assert_eq!(endpoint, "https://cloudfront.amazonaws.com");
     }
+
+    #[test]
+    fn test_encode_s3_key() {
+        assert_eq!(encode_s3_key("logs/2024/app.log"), "logs/2024/app.log");
+        assert_eq!(
+            encode_s3_key("reports/Q1 summary+final.pdf"),
+            "reports/Q1%20summary%2Bfinal.pdf"
+        );
+    }
 }
@@ -0,0 +1,268 @@
+//! Parser for the AWS shared config and credentials files
+//!
+//! Understands the section types of `~/.aws/config` (`[default]`,
+//! `[profile name]`, `[sso-session name]`, `[services name]`) and the nested
+//! sub-sections the AWS CLI supports: a key with an empty value followed by
+//! indented `key = value` lines.
+//!
+//! ```ini
+//! [services local-dynamodb]
+//! dynamodb =
+//!   endpoint_url = http://localhost:8000
+//! ```
+
+use std::collections::{BTreeMap, HashMap};
+
+/// Type of a section, from its header
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SectionKind {
+    /// `[default]`, `[profile name]`, or any credentials file section
+    Profile,
+    /// `[sso-session name]`
+    SsoSession,
+    /// `[services name]` (per-service endpoint settings)
+    Services,
+    /// Any other `[type name]` header, kept under its full header
+    Other,
+}
+
+/// Keys of a section, with nested sub-sections kept apart
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Section {
+    pub properties: BTreeMap<String, String>,
+    pub nested: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+impl Section {
+    pub fn get(&self, key: &str) -> Option<&String> {
+        self.properties.get(key)
+    }
+}
+
+/// A parsed config or credentials file
+#[derive(Debug, Clone, Default)]
+pub struct IniFile {
+    sections: HashMap<(SectionKind, String), Section>,
+}
+
+impl IniFile {
+    /// Parse a config file, where headers carry their section type
+    pub fn parse(content: &str) -> Self {
+        Self::parse_with(content, parse_header)
+    }
+
+    /// Parse a credentials file, where every header is a profile name as written
+    /// (`[ci user]` is the profile "ci user")
+    pub fn parse_credentials(content: &str) -> Self {
+        Self::parse_with(content, |header| {
+            (SectionKind::Profile, header.trim().to_string())
+        })
+    }
+
+    fn parse_with(content: &str, parse_header: fn(&str) -> (SectionKind, String)) -> Self {
+        let mut file = Self::default();
+        let mut current: Option<(SectionKind, String)> = None;
+        // Last top-level key, for nested sub-sections and continuation lines
+        let mut last_key: Option<String> = None;
+
+        for raw in content.lines() {
+            let line = raw.trim();
+            if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+                continue;
+            }
+
+            if line.starts_with('[') && line.ends_with(']') {
+                let header = parse_header(&line[1..line.len() - 1]);
+                file.sections.entry(header.clone()).or_default();
+                current = Some(header);
+                last_key = None;
+                continue;
+            }
+
+            let Some(section) = current
+                .as_ref()
+                .and_then(|header| file.sections.get_mut(header))
+            else {
+                continue;
+            };
+            let indented = raw.starts_with([' ', '\t']);
+
+            if let (true, Some(parent)) = (indented, &last_key) {
+                let parent_value = section.properties.get_mut(parent);
+                match parent_value {
+                    // `key =` followed by indented lines opens a sub-section
+                    Some(value) if value.is_empty() => {
+                        if let Some((key, value)) = line.split_once('=') {
+                            section
+                                .nested
+                                .entry(parent.clone())
+                                .or_default()
+                                .insert(key.trim().to_string(), value.trim().to_string());
+                        }
+                    }
+                    // Otherwise it continues a multi-line value
+                    Some(value) => {
+                        value.push('\n');
+                        value.push_str(line);
+                    }
+                    None => {}
+                }
+                continue;
+            }
+
+            if let Some((key, value)) = line.split_once('=') {
+                let key = key.trim().to_string();
+                section
+                    .properties
+                    .insert(key.clone(), value.trim().to_string());
+                last_key = Some(key);
+            }
+        }
+
+        file
+    }
+
+    pub fn profile(&self, name: &str) -> Option<&Section> {
+        self.section(SectionKind::Profile, name)
+    }
+
+    pub fn sso_session(&self, name: &str) -> Option<&Section> {
+        self.section(SectionKind::SsoSession, name)
+    }
+
+    /// Profiles in the file, in no particular order
+    pub fn profiles(&self) -> impl Iterator<Item = (&str, &Section)> {
+        self.sections
+            .iter()
+            .filter(|((kind, _), _)| *kind == SectionKind::Profile)
+            .map(|((_, name), section)| (name.as_str(), section))
+    }
+
+    /// All sections keyed the way the credential loaders look them up:
+    /// profiles by name, other sections by their full header ("sso-session corp")
+    pub fn flat_sections(&self) -> HashMap<String, HashMap<String, String>> {
+        self.sections
+            .iter()
+            .map(|((kind, name), section)| {
+                let key = match kind {
+                    SectionKind::Profile | SectionKind::Other => name.clone(),
+                    SectionKind::SsoSession => format!("sso-session {}", name),
+                    SectionKind::Services => format!("services {}", name),
+                };
+                let properties = section
+                    .properties
+                    .iter()
+                    .map(|(k, v)| (k.clone(), v.clone()))
+                    .collect();
+                (key, properties)
+            })
+            .collect()
+    }
+
+    fn section(&self, kind: SectionKind, name: &str) -> Option<&Section> {
+        self.sections.get(&(kind, name.to_string()))
+    }
+}
+
+fn parse_header(header: &str) -> (SectionKind, String) {
+    let header = header.trim();
+    let Some((kind, name)) = header.split_once(char::is_whitespace) else {
+        return (SectionKind::Profile, header.to_string());
+    };
+    let name = name.trim().to_string();
+    match kind {
+        "profile" => (SectionKind::Profile, name),
+        "sso-session" => (SectionKind::SsoSession, name),
+        "services" => (SectionKind::Services, name),
+        _ => (SectionKind::Other, header.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFIG: &str = "\
+[default]
+region = us-east-1
+s3 =
+  max_concurrent_requests = 20
+  addressing_style = path
+
+[profile dev]
+sso_session = corp
+services = local
+ca_bundle = /etc/ssl/a.pem
+  /etc/ssl/b.pem
+
+[sso-session corp]
+sso_start_url = https://corp.awsapps.com/start
+sso_region = eu-west-1
+
+; comment
+[services local]
+dynamodb =
+  endpoint_url = http://localhost:8000
+";
+
+    #[test]
+    fn test_parse_sections() {
+        let file = IniFile::parse(CONFIG);
+
+        let default = file.profile("default").unwrap();
+        assert_eq!(default.get("region").unwrap(), "us-east-1");
+        assert_eq!(default.get("s3").unwrap(), "");
+        assert_eq!(
+            default
+                .nested
+                .get("s3")
+                .unwrap()
+                .get("addressing_style")
+                .unwrap(),
+            "path"
+        );
+
+        let dev = file.profile("dev").unwrap();
+        assert_eq!(dev.get("sso_session").unwrap(), "corp");
+        assert_eq!(
+            dev.get("ca_bundle").unwrap(),
+            "/etc/ssl/a.pem\n/etc/ssl/b.pem"
+        );
+
+        assert_eq!(
+            file.sso_session("corp").unwrap().get("sso_region").unwrap(),
+            "eu-west-1"
+        );
+        assert_eq!(
+            file.section(SectionKind::Services, "local")
+                .unwrap()
+                .nested
+                .get("dynamodb")
+                .unwrap()
+                .get("endpoint_url")
+                .unwrap(),
+            "http://localhost:8000"
+        );
+
+        let mut profiles: Vec<&str> = file.profiles().map(|(name, _)| name).collect();
+        profiles.sort();
+        assert_eq!(profiles, vec!["default", "dev"]);
+    }
+
+    #[test]
+    fn test_credentials_headers_are_literal() {
+        let file = IniFile::parse_credentials("[ci user]\naws_access_key_id = AKIA\n[profile x]\n");
+        let mut profiles: Vec<&str> = file.profiles().map(|(name, _)| name).collect();
+        profiles.sort();
+        assert_eq!(profiles, vec!["ci user", "profile x"]);
+    }
+
+    #[test]
+    fn test_flat_sections_keep_section_type() {
+        let sections = IniFile::parse(CONFIG).flat_sections();
+        assert!(sections.contains_key("dev"));
+        assert!(sections.contains_key("sso-session corp"));
+        assert!(sections.contains_key("services local"));
+        assert!(!sections.contains_key("corp"));
+    }
+}
@@ -0,0 +1,50 @@
+//! OS credential store for secrets taws caches
+//!
+//! With `credential_store: keychain` in config.yaml, SSO tokens, SSO client
+//! registrations and assumed-role credentials are kept in the OS store
+//! (macOS Keychain, Windows Credential Manager, Secret Service on Linux)
+//! instead of plaintext files. Files written by the AWS CLI are still read.
+
+use anyhow::Result;
+use std::sync::atomic::{AtomicBool, Ordering};
+use tracing::debug;
+
+/// Service name entries are stored under
+const SERVICE: &str = "taws";
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Turn the credential store on or off (from config.yaml)
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+pub fn store(key: &str, value: &str) -> Result<()> {
+    keyring::Entry::new(SERVICE, key)?.set_password(value)?;
+    debug!("Stored '{}' in the OS credential store", key);
+    Ok(())
+}
+
+pub fn load(key: &str) -> Option<String> {
+    match keyring::Entry::new(SERVICE, key).and_then(|entry| entry.get_password()) {
+        Ok(value) => Some(value),
+        Err(keyring::Error::NoEntry) => None,
+        Err(e) => {
+            debug!(
+                "Failed to read '{}' from the OS credential store: {}",
+                key, e
+            );
+            None
+        }
+    }
+}
+
+pub fn delete(key: &str) {
+    if let Ok(entry) = keyring::Entry::new(SERVICE, key) {
+        let _ = entry.delete_credential();
+    }
+}
@@ -2,6 +2,8 @@ pub mod client;
 pub mod console_login;
 pub mod credentials;
 pub mod http;
+pub mod ini;
+pub mod keychain;
 pub mod profiles;
 pub mod sso;
 pub mod tls;
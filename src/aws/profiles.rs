@@ -1,54 +1,141 @@
+use super::ini::IniFile;
 use anyhow::Result;
-use std::collections::HashSet;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-/// List all AWS profiles from ~/.aws/credentials and ~/.aws/config
-pub fn list_profiles() -> Result<Vec<String>> {
-    let mut profiles = HashSet::new();
+/// How long a discovered region list is reused before asking AWS again
+const REGIONS_CACHE_TTL_SECS: i64 = 24 * 60 * 60;
 
-    // Always include default
-    profiles.insert("default".to_string());
-
-    // Read from ~/.aws/credentials
-    if let Some(creds_path) = get_aws_credentials_path() {
-        if let Ok(content) = fs::read_to_string(&creds_path) {
-            for line in content.lines() {
-                let line = line.trim();
-                if line.starts_with('[') && line.ends_with(']') {
-                    let profile = line[1..line.len() - 1].to_string();
-                    profiles.insert(profile);
-                }
+/// Regions discovered for a profile, cached on disk
+#[derive(Debug, Serialize, Deserialize)]
+struct RegionsCache {
+    fetched_at: i64,
+    regions: Vec<String>,
+}
+
+/// A profile and the settings that tell it apart in the profile picker
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ProfileInfo {
+    pub name: String,
+    pub region: Option<String>,
+    pub sso_session: Option<String>,
+    pub sso_account_id: Option<String>,
+    pub sso_role_name: Option<String>,
+    pub role_arn: Option<String>,
+    pub source_profile: Option<String>,
+}
+
+impl ProfileInfo {
+    fn named(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            ..Self::default()
+        }
+    }
+
+    /// How the profile gets credentials ("role Admin via base", "sso corp 1234/ReadOnly")
+    pub fn details(&self) -> String {
+        if let Some(role_arn) = &self.role_arn {
+            let role = role_arn.rsplit('/').next().unwrap_or(role_arn);
+            return match &self.source_profile {
+                Some(source) => format!("role {} via {}", role, source),
+                None => format!("role {}", role),
+            };
+        }
+        if self.sso_session.is_some() || self.sso_account_id.is_some() {
+            let mut details = format!("sso {}", self.sso_session.as_deref().unwrap_or("(legacy)"));
+            if let (Some(account), Some(role)) = (&self.sso_account_id, &self.sso_role_name) {
+                details.push_str(&format!(" {}/{}", account, role));
             }
+            return details;
+        }
+        String::new()
+    }
+
+    fn set(&mut self, key: &str, value: &str) {
+        let value = Some(value.to_string());
+        match key {
+            "region" => self.region = value,
+            "sso_session" => self.sso_session = value,
+            "sso_account_id" => self.sso_account_id = value,
+            "sso_role_name" => self.sso_role_name = value,
+            "role_arn" => self.role_arn = value,
+            "source_profile" => self.source_profile = value,
+            _ => {}
         }
     }
+}
+
+/// List all AWS profiles from ~/.aws/credentials and ~/.aws/config, sorted by name
+pub fn list_profiles() -> Result<Vec<ProfileInfo>> {
+    // Config settings take precedence over the credentials file
+    let sources: Vec<(String, bool)> = [
+        (get_aws_credentials_path(), false),
+        (get_aws_config_path(), true),
+    ]
+    .into_iter()
+    .filter_map(|(path, is_config)| Some((fs::read_to_string(path?).ok()?, is_config)))
+    .collect();
+    Ok(merge_profiles(&sources))
+}
 
-    // Read from ~/.aws/config
-    if let Some(config_path) = get_aws_config_path() {
-        if let Ok(content) = fs::read_to_string(&config_path) {
-            for line in content.lines() {
-                let line = line.trim();
-                if line.starts_with('[') && line.ends_with(']') {
-                    let section = &line[1..line.len() - 1];
-                    // Config file uses "profile <name>" format, except for default
-                    let profile = if section.starts_with("profile ") {
-                        section.strip_prefix("profile ").unwrap().to_string()
-                    } else {
-                        section.to_string()
-                    };
-                    profiles.insert(profile);
+/// Profiles of several (content, is_config) files, later files overriding earlier ones
+fn merge_profiles(sources: &[(String, bool)]) -> Vec<ProfileInfo> {
+    let mut profiles: BTreeMap<String, ProfileInfo> = BTreeMap::new();
+
+    // Always include default
+    profiles.insert("default".to_string(), ProfileInfo::named("default"));
+
+    for (content, is_config) in sources {
+        for parsed in parse_profile_sections(content, *is_config) {
+            let profile = profiles
+                .entry(parsed.name.clone())
+                .or_insert_with(|| ProfileInfo::named(&parsed.name));
+            for (key, value) in [
+                ("region", &parsed.region),
+                ("sso_session", &parsed.sso_session),
+                ("sso_account_id", &parsed.sso_account_id),
+                ("sso_role_name", &parsed.sso_role_name),
+                ("role_arn", &parsed.role_arn),
+                ("source_profile", &parsed.source_profile),
+            ] {
+                if let Some(value) = value {
+                    profile.set(key, value);
                 }
             }
         }
     }
 
-    let mut profiles: Vec<String> = profiles.into_iter().collect();
-    profiles.sort();
+    profiles.into_values().collect()
+}
 
-    Ok(profiles)
+/// Profile sections of a credentials or config file, sorted by name.
+/// Credentials file headers are profile names as written (`[ci user]`).
+fn parse_profile_sections(content: &str, is_config: bool) -> Vec<ProfileInfo> {
+    let file = if is_config {
+        IniFile::parse(content)
+    } else {
+        IniFile::parse_credentials(content)
+    };
+    let mut profiles: Vec<ProfileInfo> = file
+        .profiles()
+        .map(|(name, section)| {
+            let mut profile = ProfileInfo::named(name);
+            for (key, value) in &section.properties {
+                profile.set(key, value);
+            }
+            profile
+        })
+        .collect();
+    profiles.sort_by(|a, b| a.name.cmp(&b.name));
+    profiles
 }
 
 /// List common AWS regions
+///
+/// Used when the account's regions can't be discovered and nothing is cached.
 pub fn list_regions() -> Vec<String> {
     vec![
         "us-east-1".to_string(),
@@ -57,16 +144,20 @@ pub fn list_regions() -> Vec<String> {
         "us-west-2".to_string(),
         "af-south-1".to_string(),
         "ap-east-1".to_string(),
+        "ap-east-2".to_string(),
         "ap-south-1".to_string(),
         "ap-south-2".to_string(),
         "ap-southeast-1".to_string(),
         "ap-southeast-2".to_string(),
         "ap-southeast-3".to_string(),
         "ap-southeast-4".to_string(),
+        "ap-southeast-5".to_string(),
+        "ap-southeast-7".to_string(),
         "ap-northeast-1".to_string(),
         "ap-northeast-2".to_string(),
         "ap-northeast-3".to_string(),
         "ca-central-1".to_string(),
+        "ca-west-1".to_string(),
         "eu-central-1".to_string(),
         "eu-central-2".to_string(),
         "eu-west-1".to_string(),
@@ -75,12 +166,58 @@ pub fn list_regions() -> Vec<String> {
         "eu-south-1".to_string(),
         "eu-south-2".to_string(),
         "eu-north-1".to_string(),
+        "il-central-1".to_string(),
         "me-south-1".to_string(),
         "me-central-1".to_string(),
+        "mx-central-1".to_string(),
         "sa-east-1".to_string(),
     ]
 }
 
+/// Regions previously discovered for a profile, if the cache is still fresh
+pub fn cached_regions(profile: &str) -> Option<Vec<String>> {
+    read_regions_cache(&regions_cache_path(profile), chrono::Utc::now().timestamp())
+}
+
+/// Remember the regions discovered for a profile
+pub fn save_cached_regions(profile: &str, regions: &[String]) -> Result<()> {
+    let path = regions_cache_path(profile);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let cache = RegionsCache {
+        fetched_at: chrono::Utc::now().timestamp(),
+        regions: regions.to_vec(),
+    };
+    fs::write(&path, serde_json::to_string(&cache)?)?;
+    Ok(())
+}
+
+fn read_regions_cache(path: &Path, now: i64) -> Option<Vec<String>> {
+    let cache: RegionsCache = serde_json::from_str(&fs::read_to_string(path).ok()?).ok()?;
+    let fresh = now - cache.fetched_at < REGIONS_CACHE_TTL_SECS;
+    (fresh && !cache.regions.is_empty()).then_some(cache.regions)
+}
+
+fn regions_cache_path(profile: &str) -> PathBuf {
+    let file_name: String = profile
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    dirs::config_dir()
+        .or_else(|| dirs::home_dir().map(|h| h.join(".taws")))
+        .unwrap_or_else(|| PathBuf::from(".taws"))
+        .join("taws")
+        .join("regions")
+        .join(format!("{}.json", file_name))
+}
+
 fn get_aws_credentials_path() -> Option<PathBuf> {
     // Check AWS_SHARED_CREDENTIALS_FILE env var first
     if let Ok(path) = std::env::var("AWS_SHARED_CREDENTIALS_FILE") {
@@ -100,3 +237,77 @@ fn get_aws_config_path() -> Option<PathBuf> {
     // Fall back to ~/.aws/config
     dirs::home_dir().map(|h| h.join(".aws").join("config"))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merge_profiles() {
+        let credentials = "\
+[ci]
+aws_access_key_id = AKIAEXAMPLE
+region = us-west-2
+";
+        let config = "\
+[default]
+region = us-east-1
+
+[profile prod-admin]
+role_arn = arn:aws:iam::123456789012:role/Admin
+source_profile = default
+region = eu-west-1
+
+[sso-session corp]
+sso_region = us-east-1
+
+# comment
+[profile prod-readonly]
+sso_session = corp
+sso_account_id = 123456789012
+sso_role_name = ReadOnly
+
+[profile ci]
+region = eu-central-1
+";
+        let profiles =
+            merge_profiles(&[(credentials.to_string(), false), (config.to_string(), true)]);
+        let names: Vec<&str> = profiles.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, vec!["ci", "default", "prod-admin", "prod-readonly"]);
+        assert_eq!(profiles[0].region.as_deref(), Some("eu-central-1"));
+        assert_eq!(profiles[1].region.as_deref(), Some("us-east-1"));
+        assert_eq!(profiles[2].details(), "role Admin via default");
+        assert_eq!(profiles[3].details(), "sso corp 123456789012/ReadOnly");
+        assert_eq!(profiles[1].details(), "");
+
+        let credentials = "[ci user]\naws_access_key_id = AKIA\n";
+        assert_eq!(
+            parse_profile_sections(credentials, false)[0].name,
+            "ci user"
+        );
+    }
+
+    #[test]
+    fn test_read_regions_cache_expires() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("default.json");
+        let cache = RegionsCache {
+            fetched_at: 1_000,
+            regions: vec!["eu-west-1".to_string(), "us-east-1".to_string()],
+        };
+        fs::write(&path, serde_json::to_string(&cache).unwrap()).unwrap();
+
+        assert_eq!(
+            read_regions_cache(&path, 1_000 + 60),
+            Some(vec!["eu-west-1".to_string(), "us-east-1".to_string()])
+        );
+        assert_eq!(
+            read_regions_cache(&path, 1_000 + REGIONS_CACHE_TTL_SECS),
+            None
+        );
+        assert_eq!(
+            read_regions_cache(&dir.path().join("missing.json"), 0),
+            None
+        );
+    }
+}
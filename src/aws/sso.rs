@@ -11,19 +11,53 @@ use anyhow::{anyhow, Result};
 use serde::{Deserialize, Serialize};
 use sha1::{Digest, Sha1};
 use std::fs;
+use std::path::{Path, PathBuf};
 use std::time::{Duration, SystemTime};
 use tracing::{debug, trace};
 
 use super::credentials::{aws_config_dir, get_aws_config_file_path, Credentials};
+use super::ini::IniFile;
+use super::keychain;
+
+/// Scope requested for `[sso-session]` registrations when none is configured
+const DEFAULT_REGISTRATION_SCOPE: &str = "sso:account:access";
+
+/// Refresh a refreshable token this long before it expires
+const TOKEN_REFRESH_WINDOW_SECS: i64 = 5 * 60;
 
 /// SSO configuration parsed from profile
 #[derive(Debug, Clone)]
 pub struct SsoConfig {
+    /// `[sso-session]` name, or the profile name for legacy profiles
     pub sso_session: String,
     pub sso_account_id: String,
     pub sso_role_name: String,
     pub sso_start_url: String,
     pub sso_region: String,
+    /// Legacy profile with `sso_start_url` set directly (no refresh tokens)
+    pub legacy: bool,
+    /// Scopes requested when registering the OIDC client (`sso_registration_scopes`)
+    pub registration_scopes: Vec<String>,
+}
+
+impl SsoConfig {
+    /// Token cache files to look in, most specific first. Like the AWS CLI,
+    /// `[sso-session]` tokens are keyed by session name and legacy tokens by
+    /// start URL, so several sessions on the same start URL stay separate.
+    fn token_cache_files(&self) -> Vec<String> {
+        let mut files = Vec::new();
+        if !self.legacy {
+            files.push(format!("{}.json", sha1_hex(&self.sso_session)));
+        }
+        files.push(format!("{}.json", sha1_hex(&self.sso_start_url)));
+        files
+    }
+}
+
+fn sha1_hex(value: &str) -> String {
+    let mut hasher = Sha1::new();
+    hasher.update(value.as_bytes());
+    format!("{:x}", hasher.finalize())
 }
 
 /// OIDC client registration response
@@ -55,20 +89,60 @@ struct TokenResponse {
     #[allow(dead_code)]
     token_type: String,
     expires_in: i64,
+    /// Only issued to `[sso-session]` registrations (with scopes)
+    #[serde(default)]
+    refresh_token: Option<String>,
 }
 
 /// Cached SSO token format (compatible with AWS CLI v1 and v2)
-/// Supports both snake_case (v1/legacy) and camelCase (v2) field names via aliases
+/// Written in the CLI v2 camelCase format; snake_case (v1/legacy) is still read
 #[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 struct CachedToken {
-    #[serde(alias = "accessToken")]
+    #[serde(alias = "access_token")]
     access_token: String,
-    #[serde(alias = "expiresAt")]
+    #[serde(alias = "expires_at")]
     expires_at: String,
-    #[serde(default, alias = "region")]
+    #[serde(default)]
     region: Option<String>,
-    #[serde(alias = "startUrl")]
+    #[serde(alias = "start_url")]
     start_url: String,
+    /// Client registration and refresh token, for refreshing without a new login
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    client_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    client_secret: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    registration_expires_at: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    refresh_token: Option<String>,
+    /// Fields written by other tools, kept when the token is refreshed
+    #[serde(flatten)]
+    extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl CachedToken {
+    fn expires_at(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        chrono::DateTime::parse_from_rfc3339(&self.expires_at)
+            .ok()
+            .map(|t| t.with_timezone(&chrono::Utc))
+    }
+
+    /// Whether the token can be refreshed with its client registration.
+    /// A registration without a recorded expiry is tried; CreateToken
+    /// rejects it if it has expired.
+    fn is_refreshable(&self, now: chrono::DateTime<chrono::Utc>) -> bool {
+        let registration_valid = match self.registration_expires_at.as_deref() {
+            Some(expires_at) => {
+                chrono::DateTime::parse_from_rfc3339(expires_at).is_ok_and(|t| t > now)
+            }
+            None => true,
+        };
+        self.refresh_token.is_some()
+            && self.client_id.is_some()
+            && self.client_secret.is_some()
+            && registration_valid
+    }
 }
 
 /// SSO login state for UI (kept for potential future use)
@@ -119,10 +193,7 @@ pub fn start_device_authorization(config: &SsoConfig) -> Result<DeviceAuthInfo>
     let register_response = client
         .post(&register_url)
         .header("Content-Type", "application/json")
-        .json(&serde_json::json!({
-            "clientName": "taws",
-            "clientType": "public",
-        }))
+        .json(&registration_request(config))
         .send()?;
 
     if !register_response.status().is_success() {
@@ -168,10 +239,6 @@ pub fn start_device_authorization(config: &SsoConfig) -> Result<DeviceAuthInfo>
     );
 
     // Store client registration for token polling
-    let cache_dir = aws_config_dir()?.join("sso").join("cache");
-    fs::create_dir_all(&cache_dir)?;
-
-    let client_cache_path = cache_dir.join(format!("{}_client.json", config.sso_session));
     let client_data = serde_json::json!({
         "clientId": registration.client_id,
         "clientSecret": registration.client_secret,
@@ -179,10 +246,7 @@ pub fn start_device_authorization(config: &SsoConfig) -> Result<DeviceAuthInfo>
         "deviceCode": device_auth.device_code,
         "region": config.sso_region,
     });
-    fs::write(
-        &client_cache_path,
-        serde_json::to_string_pretty(&client_data)?,
-    )?;
+    client_registration_location(config)?.write(&serde_json::to_string_pretty(&client_data)?)?;
 
     let expires_at = SystemTime::now() + Duration::from_secs(device_auth.expires_in as u64);
 
@@ -196,6 +260,19 @@ pub fn start_device_authorization(config: &SsoConfig) -> Result<DeviceAuthInfo>
     })
 }
 
+/// RegisterClient body; `[sso-session]` registrations request scopes so the
+/// token comes with a refresh token
+fn registration_request(config: &SsoConfig) -> serde_json::Value {
+    let mut request = serde_json::json!({
+        "clientName": "taws",
+        "clientType": "public",
+    });
+    if !config.registration_scopes.is_empty() {
+        request["scopes"] = serde_json::json!(config.registration_scopes);
+    }
+    request
+}
+
 /// Open browser to SSO login page
 pub fn open_sso_browser(verification_uri_complete: &str) -> Result<()> {
     debug!("Opening browser to: {}", verification_uri_complete);
@@ -205,12 +282,11 @@ pub fn open_sso_browser(verification_uri_complete: &str) -> Result<()> {
 /// Poll for token completion (call this periodically)
 /// Returns Ok(Some(token)) when authorized, Ok(None) when still pending, Err on failure
 pub fn poll_for_token(config: &SsoConfig) -> Result<Option<String>> {
-    let cache_dir = aws_config_dir()?.join("sso").join("cache");
-    let client_cache_path = cache_dir.join(format!("{}_client.json", config.sso_session));
-
+    let client_location = client_registration_location(config)?;
     let client_data: serde_json::Value = serde_json::from_str(
-        &fs::read_to_string(&client_cache_path)
-            .map_err(|_| anyhow!("Client registration not found"))?,
+        &client_location
+            .read()
+            .ok_or_else(|| anyhow!("Client registration not found"))?,
     )?;
 
     let client_id = client_data
@@ -246,15 +322,19 @@ pub fn poll_for_token(config: &SsoConfig) -> Result<Option<String>> {
     if response.status().is_success() {
         let token_response: TokenResponse = response.json()?;
 
-        // Cache the token
-        cache_sso_token(
-            config,
-            &token_response.access_token,
-            token_response.expires_in,
-        )?;
+        // Cache the token, with the client registration when it can be refreshed
+        let registration = token_response.refresh_token.as_ref().map(|_| {
+            let expires_at = client_data
+                .get("clientSecretExpiresAt")
+                .and_then(|v| v.as_i64())
+                .and_then(|secs| chrono::DateTime::from_timestamp(secs, 0))
+                .map(|t| t.format("%Y-%m-%dT%H:%M:%SZ").to_string());
+            (client_id.to_string(), client_secret.to_string(), expires_at)
+        });
+        cache_sso_token(config, &token_response, registration)?;
 
         // Clean up client cache
-        let _ = fs::remove_file(&client_cache_path);
+        client_location.remove();
 
         debug!("SSO authentication successful");
         return Ok(Some(token_response.access_token));
@@ -283,32 +363,151 @@ pub fn poll_for_token(config: &SsoConfig) -> Result<Option<String>> {
 }
 
 /// Cache the SSO access token (compatible with AWS CLI format)
-fn cache_sso_token(config: &SsoConfig, access_token: &str, expires_in: i64) -> Result<()> {
-    let cache_dir = aws_config_dir()?.join("sso").join("cache");
-    fs::create_dir_all(&cache_dir)?;
-
-    // Calculate expiration time
-    let expires_at = chrono::Utc::now() + chrono::Duration::seconds(expires_in);
-    let expires_at_str = expires_at.format("%Y-%m-%dT%H:%M:%SZ").to_string();
-
+fn cache_sso_token(
+    config: &SsoConfig,
+    token: &TokenResponse,
+    registration: Option<(String, String, Option<String>)>,
+) -> Result<()> {
+    let (client_id, client_secret, registration_expires_at) = match registration {
+        Some((id, secret, expires_at)) => (Some(id), Some(secret), expires_at),
+        None => (None, None, None),
+    };
     let cached_token = CachedToken {
-        access_token: access_token.to_string(),
-        expires_at: expires_at_str,
+        access_token: token.access_token.clone(),
+        expires_at: token_expiry(token.expires_in),
         region: Some(config.sso_region.clone()),
         start_url: config.sso_start_url.clone(),
+        client_id,
+        client_secret,
+        registration_expires_at,
+        refresh_token: token.refresh_token.clone(),
+        extra: serde_json::Map::new(),
+    };
+    let file_name = &config.token_cache_files()[0];
+    let location = if keychain::is_enabled() {
+        CacheLocation::Keychain(format!("sso-token:{}", file_name))
+    } else {
+        CacheLocation::File(sso_cache_dir()?.join(file_name))
     };
+    write_cached_token(&location, &cached_token)
+}
 
-    // Cache file name is SHA1 of start_url (compatible with AWS CLI for both legacy and new format)
-    let mut hasher = Sha1::new();
-    hasher.update(config.sso_start_url.as_bytes());
-    let hash = hasher.finalize();
-    let cache_file_name = format!("{:x}.json", hash);
-    let cache_path = cache_dir.join(&cache_file_name);
+fn sso_cache_dir() -> Result<PathBuf> {
+    Ok(aws_config_dir()?.join("sso").join("cache"))
+}
+
+/// Where a cached token or client registration lives: the shared AWS cache
+/// directory, or the OS credential store when `credential_store: keychain`
+#[derive(Debug, Clone, PartialEq)]
+enum CacheLocation {
+    File(PathBuf),
+    Keychain(String),
+}
+
+impl CacheLocation {
+    fn read(&self) -> Option<String> {
+        match self {
+            Self::File(path) => fs::read_to_string(path).ok(),
+            Self::Keychain(key) => keychain::load(key),
+        }
+    }
+
+    fn write(&self, content: &str) -> Result<()> {
+        match self {
+            Self::File(path) => {
+                if let Some(dir) = path.parent() {
+                    fs::create_dir_all(dir)?;
+                }
+                fs::write(path, content)?;
+            }
+            Self::Keychain(key) => keychain::store(key, content)?,
+        }
+        debug!("Cached SSO data to {:?}", self);
+        Ok(())
+    }
 
-    fs::write(&cache_path, serde_json::to_string_pretty(&cached_token)?)?;
-    debug!("Cached SSO token to {:?}", cache_path);
+    fn remove(&self) {
+        match self {
+            Self::File(path) => {
+                let _ = fs::remove_file(path);
+            }
+            Self::Keychain(key) => keychain::delete(key),
+        }
+    }
+}
 
-    Ok(())
+/// Client registration kept between device authorization and token polling
+fn client_registration_location(config: &SsoConfig) -> Result<CacheLocation> {
+    Ok(if keychain::is_enabled() {
+        CacheLocation::Keychain(format!("sso-client:{}", config.sso_session))
+    } else {
+        CacheLocation::File(sso_cache_dir()?.join(format!("{}_client.json", config.sso_session)))
+    })
+}
+
+/// Places to look for a session's token, in order: taws's entries in the
+/// credential store (when enabled), then the shared cache files
+fn token_locations(config: &SsoConfig, cache_dir: &Path, use_keychain: bool) -> Vec<CacheLocation> {
+    let files = config.token_cache_files();
+    let keychain_entries = files
+        .iter()
+        .filter(|_| use_keychain)
+        .map(|file_name| CacheLocation::Keychain(format!("sso-token:{}", file_name)));
+    let cache_files = files
+        .iter()
+        .map(|file_name| CacheLocation::File(cache_dir.join(file_name)));
+    keychain_entries.chain(cache_files).collect()
+}
+
+/// Write a token to its cache location
+fn write_cached_token(location: &CacheLocation, token: &CachedToken) -> Result<()> {
+    location.write(&serde_json::to_string_pretty(token)?)
+}
+
+fn token_expiry(expires_in: i64) -> String {
+    let expires_at = chrono::Utc::now() + chrono::Duration::seconds(expires_in);
+    expires_at.format("%Y-%m-%dT%H:%M:%SZ").to_string()
+}
+
+/// Exchange a cached refresh token for a new access token (CreateToken) and
+/// write it back to where it was read from, so the AWS CLI and SDKs pick up
+/// a refreshed shared cache file as well
+fn refresh_cached_token(
+    config: &SsoConfig,
+    location: &CacheLocation,
+    cached: CachedToken,
+) -> Result<String> {
+    let http_client = super::tls::create_blocking_client_with_timeout(Duration::from_secs(10))?;
+    let region = cached.region.as_deref().unwrap_or(&config.sso_region);
+    let token_url = format!("https://oidc.{}.amazonaws.com/token", region);
+
+    debug!("Refreshing SSO token for session '{}'", config.sso_session);
+    let response = http_client
+        .post(&token_url)
+        .header("Content-Type", "application/json")
+        .json(&serde_json::json!({
+            "clientId": cached.client_id,
+            "clientSecret": cached.client_secret,
+            "refreshToken": cached.refresh_token,
+            "grantType": "refresh_token",
+        }))
+        .send()?;
+
+    if !response.status().is_success() {
+        let status = response.status();
+        let body = response.text().unwrap_or_default();
+        return Err(anyhow!("SSO token refresh failed ({}): {}", status, body));
+    }
+
+    let token: TokenResponse = response.json()?;
+    let refreshed = CachedToken {
+        access_token: token.access_token.clone(),
+        expires_at: token_expiry(token.expires_in),
+        refresh_token: token.refresh_token.or(cached.refresh_token),
+        ..cached
+    };
+    write_cached_token(location, &refreshed)?;
+    Ok(token.access_token)
 }
 
 /// Get role credentials using SSO access token
@@ -377,10 +576,10 @@ pub fn get_sso_config(profile: &str) -> Option<SsoConfig> {
 /// Parse SSO config from content
 /// Supports both new format (sso_session reference) and legacy format (direct sso_start_url)
 fn parse_sso_config_from_content(profile: &str, content: &str) -> Result<SsoConfig> {
-    let sections = parse_ini_sections(content);
+    let file = IniFile::parse(content);
 
-    let profile_section = sections
-        .get(profile)
+    let profile_section = file
+        .profile(profile)
         .ok_or_else(|| anyhow!("Profile '{}' not found", profile))?;
 
     // Check for required fields that exist in both formats
@@ -396,9 +595,8 @@ fn parse_sso_config_from_content(profile: &str, content: &str) -> Result<SsoConf
 
     // Try new format first (sso_session reference)
     if let Some(sso_session) = profile_section.get("sso_session") {
-        let session_key = format!("sso-session {}", sso_session);
-        let session_section = sections
-            .get(&session_key)
+        let session_section = file
+            .sso_session(sso_session)
             .ok_or_else(|| anyhow!("SSO session '{}' not found", sso_session))?;
 
         let sso_start_url = session_section
@@ -411,12 +609,25 @@ fn parse_sso_config_from_content(profile: &str, content: &str) -> Result<SsoConf
             .ok_or_else(|| anyhow!("No sso_region in session"))?
             .clone();
 
+        let registration_scopes = session_section
+            .get("sso_registration_scopes")
+            .map(|scopes| {
+                scopes
+                    .split(',')
+                    .map(|scope| scope.trim().to_string())
+                    .filter(|scope| !scope.is_empty())
+                    .collect()
+            })
+            .unwrap_or_else(|| vec![DEFAULT_REGISTRATION_SCOPE.to_string()]);
+
         return Ok(SsoConfig {
             sso_session: sso_session.clone(),
             sso_account_id,
             sso_role_name,
             sso_start_url,
             sso_region,
+            legacy: false,
+            registration_scopes,
         });
     }
 
@@ -438,79 +649,53 @@ fn parse_sso_config_from_content(profile: &str, content: &str) -> Result<SsoConf
         sso_role_name,
         sso_start_url,
         sso_region,
+        legacy: true,
+        registration_scopes: Vec::new(),
     })
 }
 
-/// Parse INI file into sections (duplicated here to avoid circular deps)
-fn parse_ini_sections(
-    content: &str,
-) -> std::collections::HashMap<String, std::collections::HashMap<String, String>> {
-    let mut sections = std::collections::HashMap::new();
-    let mut current_section = String::new();
-
-    for line in content.lines() {
-        let line = line.trim();
-        if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
-            continue;
-        }
-
-        if line.starts_with('[') && line.ends_with(']') {
-            current_section = line[1..line.len() - 1].trim().to_string();
-            if current_section.starts_with("profile ") {
-                current_section = current_section["profile ".len()..].to_string();
-            }
-            sections
-                .entry(current_section.clone())
-                .or_insert_with(std::collections::HashMap::new);
+/// Read cached SSO token if valid, refreshing it when it is about to expire
+/// and the cache holds a refresh token
+/// Tries multiple cache file formats for compatibility with AWS CLI v1 and v2
+pub fn read_cached_token(config: &SsoConfig) -> Option<String> {
+    let cache_dir = sso_cache_dir().ok()?;
+    let now = chrono::Utc::now();
+
+    for location in token_locations(config, &cache_dir, keychain::is_enabled()) {
+        let Some(cached) = location
+            .read()
+            .and_then(|content| serde_json::from_str::<CachedToken>(&content).ok())
+        else {
             continue;
-        }
-
-        if let Some((key, value)) = line.split_once('=') {
-            if !current_section.is_empty() {
-                sections
-                    .entry(current_section.clone())
-                    .or_insert_with(std::collections::HashMap::new)
-                    .insert(key.trim().to_string(), value.trim().to_string());
+        };
+        // Tokens without a parseable expiry are used as-is
+        let expires_at = cached.expires_at();
+        let valid = expires_at.is_none_or(|t| t > now);
+        let expiring = expires_at
+            .is_some_and(|t| t <= now + chrono::Duration::seconds(TOKEN_REFRESH_WINDOW_SECS));
+
+        if expiring && cached.is_refreshable(now) {
+            let access_token = cached.access_token.clone();
+            match refresh_cached_token(config, &location, cached) {
+                Ok(token) => return Some(token),
+                Err(e) => {
+                    debug!("{}", e);
+                    if valid {
+                        return Some(access_token);
+                    }
+                }
             }
+        } else if valid {
+            debug!(
+                "Found valid SSO token in {:?} (sso_session: {})",
+                location, config.sso_session
+            );
+            return Some(cached.access_token);
+        } else {
+            trace!("SSO token in {:?} is expired", location);
         }
     }
 
-    sections
-}
-
-/// Read cached SSO token if valid
-/// Tries multiple cache file formats for compatibility with AWS CLI v1 and v2
-pub fn read_cached_token(config: &SsoConfig) -> Option<String> {
-    let cache_dir = aws_config_dir().ok()?.join("sso").join("cache");
-
-    // AWS CLI v2 with sso_session uses SHA1 of the session name for cache file
-    // Try this format first as it's the most current
-    let mut hasher = Sha1::new();
-    hasher.update(config.sso_session.as_bytes());
-    let hash = hasher.finalize();
-    let cache_file_v2 = format!("{:x}.json", hash);
-    let cache_path_v2 = cache_dir.join(&cache_file_v2);
-
-    if let Some(token) = try_read_token_file(&cache_path_v2) {
-        debug!(
-            "Found valid SSO token using CLI v2 format (sso_session: {})",
-            config.sso_session
-        );
-        return Some(token);
-    }
-
-    // AWS CLI v1 / legacy format uses SHA1 of start_url for cache file
-    let mut hasher = Sha1::new();
-    hasher.update(config.sso_start_url.as_bytes());
-    let hash = hasher.finalize();
-    let cache_file_legacy = format!("{:x}.json", hash);
-    let cache_path_legacy = cache_dir.join(&cache_file_legacy);
-
-    if let Some(token) = try_read_token_file(&cache_path_legacy) {
-        debug!("Found valid SSO token using legacy format (start_url-based)");
-        return Some(token);
-    }
-
     trace!(
         "No valid SSO token found in cache for session '{}' or start_url",
         config.sso_session
@@ -518,18 +703,136 @@ pub fn read_cached_token(config: &SsoConfig) -> Option<String> {
     None
 }
 
-/// Helper function to read and validate a token file
-fn try_read_token_file(cache_path: &std::path::Path) -> Option<String> {
-    let content = fs::read_to_string(cache_path).ok()?;
-    let cached: CachedToken = serde_json::from_str(&content).ok()?;
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFIG: &str = "\
+[profile dev]
+sso_session = corp
+sso_account_id = 111111111111
+sso_role_name = Developer
+
+[profile ops]
+sso_session = ops
+sso_account_id = 222222222222
+sso_role_name = Admin
+
+[profile old]
+sso_start_url = https://corp.awsapps.com/start
+sso_region = us-east-1
+sso_account_id = 333333333333
+sso_role_name = ReadOnly
+
+[sso-session corp]
+sso_start_url = https://corp.awsapps.com/start
+sso_region = eu-west-1
+
+[sso-session ops]
+sso_start_url = https://corp.awsapps.com/start
+sso_region = eu-west-1
+sso_registration_scopes = sso:account:access, codewhisperer:completions
+";
+
+    #[test]
+    fn test_parse_legacy_and_session_profiles() {
+        let dev = parse_sso_config_from_content("dev", CONFIG).unwrap();
+        assert!(!dev.legacy);
+        assert_eq!(dev.sso_session, "corp");
+        assert_eq!(dev.registration_scopes, vec!["sso:account:access"]);
+
+        let ops = parse_sso_config_from_content("ops", CONFIG).unwrap();
+        assert_eq!(
+            ops.registration_scopes,
+            vec!["sso:account:access", "codewhisperer:completions"]
+        );
 
-    // Check expiration
-    if let Ok(expires_at) = chrono::DateTime::parse_from_rfc3339(&cached.expires_at) {
-        if expires_at <= chrono::Utc::now() {
-            trace!("SSO token in {:?} is expired", cache_path);
-            return None;
-        }
+        let old = parse_sso_config_from_content("old", CONFIG).unwrap();
+        assert!(old.legacy);
+        assert_eq!(old.sso_region, "us-east-1");
+        assert!(old.registration_scopes.is_empty());
+        assert!(registration_request(&old).get("scopes").is_none());
+    }
+
+    #[test]
+    fn test_sessions_on_one_start_url_use_separate_caches() {
+        let dev = parse_sso_config_from_content("dev", CONFIG).unwrap();
+        let ops = parse_sso_config_from_content("ops", CONFIG).unwrap();
+        let old = parse_sso_config_from_content("old", CONFIG).unwrap();
+
+        assert_ne!(dev.token_cache_files()[0], ops.token_cache_files()[0]);
+        // Legacy profiles share the CLI's start URL keyed cache
+        assert_eq!(
+            old.token_cache_files(),
+            vec![dev.token_cache_files()[1].clone()]
+        );
     }
 
-    Some(cached.access_token)
+    #[test]
+    fn test_cached_token_formats() {
+        let now = chrono::Utc::now();
+        let v1: CachedToken = serde_json::from_str(
+            r#"{"access_token":"a","expires_at":"2099-01-01T00:00:00Z","start_url":"u"}"#,
+        )
+        .unwrap();
+        assert!(!v1.is_refreshable(now));
+
+        let v2: CachedToken = serde_json::from_str(
+            r#"{"accessToken":"a","expiresAt":"2000-01-01T00:00:00Z","startUrl":"u",
+                "clientId":"c","clientSecret":"s","refreshToken":"r",
+                "registrationExpiresAt":"2099-01-01T00:00:00Z"}"#,
+        )
+        .unwrap();
+        assert!(v2.is_refreshable(now));
+        assert!(serde_json::to_string(&v2)
+            .unwrap()
+            .contains("\"refreshToken\""));
+    }
+
+    #[test]
+    fn test_token_locations_prefer_keychain() {
+        let dev = parse_sso_config_from_content("dev", CONFIG).unwrap();
+        let dir = Path::new("/cache");
+
+        let files = token_locations(&dev, dir, false);
+        assert_eq!(files.len(), 2);
+        assert!(files
+            .iter()
+            .all(|location| matches!(location, CacheLocation::File(_))));
+
+        let with_keychain = token_locations(&dev, dir, true);
+        assert_eq!(
+            with_keychain[0],
+            CacheLocation::Keychain(format!("sso-token:{}", dev.token_cache_files()[0]))
+        );
+        assert_eq!(with_keychain[2..], files[..]);
+    }
+
+    #[test]
+    fn test_cli_v2_token_round_trip() {
+        let now = chrono::Utc::now();
+        let cli: CachedToken = serde_json::from_str(
+            r#"{"startUrl":"u","region":"eu-west-1","accessToken":"a",
+                "expiresAt":"2000-01-01T00:00:00Z","clientId":"c","clientSecret":"s",
+                "refreshToken":"r","scopes":["sso:account:access"]}"#,
+        )
+        .unwrap();
+        // No registrationExpiresAt recorded: still worth a refresh attempt
+        assert!(cli.is_refreshable(now));
+
+        let refreshed = CachedToken {
+            access_token: "b".to_string(),
+            ..cli
+        };
+        let written: serde_json::Value =
+            serde_json::from_str(&serde_json::to_string(&refreshed).unwrap()).unwrap();
+        assert_eq!(written["accessToken"], "b");
+        assert_eq!(written["scopes"][0], "sso:account:access");
+
+        let expired_registration = CachedToken {
+            registration_expires_at: Some("2000-01-01T00:00:00Z".to_string()),
+            ..refreshed
+        };
+        assert!(!expired_registration.is_refreshable(now));
+    }
 }
@@ -36,14 +36,26 @@ pub fn generate_bash() -> String {
         return 0
     fi
 
+    # Handle --record completion (recording file)
+    if [[ ${prev} == "--record" ]]; then
+        COMPREPLY=( $(compgen -f -- "${cur}") )
+        return 0
+    fi
+
     for i in "${COMP_WORDS[@]:0:COMP_CWORD}"; do
         case "${cmd},${i}" in
             ",$1")
                 cmd="taws"
                 ;;
+            taws,approve)
+                cmd="taws__approve"
+                ;;
             taws,completion)
                 cmd="taws__completion"
                 ;;
+            taws,report)
+                cmd="taws__report"
+                ;;
             taws,help)
                 cmd="taws__help"
                 ;;
@@ -54,12 +66,21 @@ pub fn generate_bash() -> String {
 
     case "${cmd}" in
         taws)
-            opts="-p -r -h -V --profile --region --log-level --readonly --endpoint-url --help --version completion help"
+            opts="-p -r -h -V --profile --region --log-level --readonly --endpoint-url --record --redact --help --version approve completion report help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 1 ]]; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
             fi
             ;;
+        taws__approve)
+            opts="-h --help"
+            if [[ ${cur} == -* ]]; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            COMPREPLY=( $(compgen -f -- "${cur}") )
+            return 0
+            ;;
         taws__completion)
             opts="-h --help bash zsh fish powershell elvish"
             if [[ ${cur} == -* ]]; then
@@ -69,8 +90,21 @@ pub fn generate_bash() -> String {
             COMPREPLY=( $(compgen -W "bash zsh fish powershell elvish" -- "${cur}") )
             return 0
             ;;
+        taws__report)
+            opts="-o -h --template --output --format --help"
+            if [[ ${prev} == "--format" ]]; then
+                COMPREPLY=( $(compgen -W "markdown html" -- "${cur}") )
+                return 0
+            fi
+            if [[ ${prev} == "--template" || ${prev} == "-o" || ${prev} == "--output" ]]; then
+                COMPREPLY=( $(compgen -f -- "${cur}") )
+                return 0
+            fi
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         taws__help)
-            opts="completion help"
+            opts="approve completion report help"
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
@@ -124,6 +158,8 @@ _taws() {
         '--log-level=[Log level for debugging]:LOG_LEVEL:(off error warn info debug trace)' \
         '--endpoint-url=[Custom AWS endpoint URL]:ENDPOINT_URL:_default' \
         '--readonly[Run in read-only mode]' \
+        '--record=[Record the session to an asciinema file]:FILE:_files' \
+        '--redact[Mask sensitive values in the recording]' \
         '-h[Print help]' \
         '--help[Print help]' \
         '-V[Print version]' \
@@ -138,6 +174,13 @@ _taws() {
         (( CURRENT += 1 ))
         curcontext="${curcontext%:*:*}:taws-command-$line[1]:"
         case $line[1] in
+            (approve)
+                _arguments "${_arguments_options[@]}" : \
+                    '-h[Print help]' \
+                    '--help[Print help]' \
+                    ':file -- Action request file:_files' \
+                    && ret=0
+                ;;
             (completion)
                 _arguments "${_arguments_options[@]}" : \
                     '-h[Print help]' \
@@ -145,6 +188,16 @@ _taws() {
                     ':shell:(bash zsh fish powershell elvish)' \
                     && ret=0
                 ;;
+            (report)
+                _arguments "${_arguments_options[@]}" : \
+                    '--template=[Report template (YAML)]:FILE:_files' \
+                    '-o+[Write the report to a file]:FILE:_files' \
+                    '--output=[Write the report to a file]:FILE:_files' \
+                    '--format=[Output format]:FORMAT:(markdown html)' \
+                    '-h[Print help]' \
+                    '--help[Print help]' \
+                    && ret=0
+                ;;
             (help)
                 _arguments "${_arguments_options[@]}" : \
                     ":: :_taws_help_commands" \
@@ -160,7 +213,9 @@ _taws() {
 
 _taws_commands() {
     local commands; commands=(
+        'approve:Approve an action request written by another operator'
         'completion:Generate shell completion scripts'
+        'report:Render a Markdown or HTML report of the views listed in a template'
         'help:Print help for the given subcommand(s)'
     )
     _describe -t commands 'taws commands' commands "$@"
@@ -168,7 +223,9 @@ _taws_commands() {
 
 _taws_help_commands() {
     local commands; commands=(
+        'approve:Approve an action request written by another operator'
         'completion:Generate shell completion scripts'
+        'report:Render a Markdown or HTML report of the views listed in a template'
         'help:Print help for the given subcommand(s)'
     )
     _describe -t commands 'taws help commands' commands "$@"
@@ -204,13 +261,25 @@ complete -c taws -l log-level -d 'Log level for debugging' -xa "off error warn i
 # Other options
 complete -c taws -l readonly -d 'Run in read-only mode'
 complete -c taws -l endpoint-url -d 'Custom AWS endpoint URL'
+complete -c taws -l record -d 'Record the session to an asciinema file' -rF
+complete -c taws -l redact -d 'Mask sensitive values in the recording'
 complete -c taws -s h -l help -d 'Print help'
 complete -c taws -s V -l version -d 'Print version'
 
 # Subcommands
+complete -c taws -n "__fish_use_subcommand" -a "approve" -d 'Approve an action request written by another operator'
 complete -c taws -n "__fish_use_subcommand" -a "completion" -d 'Generate shell completion scripts'
+complete -c taws -n "__fish_use_subcommand" -a "report" -d 'Render a Markdown or HTML report of the views listed in a template'
 complete -c taws -n "__fish_use_subcommand" -a "help" -d 'Print help for subcommand(s)'
 
+# Approve subcommand
+complete -c taws -n "__fish_seen_subcommand_from approve" -F
+
+# Report subcommand
+complete -c taws -n "__fish_seen_subcommand_from report" -l template -d 'Report template (YAML)' -rF
+complete -c taws -n "__fish_seen_subcommand_from report" -s o -l output -d 'Write the report to a file' -rF
+complete -c taws -n "__fish_seen_subcommand_from report" -l format -d 'Output format' -xa "markdown html"
+
 # Completion subcommand
 complete -c taws -n "__fish_seen_subcommand_from completion" -xa "bash zsh fish powershell elvish"
 "#
@@ -281,7 +350,7 @@ Register-ArgumentCompleter -Native -CommandName 'taws' -ScriptBlock {
 
     switch ($command) {
         'taws' {
-            @('--profile', '-p', '--region', '-r', '--log-level', '--readonly', '--endpoint-url', '--help', '-h', '--version', '-V', 'completion', 'help') | ForEach-Object {
+            @('--profile', '-p', '--region', '-r', '--log-level', '--readonly', '--endpoint-url', '--record', '--redact', '--help', '-h', '--version', '-V', 'approve', 'completion', 'report', 'help') | ForEach-Object {
                 if ($_ -like "$wordToComplete*") {
                     $completions += [CompletionResult]::new($_, $_, 'ParameterName', $_)
                 }
@@ -294,8 +363,15 @@ Register-ArgumentCompleter -Native -CommandName 'taws' -ScriptBlock {
                 }
             }
         }
+        'taws;report' {
+            @('--template', '--output', '-o', '--format', '--help', '-h') | ForEach-Object {
+                if ($_ -like "$wordToComplete*") {
+                    $completions += [CompletionResult]::new($_, $_, 'ParameterName', $_)
+                }
+            }
+        }
         'taws;help' {
-            @('completion', 'help') | ForEach-Object {
+            @('approve', 'completion', 'report', 'help') | ForEach-Object {
                 if ($_ -like "$wordToComplete*") {
                     $completions += [CompletionResult]::new($_, $_, 'ParameterValue', $_)
                 }
@@ -3,8 +3,10 @@
 //! Stores user preferences in ~/.config/taws/config.yaml (XDG compliant)
 //! Falls back to ~/.taws/config.yaml if XDG dirs not available
 
+use crate::resource::{ColumnDef, ResourceFilter};
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::PathBuf;
 use tracing::{debug, warn};
@@ -27,6 +29,499 @@ pub struct Config {
     /// Recently used regions (most recent first, max 6)
     #[serde(default)]
     pub recently_used_regions: Vec<String>,
+
+    /// Saved incident dashboard layouts
+    #[serde(default)]
+    pub incident_layouts: Vec<IncidentLayout>,
+
+    /// SSM Change Calendar used to detect change freezes
+    #[serde(default)]
+    pub change_calendar: ChangeCalendarConfig,
+
+    /// Simulate action permissions with the IAM policy simulator and grey out denied actions
+    #[serde(default)]
+    pub permission_preflight: bool,
+
+    /// Probe one read-only call per service for each profile and grey out
+    /// resource types whose service denied it
+    #[serde(default)]
+    pub capability_probe: bool,
+
+    /// Two-person approval for destructive actions
+    #[serde(default)]
+    pub approval: ApprovalConfig,
+
+    /// Where to report finished background jobs
+    #[serde(default)]
+    pub notifications: NotificationsConfig,
+
+    /// Refresh views when their resources change instead of only on demand
+    #[serde(default)]
+    pub live_refresh: LiveRefreshConfig,
+
+    /// Masking of sensitive values for screen sharing (Ctrl+r toggles it)
+    #[serde(default)]
+    pub redaction: RedactionConfig,
+
+    /// How sizes, durations and counts are displayed
+    #[serde(default)]
+    pub formatting: FormattingConfig,
+
+    /// Where SSO tokens and assumed-role credentials taws caches are kept
+    #[serde(default)]
+    pub credential_store: CredentialStore,
+
+    /// When expiring resources (e.g., certificates) are highlighted
+    #[serde(default)]
+    pub expiry_warning: ExpiryWarningConfig,
+
+    /// Session name template for roles taws assumes, e.g. "taws-{user}-{host}"
+    /// (a profile's own role_session_name wins; unset = "taws-session")
+    #[serde(default)]
+    pub role_session_name: Option<String>,
+
+    /// Box-drawing borders and markers, or plain ASCII for terminals that garble them
+    #[serde(default)]
+    pub border_style: BorderStyle,
+
+    /// Pager for detail JSON and log streams, e.g. "bat -l json" (unset = $PAGER, then "less -R")
+    #[serde(default)]
+    pub pager: Option<String>,
+
+    /// Editor for detail JSON, e.g. "code --wait" (unset = $VISUAL, $EDITOR, then "vi")
+    #[serde(default)]
+    pub editor: Option<String>,
+
+    /// Fuzzy finder for picking rows of long lists, e.g. "fzf" or "sk" (unset = off)
+    #[serde(default)]
+    pub picker: Option<String>,
+
+    /// Sort order, hidden columns and column widths per resource type (e.g., "ec2-instances")
+    #[serde(default)]
+    pub views: BTreeMap<String, ViewConfig>,
+
+    /// Loading of further pages in paginated views
+    #[serde(default)]
+    pub pagination: PaginationConfig,
+
+    /// How often each resource type is opened, for ordering the command palette
+    #[serde(default)]
+    pub usage: UsageConfig,
+
+    /// Named environments bundling a profile, region and default view (`:ctx <name>`)
+    #[serde(default)]
+    pub contexts: Vec<NamedContext>,
+
+    /// Context last switched to, shown in the header while still in effect
+    #[serde(default)]
+    pub last_context: Option<String>,
+}
+
+/// Resource open counts that order the command palette by frequency
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UsageConfig {
+    /// Count opens and list the most used resource types first (off = alphabetical, nothing counted)
+    #[serde(default = "default_true")]
+    pub adaptive_ordering: bool,
+
+    /// Times each resource type was opened (e.g., "ec2-instances": 42)
+    #[serde(default)]
+    pub open_counts: BTreeMap<String, u32>,
+}
+
+impl Default for UsageConfig {
+    fn default() -> Self {
+        Self {
+            adaptive_ordering: true,
+            open_counts: BTreeMap::new(),
+        }
+    }
+}
+
+/// Limits for loading the remaining pages of a view on demand
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaginationConfig {
+    /// Most items one "fetch all" (`}`) adds to a view; press again to continue
+    #[serde(default = "default_fetch_all_limit")]
+    pub fetch_all_limit: usize,
+}
+
+fn default_fetch_all_limit() -> usize {
+    5000
+}
+
+impl Default for PaginationConfig {
+    fn default() -> Self {
+        Self {
+            fetch_all_limit: default_fetch_all_limit(),
+        }
+    }
+}
+
+/// Column customizations of a resource view; columns are referenced by header
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct ViewConfig {
+    /// Column the list is sorted by (unset = API order)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort: Option<ViewSort>,
+
+    /// Headers of columns not shown
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub hidden: Vec<String>,
+
+    /// Column widths that differ from the resource definition (percent of the table)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub widths: BTreeMap<String, u16>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ViewSort {
+    pub column: String,
+    #[serde(default)]
+    pub descending: bool,
+}
+
+impl ViewConfig {
+    /// Whether the view has no customizations (and needn't be stored)
+    pub fn is_default(&self) -> bool {
+        self == &Self::default()
+    }
+
+    pub fn is_hidden(&self, header: &str) -> bool {
+        self.hidden.iter().any(|h| h == header)
+    }
+
+    /// Shown columns of a resource definition with their widths applied
+    pub fn columns(&self, columns: &[ColumnDef]) -> Vec<ColumnDef> {
+        columns
+            .iter()
+            .filter(|col| !self.is_hidden(&col.header))
+            .map(|col| ColumnDef {
+                width: self.widths.get(&col.header).copied().unwrap_or(col.width),
+                ..col.clone()
+            })
+            .collect()
+    }
+}
+
+/// Days-before-expiry thresholds for highlighting rows
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExpiryWarningConfig {
+    /// Rows turn yellow at or below this many days
+    #[serde(default = "default_expiry_warning_days")]
+    pub warning_days: i64,
+
+    /// Rows turn red at or below this many days (and once expired)
+    #[serde(default = "default_expiry_critical_days")]
+    pub critical_days: i64,
+}
+
+fn default_expiry_warning_days() -> i64 {
+    30
+}
+
+fn default_expiry_critical_days() -> i64 {
+    7
+}
+
+impl Default for ExpiryWarningConfig {
+    fn default() -> Self {
+        Self {
+            warning_days: default_expiry_warning_days(),
+            critical_days: default_expiry_critical_days(),
+        }
+    }
+}
+
+impl ExpiryWarningConfig {
+    /// Row color for a days-until-expiry value (None = not highlighted)
+    pub fn color_for(&self, days: i64) -> Option<[u8; 3]> {
+        if days <= self.critical_days {
+            Some([255, 0, 0])
+        } else if days <= self.warning_days {
+            Some([255, 255, 0])
+        } else {
+            None
+        }
+    }
+}
+
+/// Characters used for borders, arrows and markers
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BorderStyle {
+    #[default]
+    Unicode,
+    /// Only ASCII (+-| borders, ^/v arrows), for fonts, locales or SSH jump
+    /// hosts that render box-drawing characters incorrectly
+    Ascii,
+}
+
+/// Storage for cached credentials
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CredentialStore {
+    /// Plaintext files in ~/.aws (shared with the AWS CLI); assumed-role
+    /// credentials are only kept in memory
+    #[default]
+    File,
+    /// The OS credential store (macOS Keychain, Windows Credential Manager,
+    /// Secret Service)
+    Keychain,
+}
+
+/// Display of numeric column values
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FormattingConfig {
+    /// Show humanized values (1.5 GiB, 2h 5m, 12,345) instead of raw API values
+    #[serde(default = "default_true")]
+    pub humanize: bool,
+
+    /// Binary (GiB) or decimal (GB) size units
+    #[serde(default)]
+    pub size_units: SizeUnits,
+
+    /// Locale for digit separators, e.g. "de_DE" (unset = LC_ALL/LC_NUMERIC/LANG)
+    #[serde(default)]
+    pub locale: Option<String>,
+}
+
+impl Default for FormattingConfig {
+    fn default() -> Self {
+        Self {
+            humanize: true,
+            size_units: SizeUnits::default(),
+            locale: None,
+        }
+    }
+}
+
+/// Unit system for byte sizes
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SizeUnits {
+    /// Powers of 1024 (KiB, MiB, GiB)
+    #[default]
+    Binary,
+    /// Powers of 1000 (KB, MB, GB)
+    Decimal,
+}
+
+/// Which values are masked while redaction is on
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RedactionConfig {
+    /// Start with redaction on
+    #[serde(default)]
+    pub enabled: bool,
+
+    #[serde(default = "default_true")]
+    pub account_ids: bool,
+
+    #[serde(default = "default_true")]
+    pub arns: bool,
+
+    #[serde(default = "default_true")]
+    pub ip_addresses: bool,
+
+    /// Access keys and values of secret-like keys (password, token, ...)
+    #[serde(default = "default_true")]
+    pub secrets: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+impl Default for RedactionConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            account_ids: true,
+            arns: true,
+            ip_addresses: true,
+            secrets: true,
+        }
+    }
+}
+
+/// Event-driven refresh settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LiveRefreshConfig {
+    /// Where change events come from (unset = disabled)
+    #[serde(default)]
+    pub source: Option<LiveRefreshSource>,
+
+    /// SQS queue fed by EventBridge rules (for `source: sqs`)
+    #[serde(default)]
+    pub queue_url: Option<String>,
+
+    /// How often to check for changes
+    #[serde(default = "default_live_refresh_interval")]
+    pub interval_seconds: u64,
+}
+
+fn default_live_refresh_interval() -> u64 {
+    30
+}
+
+impl Default for LiveRefreshConfig {
+    fn default() -> Self {
+        Self {
+            source: None,
+            queue_url: None,
+            interval_seconds: default_live_refresh_interval(),
+        }
+    }
+}
+
+/// Change event source for live refresh
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LiveRefreshSource {
+    /// Poll CloudTrail for recent write API calls
+    Cloudtrail,
+    /// Drain an SQS queue that EventBridge rules forward events to
+    Sqs,
+}
+
+/// Job completion notification settings
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct NotificationsConfig {
+    /// Webhook receiving a Slack-compatible JSON payload when a watched job finishes
+    #[serde(default)]
+    pub webhook_url: Option<String>,
+}
+
+/// Two-person approval settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApprovalConfig {
+    /// Write destructive actions to a request file instead of executing them
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Role or user ARNs allowed to approve requests (any other identity when empty)
+    #[serde(default)]
+    pub approvers: Vec<String>,
+
+    /// How long a request can wait for approval
+    #[serde(default = "default_approval_expiry_minutes")]
+    pub expiry_minutes: i64,
+}
+
+fn default_approval_expiry_minutes() -> i64 {
+    60
+}
+
+impl Default for ApprovalConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            approvers: Vec::new(),
+            expiry_minutes: default_approval_expiry_minutes(),
+        }
+    }
+}
+
+/// SSM Change Calendar settings
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ChangeCalendarConfig {
+    /// Calendar names or ARNs to check (empty = all Change Calendars owned by the account)
+    #[serde(default)]
+    pub calendars: Vec<String>,
+
+    /// What happens to mutating actions while a calendar is closed
+    #[serde(default)]
+    pub freeze_policy: FreezePolicy,
+}
+
+/// Handling of mutating actions during a change freeze
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum FreezePolicy {
+    /// Ask for an extra confirmation
+    #[default]
+    Confirm,
+    /// Refuse the action
+    Block,
+}
+
+/// A profile, region and default view switched to together (e.g., "prod-eu")
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NamedContext {
+    pub name: String,
+    pub profile: String,
+    /// Region to switch to (unset = the profile's default region)
+    #[serde(default)]
+    pub region: Option<String>,
+    /// Resource type to open (unset = stay on the current view)
+    #[serde(default)]
+    pub resource: Option<String>,
+}
+
+/// A named set of panels for the incident dashboard
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IncidentLayout {
+    pub name: String,
+    #[serde(default)]
+    pub panels: Vec<IncidentPanel>,
+}
+
+/// A live element pinned to the incident dashboard
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum IncidentPanel {
+    /// First page of a resource listing (e.g., alarms in ALARM, a service's tasks)
+    Resource {
+        resource_key: String,
+        title: String,
+        #[serde(default)]
+        filters: Vec<ResourceFilter>,
+    },
+    /// Latest events of a CloudWatch log stream
+    Logs {
+        log_group: String,
+        log_stream: String,
+    },
+    /// A CloudWatch metric over the last hour
+    Metric {
+        namespace: String,
+        metric_name: String,
+        stat: String,
+        #[serde(default)]
+        dimensions: Vec<(String, String)>,
+    },
+}
+
+impl IncidentPanel {
+    /// Panel title shown in the dashboard border
+    pub fn title(&self) -> String {
+        match self {
+            IncidentPanel::Resource { title, .. } => title.clone(),
+            IncidentPanel::Logs {
+                log_group,
+                log_stream,
+            } => format!("{} / {}", log_group, log_stream),
+            IncidentPanel::Metric {
+                namespace,
+                metric_name,
+                stat,
+                dimensions,
+            } => {
+                let dims: Vec<&str> = dimensions.iter().map(|(_, v)| v.as_str()).collect();
+                if dims.is_empty() {
+                    format!("{} {} ({})", namespace, metric_name, stat)
+                } else {
+                    format!(
+                        "{} {} {} ({})",
+                        namespace,
+                        metric_name,
+                        dims.join("/"),
+                        stat
+                    )
+                }
+            }
+        }
+    }
 }
 
 impl Config {
@@ -129,6 +624,63 @@ impl Config {
         self.save()
     }
 
+    /// Count an open of a resource type and save (no-op with adaptive ordering off)
+    pub fn record_resource_open(&mut self, resource: &str) -> Result<()> {
+        if !self.usage.adaptive_ordering {
+            return Ok(());
+        }
+        let count = self
+            .usage
+            .open_counts
+            .entry(resource.to_string())
+            .or_default();
+        *count = count.saturating_add(1);
+        self.save()
+    }
+
+    /// Times a resource type was opened (0 with adaptive ordering off)
+    pub fn resource_open_count(&self, resource: &str) -> u32 {
+        if !self.usage.adaptive_ordering {
+            return 0;
+        }
+        self.usage.open_counts.get(resource).copied().unwrap_or(0)
+    }
+
+    /// Save an incident layout, replacing any layout with the same name
+    pub fn save_incident_layout(&mut self, layout: IncidentLayout) -> Result<()> {
+        debug!("Saving incident layout: {}", layout.name);
+        self.incident_layouts.retain(|l| l.name != layout.name);
+        self.incident_layouts.push(layout);
+        self.save()
+    }
+
+    /// Store a view's column customizations (dropping the entry once it is back to defaults) and save
+    pub fn save_view(&mut self, resource_key: &str, view: ViewConfig) -> Result<()> {
+        if view.is_default() {
+            self.views.remove(resource_key);
+        } else {
+            self.views.insert(resource_key.to_string(), view);
+        }
+        self.save()
+    }
+
+    /// Find a named context by name
+    pub fn context(&self, name: &str) -> Option<&NamedContext> {
+        self.contexts.iter().find(|c| c.name == name)
+    }
+
+    /// Remember the last context switched to and save
+    pub fn set_last_context(&mut self, name: &str) -> Result<()> {
+        debug!("Setting last context to: {}", name);
+        self.last_context = Some(name.to_string());
+        self.save()
+    }
+
+    /// Find a saved incident layout by name
+    pub fn incident_layout(&self, name: &str) -> Option<&IncidentLayout> {
+        self.incident_layouts.iter().find(|l| l.name == name)
+    }
+
     /// Get effective profile (config -> env -> default)
     pub fn effective_profile(&self) -> String {
         // Priority: 1. Environment variable, 2. Config file, 3. Default
@@ -158,6 +710,8 @@ mod tests {
         let config = Config::default();
         assert!(config.profile.is_none());
         assert!(config.region.is_none());
+        assert_eq!(config.pagination.fetch_all_limit, 5000);
+        assert!(config.usage.adaptive_ordering);
     }
 
     #[test]
@@ -167,6 +721,59 @@ mod tests {
             region: Some("eu-west-1".to_string()),
             last_resource: Some("ec2-instances".to_string()),
             recently_used_regions: vec!["eu-west-1".to_string(), "us-east-1".to_string()],
+            incident_layouts: vec![],
+            change_calendar: ChangeCalendarConfig::default(),
+            permission_preflight: true,
+            capability_probe: true,
+            approval: ApprovalConfig::default(),
+            notifications: NotificationsConfig {
+                webhook_url: Some("https://hooks.slack.com/services/T0/B0/x".to_string()),
+            },
+            live_refresh: LiveRefreshConfig {
+                source: Some(LiveRefreshSource::Sqs),
+                queue_url: Some(
+                    "https://sqs.eu-west-1.amazonaws.com/123456789012/taws-events".to_string(),
+                ),
+                interval_seconds: 10,
+            },
+            redaction: RedactionConfig {
+                ip_addresses: false,
+                ..RedactionConfig::default()
+            },
+            formatting: FormattingConfig {
+                humanize: false,
+                size_units: SizeUnits::Decimal,
+                locale: Some("de_DE".to_string()),
+            },
+            credential_store: CredentialStore::Keychain,
+            expiry_warning: ExpiryWarningConfig {
+                warning_days: 45,
+                ..ExpiryWarningConfig::default()
+            },
+            role_session_name: Some("taws-{user}".to_string()),
+            border_style: BorderStyle::Ascii,
+            pager: Some("bat -l json".to_string()),
+            editor: Some("code --wait".to_string()),
+            picker: Some("fzf --height 40%".to_string()),
+            views: BTreeMap::from([(
+                "ec2-instances".to_string(),
+                ViewConfig {
+                    sort: Some(ViewSort {
+                        column: "TYPE".to_string(),
+                        descending: true,
+                    }),
+                    ..ViewConfig::default()
+                },
+            )]),
+            pagination: PaginationConfig {
+                fetch_all_limit: 20000,
+            },
+            usage: UsageConfig {
+                adaptive_ordering: false,
+                open_counts: BTreeMap::from([("lambda-functions".to_string(), 7)]),
+            },
+            contexts: vec![],
+            last_context: Some("prod-eu".to_string()),
         };
 
         let yaml = serde_yaml::to_string(&config).unwrap();
@@ -176,6 +783,89 @@ mod tests {
         assert_eq!(parsed.region, config.region);
         assert_eq!(parsed.last_resource, config.last_resource);
         assert_eq!(parsed.recently_used_regions, config.recently_used_regions);
+        assert!(parsed.permission_preflight);
+        assert!(parsed.capability_probe);
+        assert_eq!(
+            parsed.notifications.webhook_url,
+            config.notifications.webhook_url
+        );
+        assert_eq!(parsed.live_refresh.source, Some(LiveRefreshSource::Sqs));
+        assert_eq!(parsed.live_refresh.interval_seconds, 10);
+        assert!(!parsed.redaction.ip_addresses);
+        assert_eq!(parsed.pager.as_deref(), Some("bat -l json"));
+        assert_eq!(parsed.editor.as_deref(), Some("code --wait"));
+        assert_eq!(parsed.picker.as_deref(), Some("fzf --height 40%"));
+        assert_eq!(parsed.last_context.as_deref(), Some("prod-eu"));
+        assert!(parsed.redaction.arns);
+        assert!(!parsed.formatting.humanize);
+        assert_eq!(parsed.formatting.size_units, SizeUnits::Decimal);
+        assert_eq!(parsed.formatting.locale.as_deref(), Some("de_DE"));
+        assert_eq!(parsed.credential_store, CredentialStore::Keychain);
+        assert_eq!(parsed.expiry_warning.warning_days, 45);
+        assert_eq!(parsed.expiry_warning.critical_days, 7);
+        assert_eq!(parsed.role_session_name.as_deref(), Some("taws-{user}"));
+        assert!(yaml.contains("credential_store: keychain"));
+        assert_eq!(parsed.border_style, BorderStyle::Ascii);
+        assert!(yaml.contains("border_style: ascii"));
+        assert_eq!(parsed.views, config.views);
+        assert_eq!(parsed.pagination.fetch_all_limit, 20000);
+        assert!(!parsed.usage.adaptive_ordering);
+        assert_eq!(parsed.usage.open_counts.get("lambda-functions"), Some(&7));
+        // Counts are kept but ignored while adaptive ordering is off
+        assert_eq!(parsed.resource_open_count("lambda-functions"), 0);
+    }
+
+    #[test]
+    fn test_view_config_columns() {
+        let column = |header: &str, width: u16| ColumnDef {
+            header: header.to_string(),
+            json_path: header.to_lowercase(),
+            width,
+            color_map: None,
+            format: None,
+        };
+        let columns = vec![column("NAME", 30), column("STATE", 10), column("AZ", 15)];
+
+        let config: Config = serde_yaml::from_str(
+            "views:\n  ec2-instances:\n    hidden: [AZ]\n    widths:\n      NAME: 45\n",
+        )
+        .unwrap();
+        let view = &config.views["ec2-instances"];
+        assert!(view.sort.is_none());
+        let shown = view.columns(&columns);
+        assert_eq!(shown.len(), 2);
+        assert_eq!((shown[0].header.as_str(), shown[0].width), ("NAME", 45));
+        assert_eq!((shown[1].header.as_str(), shown[1].width), ("STATE", 10));
+
+        assert!(ViewConfig::default().is_default());
+        assert_eq!(ViewConfig::default().columns(&columns).len(), 3);
+        // Unchanged settings aren't written out
+        assert_eq!(
+            serde_yaml::to_string(&ViewConfig::default())
+                .unwrap()
+                .trim(),
+            "{}"
+        );
+    }
+
+    #[test]
+    fn test_expiry_warning_colors() {
+        let expiry = ExpiryWarningConfig::default();
+        assert_eq!(expiry.color_for(90), None);
+        assert_eq!(expiry.color_for(30), Some([255, 255, 0]));
+        assert_eq!(expiry.color_for(7), Some([255, 0, 0]));
+        assert_eq!(expiry.color_for(-3), Some([255, 0, 0]));
+    }
+
+    #[test]
+    fn test_live_refresh_defaults() {
+        let config: Config = serde_yaml::from_str("live_refresh:\n  source: cloudtrail\n").unwrap();
+        assert_eq!(
+            config.live_refresh.source,
+            Some(LiveRefreshSource::Cloudtrail)
+        );
+        assert_eq!(config.live_refresh.interval_seconds, 30);
+        assert!(Config::default().live_refresh.source.is_none());
     }
 
     #[test]
@@ -201,4 +891,101 @@ mod tests {
         assert_eq!(config.recently_used_regions.len(), 6);
         assert_eq!(config.recently_used_regions[0], "r5");
     }
+
+    #[test]
+    fn test_incident_layout_roundtrip() {
+        let layout = IncidentLayout {
+            name: "checkout-outage".to_string(),
+            panels: vec![
+                IncidentPanel::Resource {
+                    resource_key: "cloudwatch-alarms".to_string(),
+                    title: "CloudWatch Alarms".to_string(),
+                    filters: vec![ResourceFilter::new(
+                        "filter:state",
+                        vec!["ALARM".to_string()],
+                    )],
+                },
+                IncidentPanel::Logs {
+                    log_group: "/ecs/checkout".to_string(),
+                    log_stream: "web/abc123".to_string(),
+                },
+                IncidentPanel::Metric {
+                    namespace: "AWS/ECS".to_string(),
+                    metric_name: "CPUUtilization".to_string(),
+                    stat: "Average".to_string(),
+                    dimensions: vec![("ServiceName".to_string(), "checkout".to_string())],
+                },
+            ],
+        };
+        let config = Config {
+            incident_layouts: vec![layout.clone()],
+            ..Default::default()
+        };
+
+        let yaml = serde_yaml::to_string(&config).unwrap();
+        assert!(yaml.contains("kind: metric"));
+        let parsed: Config = serde_yaml::from_str(&yaml).unwrap();
+        assert_eq!(parsed.incident_layout("checkout-outage"), Some(&layout));
+        assert!(parsed.incident_layout("missing").is_none());
+    }
+
+    #[test]
+    fn test_named_contexts() {
+        let yaml = r#"
+contexts:
+  - name: prod-eu
+    profile: prod
+    region: eu-west-1
+    resource: ecs-clusters
+  - name: dev
+    profile: dev
+last_context: prod-eu
+"#;
+        let config: Config = serde_yaml::from_str(yaml).unwrap();
+        let prod = config.context("prod-eu").unwrap();
+        assert_eq!(prod.profile, "prod");
+        assert_eq!(prod.region.as_deref(), Some("eu-west-1"));
+        assert_eq!(prod.resource.as_deref(), Some("ecs-clusters"));
+        let dev = config.context("dev").unwrap();
+        assert!(dev.region.is_none() && dev.resource.is_none());
+        assert!(config.context("staging").is_none());
+        assert_eq!(config.last_context.as_deref(), Some("prod-eu"));
+    }
+
+    #[test]
+    fn test_incident_panel_title() {
+        let panel = IncidentPanel::Metric {
+            namespace: "AWS/SQS".to_string(),
+            metric_name: "ApproximateNumberOfMessagesVisible".to_string(),
+            stat: "Maximum".to_string(),
+            dimensions: vec![("QueueName".to_string(), "orders".to_string())],
+        };
+        assert_eq!(
+            panel.title(),
+            "AWS/SQS ApproximateNumberOfMessagesVisible orders (Maximum)"
+        );
+    }
+
+    #[test]
+    fn test_change_calendar_config() {
+        let config: Config = serde_yaml::from_str("region: eu-west-1\n").unwrap();
+        assert!(config.change_calendar.calendars.is_empty());
+        assert_eq!(config.change_calendar.freeze_policy, FreezePolicy::Confirm);
+
+        let config: Config = serde_yaml::from_str(
+            "change_calendar:\n  calendars: [prod-freeze]\n  freeze_policy: block\n",
+        )
+        .unwrap();
+        assert_eq!(config.change_calendar.calendars, vec!["prod-freeze"]);
+        assert_eq!(config.change_calendar.freeze_policy, FreezePolicy::Block);
+    }
+
+    #[test]
+    fn test_approval_config_defaults() {
+        let config: Config = serde_yaml::from_str("approval:\n  enabled: true\n").unwrap();
+        assert!(config.approval.enabled);
+        assert!(config.approval.approvers.is_empty());
+        assert_eq!(config.approval.expiry_minutes, 60);
+        assert!(!Config::default().approval.enabled);
+    }
 }
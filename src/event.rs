@@ -1,4 +1,4 @@
-use crate::app::{App, Mode, SsoLoginState};
+use crate::app::{App, Mode, PendingAction, SsoLoginState};
 use crate::aws::sso;
 use anyhow::Result;
 use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
@@ -19,18 +19,29 @@ pub async fn handle_events(app: &mut App) -> Result<bool> {
 }
 
 async fn handle_key_event(app: &mut App, key: KeyEvent) -> Result<bool> {
+    // Redaction toggle works in every view, so it can be flipped mid-demo
+    if key.code == KeyCode::Char('r') && key.modifiers.contains(KeyModifiers::CONTROL) {
+        app.redacted = !app.redacted;
+        return Ok(false);
+    }
+
     match app.mode {
         Mode::Normal => handle_normal_mode(app, key).await,
         Mode::Command => handle_command_mode(app, key).await,
         Mode::Help => handle_help_mode(app, key),
-        Mode::Describe => handle_describe_mode(app, key),
+        Mode::CellView => handle_cell_view_mode(app, key),
+        Mode::Columns => handle_columns_mode(app, key),
+        Mode::Describe => handle_describe_mode(app, key).await,
         Mode::Confirm => handle_confirm_mode(app, key).await,
         Mode::Warning => handle_warning_mode(app, key),
         Mode::Profiles => handle_profiles_mode(app, key).await,
         Mode::Regions => handle_regions_mode(app, key).await,
+        Mode::Contexts => handle_contexts_mode(app, key).await,
         Mode::SsoLogin => handle_sso_login_mode(app, key).await,
         Mode::ConsoleLogin => handle_console_login_mode(app, key).await,
         Mode::LogTail => handle_log_tail_mode(app, key).await,
+        Mode::Form => handle_form_mode(app, key).await,
+        Mode::Incident => handle_incident_mode(app, key).await,
     }
 }
 
@@ -77,6 +88,16 @@ async fn handle_normal_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
         // Quit with Ctrl+C
         KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => return Ok(true),
 
+        // Tabs - each tab keeps its own profile, region and view
+        KeyCode::Char('t') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            if let Err(e) = app.open_tab().await {
+                app.error_message = Some(format!("Failed to open tab: {}", e));
+            }
+        }
+        KeyCode::Char('w') if key.modifiers.contains(KeyModifiers::CONTROL) => app.close_tab(),
+        KeyCode::Tab => app.cycle_tab(true),
+        KeyCode::BackTab => app.cycle_tab(false),
+
         // Region shortcuts (0-5)
         KeyCode::Char('0') => {
             if let Some(region) = get_region_for_shortcut(app, 0) {
@@ -141,11 +162,22 @@ async fn handle_normal_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
                         if let Some(item) = app.selected_item() {
                             let id = crate::resource::extract_json_value(item, &resource.id_field);
                             if id != "-" && !id.is_empty() {
+                                if let Some(denied) = app.denied_permission(resource, action) {
+                                    app.error_message =
+                                        Some(permission_denied_message(action, &denied));
+                                }
                                 if app.readonly {
                                     app.show_warning(
                                         "This operation is not supported in read-only mode",
                                     );
-                                } else if let Some(pending) = app.create_pending_action(action, &id)
+                                } else if app.change_freeze_blocks() {
+                                    let message = app.change_freeze_message();
+                                    app.show_warning(&message);
+                                } else if action.form {
+                                    app.enter_form_mode(action, &id).await;
+                                } else if let Some(pending) = app
+                                    .create_bulk_pending_action(action)
+                                    .or_else(|| app.create_pending_action(action, &id))
                                 {
                                     app.enter_confirm_mode(pending);
                                 }
@@ -157,6 +189,12 @@ async fn handle_normal_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
             }
         }
 
+        // Pick a row with the external fuzzy finder (fzf, skim)
+        KeyCode::Char('p') if key.modifiers.contains(KeyModifiers::CONTROL) => app.open_picker(),
+
+        // Mark/unmark selected item for bulk actions
+        KeyCode::Char(' ') => app.toggle_mark_selected(),
+
         // Describe mode (d or Enter)
         KeyCode::Char('d') => app.enter_describe_mode().await,
         KeyCode::Enter => app.enter_describe_mode().await,
@@ -180,6 +218,12 @@ async fn handle_normal_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
                 app.prev_page().await?;
             }
         }
+        // Load the remaining pages into the current one (capped by config)
+        KeyCode::Char('}') => {
+            if app.pagination.has_more {
+                app.fetch_remaining_pages().await?;
+            }
+        }
 
         // Manual refresh
         KeyCode::Char('R') => {
@@ -187,9 +231,14 @@ async fn handle_normal_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
             app.refresh_current().await?;
         }
 
+        // Pin the current view to the incident dashboard (unless the view has its own 'P' action)
+        KeyCode::Char('P') if !app.has_action_shortcut("P") => app.pin_current_view(),
+
         // Mode switches
         KeyCode::Char(':') => app.enter_command_mode(),
         KeyCode::Char('?') => app.enter_help_mode(),
+        KeyCode::Char('v') if !app.has_action_shortcut("v") => app.enter_cell_view_mode(),
+        KeyCode::Char('C') if !app.has_action_shortcut("C") => app.enter_columns_mode(),
 
         // Backspace goes back in navigation
         KeyCode::Backspace => {
@@ -202,6 +251,8 @@ async fn handle_normal_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
         KeyCode::Esc => {
             if !app.filter_text.is_empty() {
                 app.clear_filter();
+            } else if !app.marked_ids.is_empty() {
+                app.clear_marks();
             } else if app.aws_filters.is_some() {
                 // Clear server-side AWS filters and refresh
                 app.clear_aws_filters().await?;
@@ -237,6 +288,14 @@ async fn handle_normal_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
                                         &resource.id_field,
                                     );
                                     if id != "-" && !id.is_empty() {
+                                        // Warn about actions the policy simulator denies, but
+                                        // let AWS make the final call
+                                        if let Some(denied) =
+                                            app.denied_permission(resource, action)
+                                        {
+                                            app.error_message =
+                                                Some(permission_denied_message(action, &denied));
+                                        }
                                         // Special handling for log tailing action
                                         if action.sdk_method == "tail_logs" {
                                             app.enter_log_tail_mode().await?;
@@ -245,30 +304,46 @@ async fn handle_normal_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
                                         } else if action.sdk_method == "ssm_connect" {
                                             app.request_ssm_connect();
                                             handled = true;
+                                        // Jump to the resource a row points at (e.g., an alarm's target)
+                                        } else if action.sdk_method == "goto_resource" {
+                                            app.goto_target_resource().await?;
+                                            handled = true;
+                                        // Open the row's dead-letter queue and peek at it
+                                        } else if action.sdk_method == "view_dlq" {
+                                            app.view_dead_letter_queue().await?;
+                                            handled = true;
+                                        // Pin a row's metric to the incident dashboard
+                                        } else if action.sdk_method == "pin_metric" {
+                                            app.pin_alarm_metric();
+                                            handled = true;
+                                        } else if action.show_result && action.requires_confirm() {
+                                            // Retrieving sensitive values (e.g., secret values) is
+                                            // confirmed first and not allowed in readonly mode
+                                            if app.readonly && action.is_sensitive() {
+                                                app.show_warning(
+                                                    "Revealing sensitive values is disabled in read-only mode",
+                                                );
+                                            } else if let Some(pending) =
+                                                app.create_pending_action(action, &id)
+                                            {
+                                                app.enter_confirm_mode(pending);
+                                            }
+                                            handled = true;
                                         } else if action.show_result {
-                                            // Action that displays result (e.g., get_secret_value)
+                                            // Action that displays result (e.g., get_parameter)
                                             // These are read-only operations (retrieve and display data),
                                             // so they're allowed even in readonly mode
-                                            match crate::resource::execute_action_with_result(
-                                                &resource.service,
+                                            let service = resource.service.clone();
+                                            let action = action.clone();
+                                            show_action_result(
+                                                app,
+                                                &service,
                                                 &action.sdk_method,
-                                                &app.clients,
                                                 &id,
+                                                &action.display_name,
+                                                &action.sensitive_fields,
                                             )
-                                            .await
-                                            {
-                                                Ok(data) => {
-                                                    app.describe_data = Some(data);
-                                                    app.describe_scroll = 0;
-                                                    app.last_action_display_name =
-                                                        Some(action.display_name.clone());
-                                                    app.mode = crate::app::Mode::Describe;
-                                                }
-                                                Err(e) => {
-                                                    app.error_message =
-                                                        Some(format!("Action failed: {}", e));
-                                                }
-                                            }
+                                            .await;
                                             handled = true;
                                         // Block mutating actions in readonly mode
                                         } else if app.readonly {
@@ -276,14 +351,46 @@ async fn handle_normal_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
                                                 "This operation is not supported in read-only mode",
                                             );
                                             handled = true;
+                                        // Block mutating actions during a change freeze (per config)
+                                        } else if app.change_freeze_blocks() {
+                                            let message = app.change_freeze_message();
+                                            app.show_warning(&message);
+                                            handled = true;
+                                        } else if action.form {
+                                            // Action that collects parameters first (e.g., start automation)
+                                            app.enter_form_mode(action, &id).await;
+                                            handled = true;
                                         } else if action.requires_confirm() {
                                             // Check if action requires confirmation
-                                            if let Some(pending) =
-                                                app.create_pending_action(action, &id)
+                                            if let Some(pending) = app
+                                                .create_bulk_pending_action(action)
+                                                .or_else(|| app.create_pending_action(action, &id))
                                             {
                                                 app.enter_confirm_mode(pending);
                                                 handled = true;
                                             }
+                                        } else if app.change_freeze_active() {
+                                            // Unconfirmed actions need a confirmation during a change freeze
+                                            let pending = crate::app::PendingAction {
+                                                service: resource.service.clone(),
+                                                sdk_method: action.sdk_method.clone(),
+                                                resource_ids: vec![id.clone()],
+                                                message: format!(
+                                                    "{} '{}'?",
+                                                    action.display_name, id
+                                                ),
+                                                default_no: true,
+                                                destructive: true,
+                                                selected_yes: false,
+                                                requires_approval: false,
+                                                result_title: None,
+                                                sensitive_fields: Vec::new(),
+                                                requester_pays_bucket: None,
+                                                form: None,
+                                                watch: app.job_watch(action),
+                                            };
+                                            app.enter_confirm_mode(pending);
+                                            handled = true;
                                         } else {
                                             // Execute directly
                                             if let Err(e) = crate::resource::execute_action(
@@ -296,6 +403,9 @@ async fn handle_normal_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
                                             {
                                                 app.error_message =
                                                     Some(format!("Action failed: {}", e));
+                                            } else {
+                                                let watch = app.job_watch(action);
+                                                app.watch_job(watch.as_ref(), &id);
                                             }
                                             let _ = app.refresh_current().await;
                                             handled = true;
@@ -331,6 +441,14 @@ async fn handle_normal_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
     Ok(false)
 }
 
+/// Warning shown when the IAM policy simulator denies an action
+fn permission_denied_message(action: &crate::resource::ActionDef, denied: &str) -> String {
+    format!(
+        "'{}' may be denied: the IAM policy simulator reports an explicit deny for {}",
+        action.display_name, denied
+    )
+}
+
 async fn handle_filter_input(app: &mut App, key: KeyEvent) -> Result<bool> {
     match key.code {
         KeyCode::Esc => {
@@ -436,7 +554,83 @@ fn handle_help_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
     Ok(false)
 }
 
-fn handle_describe_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
+fn handle_cell_view_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
+    const PAGE_SIZE: usize = 20;
+
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('v') => {
+            app.exit_mode();
+        }
+        KeyCode::Char('l') | KeyCode::Right => {
+            app.cell_view_cycle_column(true);
+        }
+        KeyCode::Char('h') | KeyCode::Left => {
+            app.cell_view_cycle_column(false);
+        }
+        KeyCode::Char('j') | KeyCode::Down => {
+            app.cell_view_scroll_down(1);
+        }
+        KeyCode::Char('k') | KeyCode::Up => {
+            app.cell_view_scroll_up(1);
+        }
+        KeyCode::Char('f') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.cell_view_scroll_down(PAGE_SIZE);
+        }
+        KeyCode::Char('b') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.cell_view_scroll_up(PAGE_SIZE);
+        }
+        KeyCode::PageDown => {
+            app.cell_view_scroll_down(PAGE_SIZE);
+        }
+        KeyCode::PageUp => {
+            app.cell_view_scroll_up(PAGE_SIZE);
+        }
+        KeyCode::Char('g') | KeyCode::Home => {
+            app.cell_view_scroll_up(usize::MAX);
+        }
+        KeyCode::Char('G') | KeyCode::End => {
+            app.cell_view_scroll_down(usize::MAX);
+        }
+        KeyCode::Char('y') => {
+            app.copy_cell_value();
+        }
+        _ => {}
+    }
+    Ok(false)
+}
+
+fn handle_columns_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('C') => {
+            app.exit_mode();
+        }
+        KeyCode::Char('j') | KeyCode::Down => {
+            app.columns_editor_move(true);
+        }
+        KeyCode::Char('k') | KeyCode::Up => {
+            app.columns_editor_move(false);
+        }
+        KeyCode::Char(' ') | KeyCode::Enter => {
+            app.toggle_column_visibility();
+        }
+        KeyCode::Char('s') => {
+            app.cycle_column_sort();
+        }
+        KeyCode::Char('+') | KeyCode::Char('=') | KeyCode::Char('l') | KeyCode::Right => {
+            app.resize_column(5);
+        }
+        KeyCode::Char('-') | KeyCode::Char('h') | KeyCode::Left => {
+            app.resize_column(-5);
+        }
+        KeyCode::Char('r') => {
+            app.reset_view();
+        }
+        _ => {}
+    }
+    Ok(false)
+}
+
+async fn handle_describe_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
     // If search input is active, handle text input
     if app.describe_search_active {
         return handle_describe_search_input(app, key);
@@ -470,6 +664,28 @@ fn handle_describe_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
         KeyCode::Char('N') => {
             app.describe_prev_match();
         }
+        // Open the document in the external editor / pager
+        KeyCode::Char('e') => {
+            app.open_describe_in_editor();
+        }
+        KeyCode::Char('|') => {
+            app.open_describe_in_pager();
+        }
+        // Cycle through links to other resources and follow the highlighted one
+        KeyCode::Tab => {
+            app.describe_cycle_reference(true);
+        }
+        KeyCode::BackTab => {
+            app.describe_cycle_reference(false);
+        }
+        KeyCode::Enter if app.describe_reference_selected.is_some() => {
+            app.follow_describe_reference().await?;
+        }
+        // Reveal / mask sensitive values with 'v'
+        KeyCode::Char('v') if !app.describe_masked_fields.is_empty() => {
+            app.describe_revealed = !app.describe_revealed;
+            app.update_describe_search();
+        }
         // Page down with Ctrl+f or PageDown
         KeyCode::Char('f') if key.modifiers.contains(KeyModifiers::CONTROL) => {
             app.describe_scroll_down(PAGE_SIZE);
@@ -538,6 +754,31 @@ fn handle_warning_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
     Ok(false)
 }
 
+async fn handle_form_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
+    match key.code {
+        KeyCode::Esc => {
+            app.exit_mode();
+        }
+        KeyCode::Enter => {
+            app.submit_form().await?;
+        }
+        KeyCode::Tab | KeyCode::Down => {
+            app.form_next_field();
+        }
+        KeyCode::BackTab | KeyCode::Up => {
+            app.form_previous_field();
+        }
+        KeyCode::Backspace => {
+            app.form_edit(None);
+        }
+        KeyCode::Char(c) => {
+            app.form_edit(Some(c));
+        }
+        _ => {}
+    }
+    Ok(false)
+}
+
 async fn handle_confirm_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
     match key.code {
         // Toggle selection with arrow keys or tab
@@ -548,53 +789,19 @@ async fn handle_confirm_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
         }
         // Confirm with Enter
         KeyCode::Enter => {
-            if let Some(ref pending) = app.pending_action {
-                if pending.selected_yes {
-                    // Execute the action (if not in readonly mode)
-                    if app.readonly {
-                        app.error_message =
-                            Some("This operation is not supported in read-only mode".to_string());
-                    } else {
-                        let service = pending.service.clone();
-                        let method = pending.sdk_method.clone();
-                        let resource_id = pending.resource_id.clone();
-
-                        if let Err(e) = crate::resource::execute_action(
-                            &service,
-                            &method,
-                            &app.clients,
-                            &resource_id,
-                        )
-                        .await
-                        {
-                            app.error_message = Some(format!("Action failed: {}", e));
-                        }
-                        // Refresh after action
-                        let _ = app.refresh_current().await;
-                    }
-                }
-            }
+            let pending = app.pending_action.clone().filter(|p| p.selected_yes);
             app.exit_mode();
+            if let Some(pending) = pending {
+                execute_pending_action(app, pending).await;
+            }
         }
         // Quick yes/no
         KeyCode::Char('y') | KeyCode::Char('Y') => {
-            if app.readonly {
-                app.error_message =
-                    Some("This operation is not supported in read-only mode".to_string());
-            } else if let Some(ref pending) = app.pending_action {
-                let service = pending.service.clone();
-                let method = pending.sdk_method.clone();
-                let resource_id = pending.resource_id.clone();
-
-                if let Err(e) =
-                    crate::resource::execute_action(&service, &method, &app.clients, &resource_id)
-                        .await
-                {
-                    app.error_message = Some(format!("Action failed: {}", e));
-                }
-                let _ = app.refresh_current().await;
-            }
+            let pending = app.pending_action.clone();
             app.exit_mode();
+            if let Some(pending) = pending {
+                execute_pending_action(app, pending).await;
+            }
         }
         KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
             app.exit_mode();
@@ -604,6 +811,115 @@ async fn handle_confirm_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
     Ok(false)
 }
 
+/// Run an action that returns data and show the result in the describe view
+async fn show_action_result(
+    app: &mut App,
+    service: &str,
+    sdk_method: &str,
+    resource_id: &str,
+    title: &str,
+    sensitive_fields: &[String],
+) {
+    match crate::resource::execute_action_with_result(
+        service,
+        sdk_method,
+        &app.clients,
+        resource_id,
+    )
+    .await
+    {
+        Ok(data) => app.show_action_result(data, title, sensitive_fields),
+        Err(e) => app.error_message = Some(format!("Action failed: {}", e)),
+    }
+}
+
+/// Execute the pending action against every target ID, then refresh
+async fn execute_pending_action(app: &mut App, pending: PendingAction) {
+    // Accepted Requester Pays charges; only reads follow, so allowed in read-only mode
+    if let Some(bucket) = pending.requester_pays_bucket {
+        app.requester_pays_buckets.insert(bucket, true);
+        let _ = app.refresh_current().await;
+        return;
+    }
+    if app.readonly {
+        app.error_message = Some("This operation is not supported in read-only mode".to_string());
+        return;
+    }
+    // Confirmed read of sensitive values; nothing changes, so no freeze or approval
+    if let (Some(title), [resource_id]) = (&pending.result_title, pending.resource_ids.as_slice()) {
+        show_action_result(
+            app,
+            &pending.service,
+            &pending.sdk_method,
+            resource_id,
+            title,
+            &pending.sensitive_fields,
+        )
+        .await;
+        return;
+    }
+    if app.change_freeze_blocks() {
+        app.error_message = Some(app.change_freeze_message());
+        return;
+    }
+    if app.config.approval.enabled && pending.requires_approval {
+        match app.request_approval(&pending).await {
+            Ok(path) => {
+                app.clear_marks();
+                app.show_warning(&format!(
+                    "Approval requested. A second operator must run:\n\ntaws approve {}\n\nThe action runs here once it is approved.",
+                    path.display()
+                ));
+            }
+            Err(e) => app.error_message = Some(format!("Approval request failed: {}", e)),
+        }
+        return;
+    }
+    if let Some(form) = pending.form {
+        if let Err(e) = app.execute_form(form).await {
+            app.error_message = Some(format!("Action failed: {}", e));
+        }
+        return;
+    }
+
+    let mut failures = Vec::new();
+    for resource_id in &pending.resource_ids {
+        if let Err(e) = crate::resource::execute_action(
+            &pending.service,
+            &pending.sdk_method,
+            &app.clients,
+            resource_id,
+        )
+        .await
+        {
+            failures.push((resource_id.clone(), e));
+        } else {
+            app.watch_job(pending.watch.as_ref(), resource_id);
+        }
+    }
+
+    if pending.resource_ids.len() == 1 {
+        if let Some((_, e)) = failures.first() {
+            app.error_message = Some(format!("Action failed: {}", e));
+        }
+    } else if !failures.is_empty() {
+        let details: Vec<String> = failures
+            .iter()
+            .map(|(id, e)| format!("{}: {}", id, e))
+            .collect();
+        app.error_message = Some(format!(
+            "Action failed for {}/{} items: {}",
+            failures.len(),
+            pending.resource_ids.len(),
+            details.join("; ")
+        ));
+    }
+
+    app.clear_marks();
+    // Refresh after action
+    let _ = app.refresh_current().await;
+}
+
 async fn handle_profiles_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
     match key.code {
         KeyCode::Esc | KeyCode::Char('q') => {
@@ -654,6 +970,31 @@ async fn handle_regions_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
     Ok(false)
 }
 
+async fn handle_contexts_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') => {
+            app.exit_mode();
+        }
+        KeyCode::Char('j') | KeyCode::Down => {
+            app.next();
+        }
+        KeyCode::Char('k') | KeyCode::Up => {
+            app.previous();
+        }
+        KeyCode::Char('g') | KeyCode::Home => {
+            app.go_to_top();
+        }
+        KeyCode::Char('G') | KeyCode::End => {
+            app.go_to_bottom();
+        }
+        KeyCode::Enter => {
+            app.select_context().await?;
+        }
+        _ => {}
+    }
+    Ok(false)
+}
+
 async fn handle_sso_login_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
     let sso_state = match &app.sso_state {
         Some(state) => state.clone(),
@@ -779,7 +1120,7 @@ async fn handle_sso_login_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
                     if let Err(e) = app.switch_profile(&profile_to_switch).await {
                         app.error_message = Some(format!("Failed to switch profile: {}", e));
                     } else {
-                        let _ = app.refresh_current().await;
+                        let _ = app.resume_after_login().await;
                     }
                 }
                 _ => {}
@@ -925,7 +1266,7 @@ async fn handle_console_login_mode(app: &mut App, key: KeyEvent) -> Result<bool>
                     if let Err(e) = app.switch_profile(&profile_to_switch).await {
                         app.error_message = Some(format!("Failed to switch profile: {}", e));
                     } else {
-                        let _ = app.refresh_current().await;
+                        let _ = app.resume_after_login().await;
                     }
                 }
                 _ => {}
@@ -1111,11 +1452,101 @@ async fn handle_log_tail_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
         KeyCode::Char(' ') => {
             app.toggle_log_tail_pause();
         }
+        // Pin the stream to the incident dashboard
+        KeyCode::Char('P') => {
+            app.pin_log_tail();
+        }
+        // Read the collected events in the external pager
+        KeyCode::Char('|') => {
+            app.open_log_tail_in_pager();
+        }
+        _ => {}
+    }
+    Ok(false)
+}
+
+async fn handle_incident_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
+    match key.code {
+        KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => return Ok(true),
+        KeyCode::Esc | KeyCode::Char('q') => app.exit_incident_mode(),
+        KeyCode::Tab | KeyCode::Char('l') | KeyCode::Char('j') | KeyCode::Right | KeyCode::Down => {
+            app.incident_focus_next()
+        }
+        KeyCode::BackTab
+        | KeyCode::Char('h')
+        | KeyCode::Char('k')
+        | KeyCode::Left
+        | KeyCode::Up => app.incident_focus_prev(),
+        KeyCode::Char('x') => app.unpin_focused_panel(),
+        KeyCode::Char('R') => app.refresh_incident_panels().await,
+        KeyCode::Char(':') => app.enter_command_mode(),
         _ => {}
     }
     Ok(false)
 }
 
+/// Re-check the SSM Change Calendar when stale or after a profile/region switch
+pub async fn refresh_change_calendar_if_due(app: &mut App) {
+    if app.change_calendar_needs_refresh() {
+        app.refresh_change_calendar().await;
+    }
+}
+
+/// Probe the current profile's access in the background and collect results
+pub fn probe_capabilities(app: &mut App) {
+    app.start_capability_probe_if_needed();
+    app.poll_capability_probe();
+}
+
+/// Simulate the current view's action permissions when preflight is enabled
+pub async fn run_permission_preflight_if_needed(app: &mut App) {
+    if app.mode == Mode::Normal && app.permission_preflight_needed() {
+        app.run_permission_preflight().await;
+    }
+}
+
+/// Check watched jobs for completion when due
+pub async fn poll_jobs_if_due(app: &mut App) {
+    if app.jobs_need_poll() {
+        app.poll_jobs().await;
+    }
+}
+
+/// Report finished background actions (when no dialog is open)
+pub async fn poll_background_tasks(app: &mut App) {
+    if app.mode == Mode::Normal && !app.background_tasks.is_empty() {
+        app.poll_background_tasks().await;
+    }
+}
+
+/// Execute approved action requests when due
+pub async fn poll_approvals_if_due(app: &mut App) {
+    if app.mode == Mode::Normal && app.approvals_need_poll() {
+        app.poll_approvals().await;
+    }
+}
+
+/// Refresh the current view when change events arrive for its service
+pub async fn poll_live_refresh_if_due(app: &mut App) {
+    if app.mode == Mode::Normal && app.live_refresh_due() {
+        app.poll_live_refresh().await;
+    }
+}
+
+/// Retry cells of the current page that failed to load when due
+pub async fn retry_failed_cells_if_due(app: &mut App) {
+    if app.cell_retry_due() {
+        app.retry_failed_cells().await;
+    }
+}
+
+/// Refresh the incident dashboard panels when due
+pub async fn refresh_incident_if_due(app: &mut App) {
+    if app.incident_needs_refresh() {
+        app.refresh_incident_panels().await;
+    }
+}
+
 /// Poll for new log events if in log tail mode
 pub async fn poll_logs_if_tailing(app: &mut App) {
     if app.mode != Mode::LogTail {
@@ -0,0 +1,209 @@
+//! External pager and editor
+//!
+//! Long output (detail JSON, CloudFormation templates, log streams) can be read
+//! in the user's own pager, and detail JSON opened in their editor. The main
+//! loop suspends the TUI while the program runs on a temporary file. Long lists
+//! can also be handed to a fuzzy finder (fzf, skim) to pick a row.
+
+use crate::config::Config;
+use anyhow::{anyhow, Context, Result};
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+
+/// Which program a request runs
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExternalTool {
+    Pager,
+    Editor,
+}
+
+/// Content to open outside the TUI
+#[derive(Debug, Clone)]
+pub struct ExternalRequest {
+    pub tool: ExternalTool,
+    pub content: String,
+    /// Extension of the temporary file, so editors pick the right syntax
+    pub extension: &'static str,
+    /// Where changes are applied back (editor only)
+    pub edit: Option<EditTarget>,
+}
+
+/// The resource a detail document opened in the editor belongs to
+#[derive(Debug, Clone)]
+pub struct EditTarget {
+    pub resource_key: String,
+    pub resource_id: String,
+    /// ARN for tag changes, when the row carries one
+    pub arn: Option<String>,
+    /// The document as it was opened
+    pub original: Value,
+}
+
+/// Command line of a tool: the config setting, then the environment, then a default
+pub fn command_line(tool: ExternalTool, config: &Config) -> Vec<String> {
+    let (configured, env_vars, fallback): (_, &[&str], _) = match tool {
+        ExternalTool::Pager => (config.pager.as_deref(), &["PAGER"], "less -R"),
+        ExternalTool::Editor => (config.editor.as_deref(), &["VISUAL", "EDITOR"], "vi"),
+    };
+    let env = env_vars.iter().filter_map(|name| std::env::var(name).ok());
+    resolve_command(
+        configured.map(String::from).into_iter().chain(env),
+        fallback,
+    )
+}
+
+/// Command line of the fuzzy finder; there is no default, the picker is opt-in
+pub fn picker_command(config: &Config) -> Option<Vec<String>> {
+    let command = resolve_command(config.picker.clone().into_iter(), "");
+    (!command.is_empty()).then_some(command)
+}
+
+/// First non-empty candidate split into program and arguments ("code --wait")
+fn resolve_command(candidates: impl Iterator<Item = String>, fallback: &str) -> Vec<String> {
+    candidates
+        .map(|c| c.split_whitespace().map(String::from).collect::<Vec<_>>())
+        .find(|args| !args.is_empty())
+        .unwrap_or_else(|| fallback.split_whitespace().map(String::from).collect())
+}
+
+/// Write `content` to a private temporary file, run the command on it and
+/// return the file's content once the program exits
+pub fn run(command: &[String], content: &str, extension: &str) -> Result<String> {
+    let (program, args) = command
+        .split_first()
+        .ok_or_else(|| anyhow!("No pager or editor configured"))?;
+    let path = temp_path(extension);
+    write_private(&path, content)?;
+
+    let status = std::process::Command::new(program)
+        .args(args)
+        .arg(&path)
+        .status()
+        .with_context(|| format!("Failed to start {}", program));
+    let result = status.and_then(|status| {
+        if status.success() {
+            Ok(std::fs::read_to_string(&path)?)
+        } else {
+            Err(anyhow!(
+                "{} exited with code {}",
+                program,
+                status.code().unwrap_or(-1)
+            ))
+        }
+    });
+    let _ = std::fs::remove_file(&path);
+    result
+}
+
+/// Feed `lines` to the fuzzy finder on stdin and return the line it prints,
+/// or None when the user cancelled or nothing matched
+pub fn pick(command: &[String], lines: &[String]) -> Result<Option<String>> {
+    use std::io::Write;
+    use std::process::Stdio;
+
+    let (program, args) = command
+        .split_first()
+        .ok_or_else(|| anyhow!("No picker configured"))?;
+    let mut child = std::process::Command::new(program)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .with_context(|| format!("Failed to start {}", program))?;
+    if let Some(mut stdin) = child.stdin.take() {
+        // The picker may exit before reading everything (e.g., an early accept)
+        let _ = stdin.write_all(lines.join("\n").as_bytes());
+    }
+    let output = child.wait_with_output()?;
+    match output.status.code() {
+        Some(0) => Ok(String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .next()
+            .filter(|line| !line.is_empty())
+            .map(String::from)),
+        // fzf and skim exit with 1 when nothing matched and 130 when cancelled
+        Some(1) | Some(130) => Ok(None),
+        code => Err(anyhow!(
+            "{} exited with code {}",
+            program,
+            code.unwrap_or(-1)
+        )),
+    }
+}
+
+fn temp_path(extension: &str) -> PathBuf {
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.subsec_nanos())
+        .unwrap_or_default();
+    std::env::temp_dir().join(format!(
+        "taws-{}-{}.{}",
+        std::process::id(),
+        nanos,
+        extension
+    ))
+}
+
+/// Create the file readable by the current user only (it may hold secret values)
+fn write_private(path: &Path, content: &str) -> Result<()> {
+    use std::io::Write;
+
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let mut file = options
+        .open(path)
+        .with_context(|| format!("Failed to create {}", path.display()))?;
+    file.write_all(content.as_bytes())?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_command() {
+        let candidates = vec!["".to_string(), "code --wait".to_string()];
+        assert_eq!(
+            resolve_command(candidates.into_iter(), "vi"),
+            vec!["code", "--wait"]
+        );
+        assert_eq!(
+            resolve_command(std::iter::empty(), "less -R"),
+            vec!["less", "-R"]
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_run_returns_edited_file() {
+        let command = vec![
+            "sh".to_string(),
+            "-c".to_string(),
+            "printf edited > \"$0\"".to_string(),
+        ];
+        assert_eq!(run(&command, "original", "json").unwrap(), "edited");
+
+        let failing = vec!["false".to_string()];
+        assert!(run(&failing, "original", "json").is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_pick_returns_selected_line() {
+        let lines = vec!["i-0aaa\tweb".to_string(), "i-0bbb\tdb".to_string()];
+        let second = vec!["sh".to_string(), "-c".to_string(), "sed -n 2p".to_string()];
+        assert_eq!(
+            pick(&second, &lines).unwrap().as_deref(),
+            Some("i-0bbb\tdb")
+        );
+
+        let cancelled = vec!["sh".to_string(), "-c".to_string(), "exit 130".to_string()];
+        assert_eq!(pick(&cancelled, &lines).unwrap(), None);
+    }
+}
@@ -0,0 +1,295 @@
+//! Humanized display of sizes, durations and counts
+//!
+//! Columns with a `format` hold raw API numbers such as `TableSizeBytes` or a
+//! Lambda `Timeout`. With `formatting.humanize` on (the default) they are shown
+//! as "1.5 GiB", "15m" or "12,345", using the digit separators of the locale.
+//! With it off, the raw values are shown and the unit moves to the header.
+
+use crate::config::{FormattingConfig, SizeUnits};
+use serde::Deserialize;
+
+/// How a column's raw value is interpreted
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ValueFormat {
+    Bytes,
+    /// Sizes AWS reports in MiB (Lambda memory)
+    Mebibytes,
+    /// Sizes AWS reports in GiB (EBS volumes, RDS storage)
+    Gibibytes,
+    Count,
+    Milliseconds,
+    Seconds,
+    Hours,
+}
+
+impl ValueFormat {
+    /// Unit of the raw value, shown in the header when values aren't humanized
+    pub fn raw_unit(self) -> Option<&'static str> {
+        match self {
+            ValueFormat::Bytes => Some("bytes"),
+            ValueFormat::Mebibytes => Some("MiB"),
+            ValueFormat::Gibibytes => Some("GiB"),
+            ValueFormat::Count => None,
+            ValueFormat::Milliseconds => Some("ms"),
+            ValueFormat::Seconds => Some("s"),
+            ValueFormat::Hours => Some("h"),
+        }
+    }
+}
+
+/// Digit group and decimal separators of a locale
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Separators {
+    pub group: char,
+    pub decimal: char,
+}
+
+impl Separators {
+    /// Separators for a POSIX locale name such as "de_DE.UTF-8"
+    pub fn for_locale(locale: &str) -> Self {
+        let name = locale.split(['.', '@']).next().unwrap_or_default();
+        let mut parts = name.split(['_', '-']);
+        let language = parts.next().unwrap_or_default().to_lowercase();
+        let region = parts.next().unwrap_or_default().to_uppercase();
+
+        let (group, decimal) = match (language.as_str(), region.as_str()) {
+            ("de" | "it", "CH") => ('\'', '.'),
+            (
+                "de" | "es" | "it" | "nl" | "pt" | "id" | "tr" | "da" | "el" | "ro" | "hr" | "sl"
+                | "sr",
+                _,
+            ) => ('.', ','),
+            (
+                "fr" | "ru" | "pl" | "cs" | "sk" | "sv" | "fi" | "nb" | "nn" | "no" | "uk" | "hu"
+                | "bg" | "lt" | "lv" | "et",
+                _,
+            ) => (' ', ','),
+            _ => (',', '.'),
+        };
+        Self { group, decimal }
+    }
+}
+
+/// Formats column values according to the formatting config
+#[derive(Debug, Clone, Copy)]
+pub struct Formatter {
+    humanize: bool,
+    size_units: SizeUnits,
+    separators: Separators,
+}
+
+impl From<&FormattingConfig> for Formatter {
+    fn from(config: &FormattingConfig) -> Self {
+        let locale = config.locale.clone().or_else(|| {
+            ["LC_ALL", "LC_NUMERIC", "LANG"]
+                .iter()
+                .filter_map(|var| std::env::var(var).ok())
+                .find(|value| !value.is_empty())
+        });
+        Self {
+            humanize: config.humanize,
+            size_units: config.size_units,
+            separators: Separators::for_locale(locale.as_deref().unwrap_or("C")),
+        }
+    }
+}
+
+impl Formatter {
+    /// Column header, with the raw unit appended when values aren't humanized
+    pub fn header(&self, header: &str, format: Option<ValueFormat>) -> String {
+        match format.and_then(ValueFormat::raw_unit) {
+            Some(unit) if !self.humanize => format!("{} ({})", header, unit),
+            _ => header.to_string(),
+        }
+    }
+
+    /// Display a raw value (non-numeric values like "-" are left alone)
+    pub fn format(&self, value: &str, format: ValueFormat) -> String {
+        if !self.humanize {
+            return value.to_string();
+        }
+        let Ok(number) = value.trim().parse::<f64>() else {
+            return value.to_string();
+        };
+        match format {
+            ValueFormat::Bytes => self.size(number),
+            ValueFormat::Mebibytes => self.size(number * 1024.0 * 1024.0),
+            ValueFormat::Gibibytes => self.size(number * 1024.0 * 1024.0 * 1024.0),
+            ValueFormat::Count => self.count(number),
+            ValueFormat::Milliseconds => self.millis(number),
+            ValueFormat::Seconds => duration(number as u64),
+            ValueFormat::Hours => duration((number * 3600.0) as u64),
+        }
+    }
+
+    /// Humanized form of a formatted field in a JSON line (`"Size": 1024,`)
+    pub fn json_line_hint(&self, line: &str, fields: &[(&str, ValueFormat)]) -> Option<String> {
+        if !self.humanize {
+            return None;
+        }
+        let (key, value) = line.trim().split_once(':')?;
+        let key = key.trim().trim_matches('"');
+        let value = value.trim().trim_end_matches(',').trim_matches('"');
+        let format = fields.iter().find(|(field, _)| *field == key)?.1;
+        let formatted = self.format(value, format);
+        (formatted != value).then_some(formatted)
+    }
+
+    fn size(&self, bytes: f64) -> String {
+        let (base, units) = match self.size_units {
+            SizeUnits::Binary => (1024.0, ["B", "KiB", "MiB", "GiB", "TiB", "PiB"]),
+            SizeUnits::Decimal => (1000.0, ["B", "KB", "MB", "GB", "TB", "PB"]),
+        };
+        let mut value = bytes;
+        let mut unit = 0;
+        while value.abs() >= base && unit < units.len() - 1 {
+            value /= base;
+            unit += 1;
+        }
+        let decimals = if unit == 0 || value.fract() == 0.0 {
+            0
+        } else {
+            1
+        };
+        format!("{} {}", self.number(value, decimals), units[unit])
+    }
+
+    fn millis(&self, millis: f64) -> String {
+        if millis < 1000.0 {
+            format!("{}ms", self.number(millis, 0))
+        } else if millis < 60_000.0 && millis % 1000.0 != 0.0 {
+            format!("{}s", self.number(millis / 1000.0, 1))
+        } else {
+            duration((millis / 1000.0) as u64)
+        }
+    }
+
+    fn count(&self, count: f64) -> String {
+        let suffixes = [(1e12, "T"), (1e9, "B"), (1e6, "M")];
+        for (scale, suffix) in suffixes {
+            if count.abs() >= scale {
+                return format!("{}{}", self.number(count / scale, 1), suffix);
+            }
+        }
+        let decimals = if count.fract() == 0.0 { 0 } else { 2 };
+        self.number(count, decimals)
+    }
+
+    /// A number with locale separators and a fixed number of decimals
+    fn number(&self, value: f64, decimals: usize) -> String {
+        let rendered = format!("{:.*}", decimals, value.abs());
+        let (integer, fraction) = rendered.split_once('.').unwrap_or((&rendered, ""));
+
+        let mut out = String::new();
+        if value < 0.0 && rendered.chars().any(|c| c != '0' && c != '.') {
+            out.push('-');
+        }
+        for (i, digit) in integer.chars().enumerate() {
+            if i > 0 && (integer.len() - i) % 3 == 0 {
+                out.push(self.separators.group);
+            }
+            out.push(digit);
+        }
+        if !fraction.is_empty() {
+            out.push(self.separators.decimal);
+            out.push_str(fraction);
+        }
+        out
+    }
+}
+
+/// Compact duration with its two largest units ("1h 30m", "2d 4h", "45s")
+fn duration(seconds: u64) -> String {
+    let units = [(86400, "d"), (3600, "h"), (60, "m"), (1, "s")];
+    let Some(first) = units.iter().position(|(size, _)| seconds >= *size) else {
+        return "0s".to_string();
+    };
+    let (size, label) = units[first];
+    let mut out = format!("{}{}", seconds / size, label);
+    if let Some((next_size, next_label)) = units.get(first + 1) {
+        let rest = (seconds % size) / next_size;
+        if rest > 0 {
+            out.push_str(&format!(" {}{}", rest, next_label));
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn formatter(size_units: SizeUnits, locale: &str) -> Formatter {
+        Formatter::from(&FormattingConfig {
+            humanize: true,
+            size_units,
+            locale: Some(locale.to_string()),
+        })
+    }
+
+    #[test]
+    fn test_format_sizes() {
+        let binary = formatter(SizeUnits::Binary, "en_US.UTF-8");
+        assert_eq!(binary.format("512", ValueFormat::Bytes), "512 B");
+        assert_eq!(binary.format("1610612736", ValueFormat::Bytes), "1.5 GiB");
+        assert_eq!(binary.format("128", ValueFormat::Mebibytes), "128 MiB");
+        assert_eq!(binary.format("2048", ValueFormat::Gibibytes), "2 TiB");
+        assert_eq!(binary.format("-", ValueFormat::Bytes), "-");
+
+        let decimal = formatter(SizeUnits::Decimal, "de_DE.UTF-8");
+        assert_eq!(decimal.format("1610612736", ValueFormat::Bytes), "1,6 GB");
+        assert_eq!(decimal.format("8", ValueFormat::Gibibytes), "8,6 GB");
+    }
+
+    #[test]
+    fn test_format_counts_and_durations() {
+        let en = formatter(SizeUnits::Binary, "en_US");
+        assert_eq!(en.format("12345", ValueFormat::Count), "12,345");
+        assert_eq!(en.format("987", ValueFormat::Count), "987");
+        assert_eq!(en.format("4260000", ValueFormat::Count), "4.3M");
+        assert_eq!(
+            formatter(SizeUnits::Binary, "fr_FR").format("1234567.0", ValueFormat::Count),
+            "1,2M"
+        );
+        assert_eq!(
+            formatter(SizeUnits::Binary, "de_CH").format("12345", ValueFormat::Count),
+            "12'345"
+        );
+
+        assert_eq!(en.format("45", ValueFormat::Seconds), "45s");
+        assert_eq!(en.format("900", ValueFormat::Seconds), "15m");
+        assert_eq!(en.format("5400", ValueFormat::Seconds), "1h 30m");
+        assert_eq!(en.format("100", ValueFormat::Hours), "4d 4h");
+        assert_eq!(en.format("0", ValueFormat::Seconds), "0s");
+        assert_eq!(en.format("250", ValueFormat::Milliseconds), "250ms");
+        assert_eq!(en.format("1500", ValueFormat::Milliseconds), "1.5s");
+        assert_eq!(en.format("29000", ValueFormat::Milliseconds), "29s");
+    }
+
+    #[test]
+    fn test_raw_values() {
+        let raw = Formatter::from(&FormattingConfig {
+            humanize: false,
+            ..FormattingConfig::default()
+        });
+        assert_eq!(raw.format("1610612736", ValueFormat::Bytes), "1610612736");
+        assert_eq!(raw.header("SIZE", Some(ValueFormat::Bytes)), "SIZE (bytes)");
+        assert_eq!(raw.header("ITEMS", Some(ValueFormat::Count)), "ITEMS");
+        assert_eq!(
+            formatter(SizeUnits::Binary, "C").header("SIZE", Some(ValueFormat::Bytes)),
+            "SIZE"
+        );
+    }
+
+    #[test]
+    fn test_json_line_hint() {
+        let en = formatter(SizeUnits::Binary, "en_US");
+        let fields = [("TableSizeBytes", ValueFormat::Bytes)];
+        assert_eq!(
+            en.json_line_hint(r#"    "TableSizeBytes": 1073741824,"#, &fields),
+            Some("1 GiB".to_string())
+        );
+        assert_eq!(en.json_line_hint(r#"  "ItemCount": 5,"#, &fields), None);
+    }
+}
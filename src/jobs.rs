@@ -0,0 +1,241 @@
+//! Completion notifications for long-running jobs
+//!
+//! Actions with a `watch` definition (stack deletes, node group updates, ...)
+//! are tracked after they start. taws polls the resource's status and, when the
+//! job finishes or fails, POSTs a Slack-compatible JSON payload to the webhook
+//! configured under `notifications.webhook_url`.
+
+use crate::resource::{ResourceFilter, WatchDef};
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+/// Terminal statuses seen before the job was observed running are ignored for this
+/// long, since APIs may still report the previous operation's result
+const STALE_STATUS_GRACE: std::time::Duration = std::time::Duration::from_secs(60);
+
+/// How long a job may run before it is reported as timed out (unless its watch
+/// definition sets `timeout_minutes`)
+const DEFAULT_JOB_TIMEOUT_MINUTES: u64 = 360;
+
+/// What to follow once an action has run, taken from the view the action was
+/// chosen in (that view may be gone by then, e.g. after waiting for approval)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JobWatch {
+    /// Action display name (e.g., "Delete Stack")
+    pub action: String,
+    pub resource_key: String,
+    /// Filters of the view the action was chosen in (parent context)
+    pub filters: Vec<ResourceFilter>,
+    pub watch: WatchDef,
+}
+
+/// A job started from taws that is waiting to finish
+#[derive(Debug, Clone)]
+pub struct WatchedJob {
+    /// Action display name (e.g., "Delete Stack")
+    pub action: String,
+    pub resource_key: String,
+    /// Filters of the view the job was started from (parent context)
+    pub filters: Vec<ResourceFilter>,
+    pub resource_id: String,
+    pub resource_name: String,
+    pub watch: WatchDef,
+    pub profile: String,
+    pub region: String,
+    pub started_at: chrono::DateTime<chrono::Utc>,
+    pub started: std::time::Instant,
+    /// Whether a non-terminal status was observed
+    pub seen_running: bool,
+}
+
+/// An action that waits a long time (e.g., stopping an instance to resize it),
+/// run on its own task so taws stays usable until it reports back
+#[derive(Debug)]
+pub struct BackgroundTask {
+    /// What is running (e.g., "Change to Type · i-0abc|m5.large")
+    pub description: String,
+    pub resource_id: String,
+    /// Job to watch once the action succeeded
+    pub watch: Option<JobWatch>,
+    /// The action's result message, or its error
+    pub receiver: std::sync::mpsc::Receiver<std::result::Result<String, String>>,
+}
+
+/// Where a watched job stands after a poll
+#[derive(Debug, Clone, PartialEq)]
+pub enum JobProgress {
+    Running,
+    Succeeded(String),
+    Failed(String),
+    /// Still running when its deadline passed
+    TimedOut,
+}
+
+impl WatchedJob {
+    /// Check if the job ran past its deadline
+    pub fn timed_out(&self) -> bool {
+        let minutes = self
+            .watch
+            .timeout_minutes
+            .unwrap_or(DEFAULT_JOB_TIMEOUT_MINUTES);
+        self.started.elapsed() >= std::time::Duration::from_secs(minutes * 60)
+    }
+
+    /// Update the job from its current status (None = the resource is gone)
+    pub fn observe(&mut self, status: Option<&str>) -> JobProgress {
+        let Some(status) = status else {
+            return if self.watch.gone_succeeds {
+                JobProgress::Succeeded("gone".to_string())
+            } else {
+                JobProgress::Failed("gone".to_string())
+            };
+        };
+
+        let succeeded = self.watch.success.iter().any(|s| s == status);
+        let failed = self.watch.failure.iter().any(|s| s == status);
+        if !succeeded && !failed {
+            self.seen_running = true;
+            return JobProgress::Running;
+        }
+        if !self.seen_running && self.started.elapsed() < STALE_STATUS_GRACE {
+            return JobProgress::Running;
+        }
+        if succeeded {
+            JobProgress::Succeeded(status.to_string())
+        } else {
+            JobProgress::Failed(status.to_string())
+        }
+    }
+
+    /// Slack-compatible webhook payload (`text`) with the job details attached
+    pub fn notification_payload(&self, progress: &JobProgress) -> Value {
+        let (icon, outcome, final_status) = match progress {
+            JobProgress::Succeeded(status) => (":white_check_mark:", "succeeded", status),
+            JobProgress::Failed(status) => (":x:", "failed", status),
+            JobProgress::TimedOut => (":warning:", "timed out", &String::new()),
+            JobProgress::Running => (":hourglass:", "running", &String::new()),
+        };
+        let finished_at = chrono::Utc::now();
+        let duration = format_duration(finished_at - self.started_at);
+        json!({
+            "text": format!(
+                "{} {} {} for {} ({} / {}) after {}",
+                icon,
+                self.action,
+                outcome,
+                self.resource_name,
+                self.profile,
+                self.region,
+                duration
+            ),
+            "job": {
+                "action": self.action,
+                "resource_type": self.resource_key,
+                "resource_id": self.resource_id,
+                "resource_name": self.resource_name,
+                "outcome": outcome,
+                "status": final_status,
+                "profile": self.profile,
+                "region": self.region,
+                "started_at": self.started_at.to_rfc3339(),
+                "finished_at": finished_at.to_rfc3339(),
+            }
+        })
+    }
+}
+
+/// POST a payload to the notification webhook
+pub async fn send_webhook(url: &str, payload: &Value) -> Result<()> {
+    let client = crate::aws::tls::create_async_client()?;
+    let response = client.post(url).json(payload).send().await?;
+    if !response.status().is_success() {
+        return Err(anyhow!("Webhook returned {}", response.status()));
+    }
+    Ok(())
+}
+
+fn format_duration(duration: chrono::Duration) -> String {
+    let seconds = duration.num_seconds().max(0);
+    match seconds {
+        0..=59 => format!("{}s", seconds),
+        60..=3599 => format!("{}m {}s", seconds / 60, seconds % 60),
+        _ => format!("{}h {}m", seconds / 3600, (seconds % 3600) / 60),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn job(seen_running: bool) -> WatchedJob {
+        WatchedJob {
+            action: "Delete Stack".to_string(),
+            resource_key: "cloudformation-stacks".to_string(),
+            filters: Vec::new(),
+            resource_id: "web".to_string(),
+            resource_name: "web".to_string(),
+            watch: WatchDef {
+                status_field: "StackStatus".to_string(),
+                success: vec!["DELETE_COMPLETE".to_string()],
+                failure: vec!["DELETE_FAILED".to_string()],
+                in_progress: vec!["DELETE_IN_PROGRESS".to_string()],
+                gone_succeeds: true,
+                timeout_minutes: Some(30),
+            },
+            profile: "prod".to_string(),
+            region: "eu-west-1".to_string(),
+            started_at: chrono::Utc::now() - chrono::Duration::seconds(252),
+            started: std::time::Instant::now(),
+            seen_running,
+        }
+    }
+
+    #[test]
+    fn test_observe_job_status() {
+        let mut job = job(false);
+        // The previous operation's result doesn't end a job that just started
+        assert_eq!(job.observe(Some("DELETE_FAILED")), JobProgress::Running);
+        assert_eq!(
+            job.observe(Some("DELETE_IN_PROGRESS")),
+            JobProgress::Running
+        );
+        assert!(job.seen_running);
+        assert_eq!(
+            job.observe(Some("DELETE_FAILED")),
+            JobProgress::Failed("DELETE_FAILED".to_string())
+        );
+        assert_eq!(
+            job.observe(None),
+            JobProgress::Succeeded("gone".to_string())
+        );
+    }
+
+    #[test]
+    fn test_job_times_out() {
+        let mut job = job(true);
+        assert!(!job.timed_out());
+        job.watch.timeout_minutes = Some(0);
+        assert!(job.timed_out());
+        let payload = job.notification_payload(&JobProgress::TimedOut);
+        assert_eq!(payload["job"]["outcome"], "timed out");
+    }
+
+    #[test]
+    fn test_notification_payload() {
+        let job = job(true);
+        let payload = job.notification_payload(&JobProgress::Succeeded("gone".to_string()));
+        assert_eq!(
+            payload["text"],
+            ":white_check_mark: Delete Stack succeeded for web (prod / eu-west-1) after 4m 12s"
+        );
+        assert_eq!(payload["job"]["outcome"], "succeeded");
+        assert_eq!(payload["job"]["resource_type"], "cloudformation-stacks");
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(chrono::Duration::seconds(42)), "42s");
+        assert_eq!(format_duration(chrono::Duration::seconds(3725)), "1h 2m");
+    }
+}
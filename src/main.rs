@@ -1,8 +1,16 @@
 mod app;
+mod approval;
 mod aws;
+mod clipboard;
 mod completion;
 mod config;
 mod event;
+mod external;
+mod humanize;
+mod jobs;
+mod recording;
+mod redact;
+mod report;
 mod resource;
 mod ui;
 
@@ -16,9 +24,10 @@ pub const VERSION: &str = match option_env!("TAWS_VERSION") {
 use anyhow::Result;
 use app::{App, Mode, SsoLoginState};
 use aws::client::ClientResult;
+use aws::profiles::ProfileInfo;
 use clap::{CommandFactory, Parser, Subcommand, ValueEnum};
 use clap_complete::{generate, Shell};
-use config::Config;
+use config::{BorderStyle, Config, CredentialStore};
 use crossterm::{
     event::{poll, read, Event, KeyCode, KeyModifiers},
     execute,
@@ -56,6 +65,14 @@ struct Args {
     #[arg(long)]
     endpoint_url: Option<String>,
 
+    /// Record the session to an asciinema (asciicast v2) file
+    #[arg(long, value_name = "FILE")]
+    record: Option<PathBuf>,
+
+    /// Mask account IDs, ARNs, IP addresses and secrets in the recording (per the redaction config)
+    #[arg(long, requires = "record")]
+    redact: bool,
+
     #[command(subcommand)]
     command: Option<Command>,
 }
@@ -68,6 +85,23 @@ enum Command {
         #[arg(value_enum)]
         shell: Shell,
     },
+    /// Approve an action request written by another operator
+    Approve {
+        /// Action request file
+        file: PathBuf,
+    },
+    /// Render a Markdown or HTML report of the views listed in a template
+    Report {
+        /// Report template (YAML)
+        #[arg(long, value_name = "FILE")]
+        template: PathBuf,
+        /// Write the report to a file instead of stdout
+        #[arg(short, long, value_name = "FILE")]
+        output: Option<PathBuf>,
+        /// Output format (default: from the output file's extension, else markdown)
+        #[arg(long, value_enum)]
+        format: Option<report::ReportFormat>,
+    },
     /// List available AWS profiles (for shell completion)
     #[command(hide = true)]
     ListProfiles,
@@ -166,18 +200,34 @@ async fn main() -> Result<()> {
             }
             return Ok(());
         }
+        Some(Command::Approve { file }) => {
+            return approve_request(&args, file).await;
+        }
+        Some(Command::Report {
+            template,
+            output,
+            format,
+        }) => {
+            return generate_report(&args, template, output.as_deref(), *format).await;
+        }
         Some(Command::ListProfiles) => {
             // Output profiles for shell completion
             if let Ok(profiles) = aws::profiles::list_profiles() {
                 for profile in profiles {
-                    println!("{}", profile);
+                    println!("{}", profile.name);
                 }
             }
             return Ok(());
         }
         Some(Command::ListRegions) => {
-            // Output regions for shell completion
-            for region in aws::profiles::list_regions() {
+            // Output regions for shell completion (as last discovered for the profile)
+            let profile = args
+                .profile
+                .clone()
+                .unwrap_or_else(|| Config::load().effective_profile());
+            let regions =
+                aws::profiles::cached_regions(&profile).unwrap_or_else(aws::profiles::list_regions);
+            for region in regions {
                 println!("{}", region);
             }
             return Ok(());
@@ -200,8 +250,33 @@ async fn main() -> Result<()> {
 
     match result {
         Ok(Some(mut app)) => {
+            // Reopen the last context's view when the session starts in that context
+            if let Some(resource) = app.active_context().and_then(|c| c.resource.clone()) {
+                if resource != app.current_resource_key {
+                    let _ = app.navigate_to_resource(&resource).await;
+                }
+            }
+
+            // Start recording once the session is ready
+            let mut recorder = match &args.record {
+                Some(path) => {
+                    let size = terminal.size()?;
+                    let redact = args
+                        .redact
+                        .then(|| redact::RedactClasses::from(&app.config.redaction));
+                    match recording::Recorder::create(path, size.width, size.height, redact) {
+                        Ok(recorder) => Some(recorder),
+                        Err(e) => {
+                            cleanup_terminal(&mut terminal)?;
+                            return Err(e);
+                        }
+                    }
+                }
+                None => None,
+            };
+
             // Run the main app
-            let run_result = run_app(&mut terminal, &mut app).await;
+            let run_result = run_app(&mut terminal, &mut app, &mut recorder).await;
 
             // Restore terminal
             cleanup_terminal(&mut terminal)?;
@@ -224,6 +299,102 @@ async fn main() -> Result<()> {
     Ok(())
 }
 
+/// Review an action request and approve it with a proof of the current AWS identity
+async fn approve_request(args: &Args, file: &std::path::Path) -> Result<()> {
+    let config = Config::load();
+    let mut request = approval::ActionRequest::load(file)?;
+    request.verify(chrono::Utc::now())?;
+    if request.approval.is_some() {
+        anyhow::bail!("Request {} is already approved", request.id);
+    }
+
+    println!("Request:      {}", request.id);
+    println!("Action:       {}", request.description);
+    println!("Operation:    {}.{}", request.service, request.sdk_method);
+    println!("Resources:    {}", request.resource_ids.join(", "));
+    for field in request.form_fields.iter().flatten() {
+        println!("  {} = {}", field.name, field.value);
+    }
+    println!("Account:      {} / {}", request.profile, request.region);
+    println!("Requested by: {}", request.requested_by);
+    println!("Requested at: {}", request.requested_at);
+    println!("Expires at:   {}", request.expires_at);
+    print!("\nApprove this action? [y/N] ");
+    io::Write::flush(&mut io::stdout())?;
+
+    let mut answer = String::new();
+    io::stdin().read_line(&mut answer)?;
+    if !answer.trim().eq_ignore_ascii_case("y") {
+        println!("Not approved");
+        return Ok(());
+    }
+
+    // Prove the approver's own identity, not the requester's profile
+    let profile = args
+        .profile
+        .clone()
+        .unwrap_or_else(|| config.effective_profile());
+    let region = args
+        .region
+        .clone()
+        .unwrap_or_else(|| request.region.clone());
+    let endpoint_url = args
+        .endpoint_url
+        .clone()
+        .or_else(|| std::env::var("AWS_ENDPOINT_URL").ok());
+    let (clients, _) = aws::client::AwsClients::new(&profile, &region, endpoint_url).await?;
+    request
+        .approve(&clients, &config.approval.approvers, chrono::Utc::now())
+        .await?;
+    request.save(file)?;
+    let approver = request
+        .approval
+        .as_ref()
+        .map(|a| a.approved_by.as_str())
+        .unwrap_or_default();
+    println!(
+        "Approved as {}. The requesting session will execute it.",
+        approver
+    );
+    Ok(())
+}
+
+/// List the template's views headlessly and write the rendered report
+async fn generate_report(
+    args: &Args,
+    template: &std::path::Path,
+    output: Option<&std::path::Path>,
+    format: Option<report::ReportFormat>,
+) -> Result<()> {
+    let config = Config::load();
+    let template = report::ReportTemplate::load(template)?;
+    let format = format.unwrap_or_else(|| report::ReportFormat::for_output(output));
+
+    let profile = args
+        .profile
+        .clone()
+        .unwrap_or_else(|| config.effective_profile());
+    let region = args
+        .region
+        .clone()
+        .unwrap_or_else(|| config.effective_region());
+    let endpoint_url = args
+        .endpoint_url
+        .clone()
+        .or_else(|| std::env::var("AWS_ENDPOINT_URL").ok());
+    let (mut clients, _) = aws::client::AwsClients::new(&profile, &region, endpoint_url).await?;
+
+    let (rendered, failed) = report::run(&template, &mut clients, &config, &profile, format).await;
+    match output {
+        Some(path) => std::fs::write(path, rendered)?,
+        None => print!("{}", rendered),
+    }
+    if failed > 0 {
+        anyhow::bail!("{} report section(s) failed to load", failed);
+    }
+    Ok(())
+}
+
 fn cleanup_terminal<B: Backend + std::io::Write>(terminal: &mut Terminal<B>) -> Result<()>
 where
     B::Error: Send + Sync + 'static,
@@ -245,7 +416,7 @@ enum InitResult {
         region: String,
         endpoint_url: Option<String>,
         config: Config,
-        available_profiles: Vec<String>,
+        available_profiles: Vec<ProfileInfo>,
         available_regions: Vec<String>,
         readonly: bool,
     },
@@ -256,7 +427,7 @@ enum InitResult {
         region: String,
         endpoint_url: Option<String>,
         config: Config,
-        available_profiles: Vec<String>,
+        available_profiles: Vec<ProfileInfo>,
         available_regions: Vec<String>,
         readonly: bool,
     },
@@ -332,6 +503,10 @@ where
 {
     let mut splash = SplashState::new();
 
+    // Load configuration first so the splash already uses the configured borders
+    let config = Config::load();
+    ui::charset::set_ascii(config.border_style == BorderStyle::Ascii);
+
     // Render initial splash
     terminal.draw(|f| render_splash(f, &splash))?;
 
@@ -340,8 +515,9 @@ where
         return Ok(None);
     }
 
-    // Step 1: Load configuration (CLI args > env vars > saved config)
-    let config = Config::load();
+    // Step 1: Apply configuration (CLI args > env vars > saved config)
+    aws::keychain::set_enabled(config.credential_store == CredentialStore::Keychain);
+    aws::credentials::set_role_session_name_template(config.role_session_name.clone());
     let profile = args
         .profile
         .clone()
@@ -376,8 +552,12 @@ where
     splash.set_message("Reading ~/.aws/config");
     terminal.draw(|f| render_splash(f, &splash))?;
 
-    let available_profiles =
-        aws::profiles::list_profiles().unwrap_or_else(|_| vec!["default".to_string()]);
+    let available_profiles = aws::profiles::list_profiles().unwrap_or_else(|_| {
+        vec![ProfileInfo {
+            name: "default".to_string(),
+            ..ProfileInfo::default()
+        }]
+    });
     let available_regions = aws::profiles::list_regions();
     splash.complete_step();
 
@@ -443,6 +623,25 @@ where
         }
     };
 
+    // Offer only the regions enabled for the account (cached for a day,
+    // falls back to the built-in list)
+    let available_regions = match aws::profiles::cached_regions(&profile) {
+        Some(regions) => regions,
+        None => match resource::list_enabled_regions(&clients).await {
+            Ok(regions) if !regions.is_empty() => {
+                if let Err(e) = aws::profiles::save_cached_regions(&profile, &regions) {
+                    tracing::debug!("Failed to cache regions: {}", e);
+                }
+                regions
+            }
+            Ok(_) => available_regions,
+            Err(e) => {
+                tracing::debug!("Account regions unavailable, using built-in list: {}", e);
+                available_regions
+            }
+        },
+    };
+
     splash.complete_step();
 
     if check_abort()? {
@@ -501,7 +700,7 @@ async fn handle_sso_login_flow<B: Backend>(
     region: String,
     endpoint_url: Option<String>,
     config: Config,
-    available_profiles: Vec<String>,
+    available_profiles: Vec<ProfileInfo>,
     available_regions: Vec<String>,
     readonly: bool,
 ) -> Result<Option<App>>
@@ -526,6 +725,7 @@ where
         // Render SSO dialog
         terminal.draw(|f| {
             render_sso_standalone(f, &sso_state);
+            ui::charset::apply(f.buffer_mut());
         })?;
 
         // Handle input
@@ -962,7 +1162,7 @@ async fn handle_console_login_flow<B: Backend>(
     region: String,
     endpoint_url: Option<String>,
     config: Config,
-    available_profiles: Vec<String>,
+    available_profiles: Vec<ProfileInfo>,
     available_regions: Vec<String>,
     readonly: bool,
 ) -> Result<Option<App>>
@@ -989,6 +1189,7 @@ where
         // Render console login dialog
         terminal.draw(|f| {
             render_console_login_standalone(f, &console_state);
+            ui::charset::apply(f.buffer_mut());
         })?;
 
         // Poll child process status if waiting
@@ -1407,15 +1608,78 @@ fn check_abort() -> Result<bool> {
     Ok(false)
 }
 
-async fn run_app<B: Backend>(terminal: &mut Terminal<B>, app: &mut App) -> Result<()>
+/// How often a fetch in flight is redrawn (spinner, items loaded so far)
+const FETCH_REDRAW_INTERVAL: Duration = Duration::from_millis(120);
+
+/// Render the UI into an off-screen buffer of the given size
+fn render_offscreen(app: &App, area: ratatui::layout::Rect) -> Result<ratatui::buffer::Buffer> {
+    let backend = ratatui::backend::TestBackend::new(area.width, area.height);
+    let mut terminal = Terminal::new(backend)?;
+    let frame = terminal.draw(|f| ui::render(f, app))?;
+    Ok(frame.buffer.clone())
+}
+
+async fn run_app<B: Backend>(
+    terminal: &mut Terminal<B>,
+    app: &mut App,
+    recorder: &mut Option<recording::Recorder>,
+) -> Result<()>
 where
     B::Error: Send + Sync + 'static,
 {
     loop {
-        terminal.draw(|f| ui::render(f, app))?;
+        let frame = terminal.draw(|f| ui::render(f, app))?;
+        let last_frame = frame.buffer.clone();
+        if let Some(active) = recorder {
+            // A redacted recording of an unredacted screen is rendered again
+            // with redaction on, so views mask values before wrapping them
+            let recorded = if active.redacts() && !app.redacted {
+                app.redacted = true;
+                let buffer = render_offscreen(app, last_frame.area);
+                app.redacted = false;
+                buffer
+            } else {
+                Ok(last_frame.clone())
+            };
+            if let Err(e) = recorded.and_then(|buffer| active.record(&buffer)) {
+                // A failed recording shouldn't end the session
+                tracing::warn!("Stopped recording: {}", e);
+                app.show_warning(&format!("Session recording stopped: {}", e));
+                *recorder = None;
+            }
+        }
 
-        // Handle user input
-        if event::handle_events(app).await? {
+        // Handle user input, redrawing any fetch it starts until it returns
+        let fetch_progress = app.fetch_progress.clone();
+        // The overlay can show resource names, so it's masked like the frame under it
+        let redact_classes = app
+            .redacted
+            .then(|| redact::RedactClasses::from(&app.config.redaction));
+        let quit = {
+            let events = event::handle_events(app);
+            tokio::pin!(events);
+            loop {
+                tokio::select! {
+                    quit = &mut events => break quit?,
+                    _ = tokio::time::sleep(FETCH_REDRAW_INTERVAL) => {
+                        let progress = fetch_progress.lock().ok().and_then(|slot| slot.clone());
+                        if let Some(progress) = progress {
+                            terminal.draw(|f| {
+                                if last_frame.area == f.area() {
+                                    f.buffer_mut().merge(&last_frame);
+                                }
+                                ui::loading::render(f, &progress, redact_classes);
+                                if let Some(classes) = redact_classes {
+                                    redact::redact_buffer(f.buffer_mut(), classes);
+                                }
+                                ui::charset::apply(f.buffer_mut());
+                            })?;
+                        }
+                    }
+                }
+            }
+        };
+        if quit {
             return Ok(());
         }
 
@@ -1424,6 +1688,32 @@ where
             execute_ssm_connect(terminal, &request)?;
         }
 
+        // Open content in the external pager or editor (also suspends the TUI)
+        if let Some(request) = app.take_external_request() {
+            let command = external::command_line(request.tool, &app.config);
+            match run_suspended(terminal, || {
+                external::run(&command, &request.content, request.extension)
+            })? {
+                Ok(edited) => {
+                    if let Some(target) = request.edit {
+                        app.apply_edited_document(target, &edited).await;
+                    }
+                }
+                Err(e) => app.error_message = Some(e.to_string()),
+            }
+        }
+
+        // Pick a row with the external fuzzy finder (also suspends the TUI)
+        if let Some(lines) = app.take_picker_request() {
+            if let Some(command) = external::picker_command(&app.config) {
+                match run_suspended(terminal, || external::pick(&command, &lines))? {
+                    Ok(Some(line)) => app.select_picked_row(&line),
+                    Ok(None) => {}
+                    Err(e) => app.error_message = Some(e.to_string()),
+                }
+            }
+        }
+
         // Poll SSO if in waiting state
         if app.mode == Mode::SsoLogin {
             event::poll_sso_if_waiting(app).await;
@@ -1439,6 +1729,35 @@ where
             event::poll_logs_if_tailing(app).await;
         }
 
+        // Report finished background jobs to the notification webhook
+        event::poll_jobs_if_due(app).await;
+
+        // Report long-running actions that finished in the background
+        event::poll_background_tasks(app).await;
+
+        // Run action requests once a second operator approved them
+        event::poll_approvals_if_due(app).await;
+
+        // Keep the change freeze indicator current
+        event::refresh_change_calendar_if_due(app).await;
+
+        // Grey out actions the current identity isn't allowed to perform
+        event::run_permission_preflight_if_needed(app).await;
+
+        // Grey out resource types whose service the identity can't access
+        event::probe_capabilities(app);
+
+        // Refresh the current view when its resources change
+        event::poll_live_refresh_if_due(app).await;
+
+        // Fill in cells whose lookups failed (e.g., throttled) once things calm down
+        event::retry_failed_cells_if_due(app).await;
+
+        // Refresh pinned panels while the incident dashboard is open
+        if app.mode == Mode::Incident {
+            event::refresh_incident_if_due(app).await;
+        }
+
         // Auto-refresh every 5 seconds (only in Normal mode)
         if app.needs_refresh() {
             let _ = app.refresh_current().await;
@@ -1446,6 +1765,37 @@ where
     }
 }
 
+/// Run an external program (pager, editor, fuzzy finder) with the TUI
+/// suspended; the inner result is the program's outcome
+fn run_suspended<B: Backend, T>(
+    terminal: &mut Terminal<B>,
+    program: impl FnOnce() -> Result<T>,
+) -> Result<Result<T>>
+where
+    B::Error: Send + Sync + 'static,
+{
+    // Suspend TUI - the program takes over the terminal
+    crossterm::terminal::disable_raw_mode()?;
+    crossterm::execute!(
+        std::io::stdout(),
+        crossterm::terminal::LeaveAlternateScreen,
+        crossterm::cursor::Show
+    )?;
+
+    let outcome = program();
+
+    // Restore TUI
+    crossterm::terminal::enable_raw_mode()?;
+    crossterm::execute!(
+        std::io::stdout(),
+        crossterm::terminal::EnterAlternateScreen,
+        crossterm::cursor::Hide
+    )?;
+    terminal.clear()?;
+
+    Ok(outcome)
+}
+
 /// Execute SSM connect by suspending TUI and running aws ssm start-session
 fn execute_ssm_connect<B: Backend>(
     terminal: &mut Terminal<B>,
@@ -0,0 +1,182 @@
+//! Session recording in asciinema (asciicast v2) format
+//!
+//! `taws --record session.cast` writes every rendered frame as an output event,
+//! so the session can be replayed with `asciinema play` or embedded with the
+//! asciinema player. With `--redact`, the value classes enabled in the
+//! `redaction` config are masked in the recording (the live screen is unchanged).
+
+use crate::redact::{redact_buffer, RedactClasses};
+use anyhow::Result;
+use ratatui::buffer::Buffer;
+use ratatui::style::{Color, Modifier};
+use serde_json::json;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+/// Writes rendered frames to an asciicast v2 file
+pub struct Recorder {
+    file: BufWriter<File>,
+    started: std::time::Instant,
+    redact: Option<RedactClasses>,
+    /// Size and content of the last recorded frame (unchanged frames are skipped)
+    size: (u16, u16),
+    last_frame: String,
+}
+
+impl Recorder {
+    /// Create the recording file and write the asciicast header
+    pub fn create(
+        path: &Path,
+        width: u16,
+        height: u16,
+        redact: Option<RedactClasses>,
+    ) -> Result<Self> {
+        let mut file = BufWriter::new(File::create(path).map_err(|e| {
+            anyhow::anyhow!("Failed to create recording {}: {}", path.display(), e)
+        })?);
+        let header = json!({
+            "version": 2,
+            "width": width,
+            "height": height,
+            "timestamp": chrono::Utc::now().timestamp(),
+            "title": format!("taws {}", crate::VERSION),
+            "env": {
+                "TERM": std::env::var("TERM").unwrap_or_default(),
+                "SHELL": std::env::var("SHELL").unwrap_or_default(),
+            },
+        });
+        writeln!(file, "{}", header)?;
+        file.flush()?;
+        Ok(Self {
+            file,
+            started: std::time::Instant::now(),
+            redact,
+            size: (width, height),
+            last_frame: String::new(),
+        })
+    }
+
+    /// Whether value classes are masked in the recording
+    pub fn redacts(&self) -> bool {
+        self.redact.is_some()
+    }
+
+    /// Record a rendered frame if it differs from the previous one
+    pub fn record(&mut self, buffer: &Buffer) -> Result<()> {
+        let elapsed = self.started.elapsed().as_secs_f64();
+        let size = (buffer.area.width, buffer.area.height);
+        if size != self.size {
+            self.size = size;
+            let event = json!([elapsed, "r", format!("{}x{}", size.0, size.1)]);
+            writeln!(self.file, "{}", event)?;
+        }
+
+        let frame = match self.redact {
+            Some(classes) => {
+                let mut redacted = buffer.clone();
+                redact_buffer(&mut redacted, classes);
+                frame_to_ansi(&redacted)
+            }
+            None => frame_to_ansi(buffer),
+        };
+        if frame == self.last_frame {
+            return Ok(());
+        }
+        writeln!(self.file, "{}", json!([elapsed, "o", frame]))?;
+        self.file.flush()?;
+        self.last_frame = frame;
+        Ok(())
+    }
+}
+
+/// Full-screen ANSI rendering of a frame buffer
+fn frame_to_ansi(buffer: &Buffer) -> String {
+    let width = buffer.area.width as usize;
+    let mut out = String::from("\x1b[H");
+    for (row_index, row) in buffer.content().chunks(width.max(1)).enumerate() {
+        if row_index > 0 {
+            out.push_str("\r\n");
+        }
+
+        let mut current = None;
+        for cell in row {
+            if cell.skip {
+                continue;
+            }
+            let style = (cell.fg, cell.bg, cell.modifier);
+            if current != Some(style) {
+                out.push_str(&sgr(cell.fg, cell.bg, cell.modifier));
+                current = Some(style);
+            }
+            out.push_str(cell.symbol());
+        }
+        out.push_str("\x1b[0m");
+    }
+    out
+}
+
+/// SGR escape sequence selecting a cell style
+fn sgr(fg: Color, bg: Color, modifier: Modifier) -> String {
+    let mut codes = vec!["0".to_string()];
+    for (flag, code) in [
+        (Modifier::BOLD, "1"),
+        (Modifier::DIM, "2"),
+        (Modifier::ITALIC, "3"),
+        (Modifier::UNDERLINED, "4"),
+        (Modifier::REVERSED, "7"),
+        (Modifier::CROSSED_OUT, "9"),
+    ] {
+        if modifier.contains(flag) {
+            codes.push(code.to_string());
+        }
+    }
+    codes.extend(color_code(fg, false));
+    codes.extend(color_code(bg, true));
+    format!("\x1b[{}m", codes.join(";"))
+}
+
+/// SGR parameters for a foreground or background color
+fn color_code(color: Color, background: bool) -> Option<String> {
+    let offset = if background { 10 } else { 0 };
+    let base = match color {
+        Color::Reset => return None,
+        Color::Black => 30,
+        Color::Red => 31,
+        Color::Green => 32,
+        Color::Yellow => 33,
+        Color::Blue => 34,
+        Color::Magenta => 35,
+        Color::Cyan => 36,
+        Color::Gray => 37,
+        Color::DarkGray => 90,
+        Color::LightRed => 91,
+        Color::LightGreen => 92,
+        Color::LightYellow => 93,
+        Color::LightBlue => 94,
+        Color::LightMagenta => 95,
+        Color::LightCyan => 96,
+        Color::White => 97,
+        Color::Indexed(i) => return Some(format!("{};5;{}", 38 + offset, i)),
+        Color::Rgb(r, g, b) => return Some(format!("{};2;{};{};{}", 38 + offset, r, g, b)),
+    };
+    Some((base + offset).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::layout::Rect;
+    use ratatui::style::Style;
+
+    #[test]
+    fn test_frame_to_ansi() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 2));
+        buffer.set_string(0, 0, "ab", Style::default().fg(Color::Red));
+        buffer.set_string(0, 1, "cd", Style::default());
+        assert_eq!(
+            frame_to_ansi(&buffer),
+            "\x1b[H\x1b[0;31mab\x1b[0m  \x1b[0m\r\n\x1b[0mcd  \x1b[0m"
+        );
+    }
+}
@@ -0,0 +1,566 @@
+//! Headless reports
+//!
+//! `taws report --template <file>` lists the views a template names with the
+//! same resource definitions and fetch code as the TUI, and renders them as a
+//! Markdown or HTML document, e.g. for a weekly audit run from cron. Reports
+//! only make list calls, so they never change anything.
+
+use crate::app::AwsFilters;
+use crate::aws::client::AwsClients;
+use crate::config::Config;
+use crate::humanize::Formatter;
+use crate::resource::{
+    cell_error, extract_json_value, fetch_resources_paginated, get_resource, ColumnDef,
+    ResourceFilter,
+};
+use anyhow::{anyhow, bail, Context, Result};
+use clap::ValueEnum;
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// A report template (YAML)
+#[derive(Debug, Deserialize)]
+pub struct ReportTemplate {
+    pub title: String,
+    pub sections: Vec<ReportSection>,
+}
+
+/// One table of the report: a view, optionally filtered, in one or more regions
+#[derive(Debug, Deserialize)]
+pub struct ReportSection {
+    /// Heading (default: the resource's display name)
+    #[serde(default)]
+    pub title: Option<String>,
+
+    /// Resource type as in the command palette, e.g. "ec2-instances"
+    pub resource: String,
+
+    /// Server-side filters as typed in the TUI, e.g. "state=running, tag:Env=prod"
+    #[serde(default)]
+    pub filters: Option<String>,
+
+    /// Further list parameters, e.g. the parent of a sub-resource (`file_system_id: fs-0123`)
+    #[serde(default)]
+    pub params: BTreeMap<String, String>,
+
+    /// Regions to list (default: the report's region)
+    #[serde(default)]
+    pub regions: Vec<String>,
+
+    /// Column headers to include (default: all of the view's columns)
+    #[serde(default)]
+    pub columns: Vec<String>,
+
+    /// Only rows with a cell containing this text (case-insensitive)
+    #[serde(default, rename = "match")]
+    pub match_text: Option<String>,
+
+    /// Most rows per region (default: `pagination.fetch_all_limit`)
+    #[serde(default)]
+    pub limit: Option<usize>,
+}
+
+/// Output format of a report
+#[derive(Debug, Clone, Copy, PartialEq, ValueEnum)]
+pub enum ReportFormat {
+    Markdown,
+    Html,
+}
+
+impl ReportFormat {
+    /// Format implied by the output file's extension (Markdown unless .html/.htm)
+    pub fn for_output(path: Option<&Path>) -> Self {
+        match path
+            .and_then(|p| p.extension())
+            .and_then(|e| e.to_str())
+            .map(|e| e.to_lowercase())
+            .as_deref()
+        {
+            Some("html" | "htm") => ReportFormat::Html,
+            _ => ReportFormat::Markdown,
+        }
+    }
+}
+
+/// A listed section, ready to render
+#[derive(Debug, Default)]
+struct SectionTable {
+    title: String,
+    headers: Vec<String>,
+    rows: Vec<Vec<String>>,
+    /// More rows existed than the section's limit
+    truncated: bool,
+    error: Option<String>,
+}
+
+/// A report with its sections listed
+#[derive(Debug)]
+struct Report {
+    title: String,
+    generated: String,
+    profile: String,
+    region: String,
+    sections: Vec<SectionTable>,
+}
+
+impl ReportTemplate {
+    pub fn load(path: &Path) -> Result<Self> {
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let template: ReportTemplate = serde_yaml::from_str(&contents)
+            .with_context(|| format!("Invalid report template {}", path.display()))?;
+        template.validate()?;
+        Ok(template)
+    }
+
+    /// Catch mistakes before any AWS call is made
+    fn validate(&self) -> Result<()> {
+        if self.sections.is_empty() {
+            bail!("The report template has no sections");
+        }
+        for section in &self.sections {
+            let resource = get_resource(&section.resource)
+                .ok_or_else(|| anyhow!("Unknown resource: {}", section.resource))?;
+            if resource.requires_parent && section.params.is_empty() {
+                bail!(
+                    "{} needs its parent in `params` (e.g., the sub-resource's filter parameter)",
+                    section.resource
+                );
+            }
+            if let Some(filters) = &section.filters {
+                if parse_filters(filters).is_none() {
+                    bail!("Invalid filters for {}: {}", section.resource, filters);
+                }
+            }
+            section_columns(section, &resource.columns)?;
+        }
+        Ok(())
+    }
+}
+
+/// Parse "key=value, ..." the way the TUI parses "Filters: key=value, ..."
+fn parse_filters(text: &str) -> Option<AwsFilters> {
+    AwsFilters::parse(&format!("Filters: {}", text))
+}
+
+/// The section's columns, in the order the template lists them
+fn section_columns<'a>(
+    section: &ReportSection,
+    columns: &'a [ColumnDef],
+) -> Result<Vec<&'a ColumnDef>> {
+    if section.columns.is_empty() {
+        return Ok(columns.iter().collect());
+    }
+    section
+        .columns
+        .iter()
+        .map(|header| {
+            columns
+                .iter()
+                .find(|col| col.header.eq_ignore_ascii_case(header))
+                .ok_or_else(|| anyhow!("{} has no column {}", section.resource, header))
+        })
+        .collect()
+}
+
+/// List every section of the template and render the report; sections that
+/// fail are shown with their error and make the whole run fail afterwards
+pub async fn run(
+    template: &ReportTemplate,
+    clients: &mut AwsClients,
+    config: &Config,
+    profile: &str,
+    format: ReportFormat,
+) -> (String, usize) {
+    let formatter = Formatter::from(&config.formatting);
+    let default_region = clients.region.clone();
+    let mut current_region = default_region.clone();
+    let mut sections = Vec::new();
+
+    for section in &template.sections {
+        let Some(resource) = get_resource(&section.resource) else {
+            continue;
+        };
+        let regions = if section.regions.is_empty() {
+            vec![default_region.clone()]
+        } else {
+            section.regions.clone()
+        };
+        let title = section
+            .title
+            .clone()
+            .unwrap_or_else(|| resource.display_name.clone());
+
+        for region in &regions {
+            let mut table = SectionTable {
+                title: if regions.len() > 1 || *region != default_region {
+                    format!("{} ({})", title, region)
+                } else {
+                    title.clone()
+                },
+                ..SectionTable::default()
+            };
+            if *region != current_region {
+                match clients.switch_region(profile, region).await {
+                    Ok(actual) => current_region = actual,
+                    Err(e) => {
+                        table.error = Some(format!("Failed to switch to {}: {}", region, e));
+                        sections.push(table);
+                        continue;
+                    }
+                }
+            }
+            let columns = section_columns(section, &resource.columns).unwrap_or_default();
+            let mut rows = SectionRows {
+                columns: &columns,
+                formatter: &formatter,
+                match_text: section.match_text.as_deref(),
+                limit: section.limit.unwrap_or(config.pagination.fetch_all_limit),
+                rows: Vec::new(),
+            };
+            match fetch_rows(clients, section, &mut rows).await {
+                Ok(truncated) => {
+                    table.headers = columns
+                        .iter()
+                        .map(|col| formatter.header(&col.header, col.format))
+                        .collect();
+                    table.rows = rows.rows;
+                    table.truncated = truncated;
+                }
+                Err(e) => table.error = Some(crate::aws::client::format_aws_error(&e)),
+            }
+            sections.push(table);
+        }
+    }
+
+    let failed = sections.iter().filter(|s| s.error.is_some()).count();
+    let report = Report {
+        title: template.title.clone(),
+        generated: chrono::Utc::now().format("%Y-%m-%d %H:%M UTC").to_string(),
+        profile: profile.to_string(),
+        region: default_region,
+        sections,
+    };
+    let rendered = match format {
+        ReportFormat::Markdown => render_markdown(&report),
+        ReportFormat::Html => render_html(&report),
+    };
+    (rendered, failed)
+}
+
+/// Rows of a section collected while its view is paged
+struct SectionRows<'a> {
+    columns: &'a [&'a ColumnDef],
+    formatter: &'a Formatter,
+    match_text: Option<&'a str>,
+    /// Most matching rows to keep
+    limit: usize,
+    rows: Vec<Vec<String>>,
+}
+
+impl SectionRows<'_> {
+    /// Add a page's matching rows; true once a matching row had to be left
+    /// out because the limit was reached
+    fn add_page(&mut self, items: &[Value]) -> bool {
+        for item in items {
+            let cells = row_cells(item, self.columns, self.formatter);
+            if !row_matches(&cells, self.match_text) {
+                continue;
+            }
+            if self.rows.len() >= self.limit {
+                return true;
+            }
+            self.rows.push(cells);
+        }
+        false
+    }
+
+    fn is_full(&self) -> bool {
+        self.rows.len() >= self.limit
+    }
+}
+
+/// Page through a section's view until `limit` matching rows are collected;
+/// true when rows were (or may have been) left out
+async fn fetch_rows(
+    clients: &AwsClients,
+    section: &ReportSection,
+    rows: &mut SectionRows<'_>,
+) -> Result<bool> {
+    let mut filters: Vec<ResourceFilter> = section
+        .params
+        .iter()
+        .map(|(name, value)| ResourceFilter::new(name, vec![value.clone()]))
+        .collect();
+    if let Some(aws_filters) = section.filters.as_deref().and_then(parse_filters) {
+        filters.extend(aws_filters.to_resource_filters());
+    }
+
+    let mut token: Option<String> = None;
+    loop {
+        let page =
+            fetch_resources_paginated(&section.resource, clients, &filters, token.as_deref())
+                .await?;
+        if rows.add_page(&page.items) {
+            return Ok(true);
+        }
+        match page.next_token {
+            // More pages may hold further matches
+            Some(_) if rows.is_full() => return Ok(true),
+            Some(next) => token = Some(next),
+            None => return Ok(false),
+        }
+    }
+}
+
+/// Cell texts of a row as the TUI shows them (failed lookups read "error")
+fn row_cells(item: &Value, columns: &[&ColumnDef], formatter: &Formatter) -> Vec<String> {
+    columns
+        .iter()
+        .map(|col| {
+            if cell_error(item, &col.json_path).is_some() {
+                return "error".to_string();
+            }
+            let value = extract_json_value(item, &col.json_path);
+            match col.format {
+                Some(format) => formatter.format(&value, format),
+                None => value,
+            }
+        })
+        .collect()
+}
+
+fn row_matches(cells: &[String], match_text: Option<&str>) -> bool {
+    match match_text {
+        Some(text) => {
+            let text = text.to_lowercase();
+            cells.iter().any(|cell| cell.to_lowercase().contains(&text))
+        }
+        None => true,
+    }
+}
+
+/// Footer line of a section: its row count, the limit or its error
+fn section_note(section: &SectionTable) -> String {
+    if let Some(error) = &section.error {
+        return format!("Error: {}", error);
+    }
+    match (section.rows.len(), section.truncated) {
+        (0, _) => "No rows".to_string(),
+        (count, true) => format!("First {} rows (limit reached)", count),
+        (1, false) => "1 row".to_string(),
+        (count, false) => format!("{} rows", count),
+    }
+}
+
+fn markdown_cell(text: &str) -> String {
+    text.replace('|', "\\|").replace(['\n', '\r'], " ")
+}
+
+fn render_markdown(report: &Report) -> String {
+    let mut out = format!(
+        "# {}\n\nGenerated {} · profile `{}` · region `{}`\n",
+        report.title, report.generated, report.profile, report.region
+    );
+    for section in &report.sections {
+        out.push_str(&format!("\n## {}\n\n", section.title));
+        if section.error.is_none() && !section.rows.is_empty() {
+            let headers: Vec<String> = section.headers.iter().map(|h| markdown_cell(h)).collect();
+            out.push_str(&format!("| {} |\n", headers.join(" | ")));
+            out.push_str(&format!("|{}\n", " --- |".repeat(headers.len())));
+            for row in &section.rows {
+                let cells: Vec<String> = row.iter().map(|c| markdown_cell(c)).collect();
+                out.push_str(&format!("| {} |\n", cells.join(" | ")));
+            }
+            out.push('\n');
+        }
+        out.push_str(&format!("_{}_\n", section_note(section)));
+    }
+    out
+}
+
+fn html_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn render_html(report: &Report) -> String {
+    let mut out = format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{title}</title>\n\
+         <style>\n\
+         body {{ font-family: sans-serif; margin: 2em; }}\n\
+         table {{ border-collapse: collapse; font-size: 0.9em; }}\n\
+         th, td {{ border: 1px solid #ccc; padding: 4px 8px; text-align: left; }}\n\
+         th {{ background: #f0f0f0; }}\n\
+         .note {{ color: #666; }}\n\
+         .error {{ color: #c00; }}\n\
+         </style>\n</head>\n<body>\n<h1>{title}</h1>\n\
+         <p class=\"note\">Generated {generated} · profile <code>{profile}</code> · region <code>{region}</code></p>\n",
+        title = html_escape(&report.title),
+        generated = html_escape(&report.generated),
+        profile = html_escape(&report.profile),
+        region = html_escape(&report.region),
+    );
+    for section in &report.sections {
+        out.push_str(&format!("<h2>{}</h2>\n", html_escape(&section.title)));
+        if section.error.is_none() && !section.rows.is_empty() {
+            out.push_str("<table>\n<tr>");
+            for header in &section.headers {
+                out.push_str(&format!("<th>{}</th>", html_escape(header)));
+            }
+            out.push_str("</tr>\n");
+            for row in &section.rows {
+                out.push_str("<tr>");
+                for cell in row {
+                    out.push_str(&format!("<td>{}</td>", html_escape(cell)));
+                }
+                out.push_str("</tr>\n");
+            }
+            out.push_str("</table>\n");
+        }
+        let class = if section.error.is_some() {
+            "error"
+        } else {
+            "note"
+        };
+        out.push_str(&format!(
+            "<p class=\"{}\">{}</p>\n",
+            class,
+            html_escape(&section_note(section))
+        ));
+    }
+    out.push_str("</body>\n</html>\n");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_report() -> Report {
+        Report {
+            title: "Weekly audit".to_string(),
+            generated: "2026-10-18 06:00 UTC".to_string(),
+            profile: "prod".to_string(),
+            region: "eu-west-1".to_string(),
+            sections: vec![
+                SectionTable {
+                    title: "Open security groups".to_string(),
+                    headers: vec!["GROUP".to_string(), "RULE".to_string()],
+                    rows: vec![vec![
+                        "sg-0abc".to_string(),
+                        "tcp|22 <0.0.0.0/0>".to_string(),
+                    ]],
+                    truncated: false,
+                    error: None,
+                },
+                SectionTable {
+                    title: "Quotas (us-east-1)".to_string(),
+                    error: Some("AccessDenied".to_string()),
+                    ..SectionTable::default()
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_template_validation() {
+        let template: ReportTemplate = serde_yaml::from_str(
+            "title: Audit\nsections:\n  - resource: ec2-instances\n    filters: state=running\n    columns: [\"instance id\", state]\n",
+        )
+        .unwrap();
+        assert!(template.validate().is_ok());
+
+        let unknown_column: ReportTemplate = serde_yaml::from_str(
+            "title: Audit\nsections:\n  - resource: ec2-instances\n    columns: [NOPE]\n",
+        )
+        .unwrap();
+        assert!(unknown_column.validate().is_err());
+
+        let missing_parent: ReportTemplate =
+            serde_yaml::from_str("title: Audit\nsections:\n  - resource: efs-mount-targets\n")
+                .unwrap();
+        assert!(missing_parent.validate().is_err());
+    }
+
+    #[test]
+    fn test_section_rows_match_before_limit() {
+        let resource = get_resource("ec2-instances").unwrap();
+        let columns = section_columns(
+            &ReportSection {
+                title: None,
+                resource: "ec2-instances".to_string(),
+                filters: None,
+                params: BTreeMap::new(),
+                regions: Vec::new(),
+                columns: vec!["NAME".to_string()],
+                match_text: None,
+                limit: None,
+            },
+            &resource.columns,
+        )
+        .unwrap();
+        let formatter = Formatter::from(&Config::default().formatting);
+        let page = |names: &[&str]| -> Vec<Value> {
+            names
+                .iter()
+                .map(|name| serde_json::json!({ "Tags": { "Name": name } }))
+                .collect()
+        };
+        let mut rows = SectionRows {
+            columns: &columns,
+            formatter: &formatter,
+            match_text: Some("prod"),
+            limit: 2,
+            rows: Vec::new(),
+        };
+
+        // Matches on later pages are found, not just among the first `limit` rows
+        assert!(!rows.add_page(&page(&["dev-1", "dev-2", "dev-3"])));
+        assert!(!rows.add_page(&page(&["test-1", "prod-web"])));
+        assert!(!rows.is_full());
+        assert!(!rows.add_page(&page(&["prod-db", "dev-4"])));
+        assert!(rows.is_full());
+        assert_eq!(
+            rows.rows,
+            vec![vec!["prod-web".to_string()], vec!["prod-db".to_string()]]
+        );
+        // A further match is left out, so the section is marked truncated
+        assert!(rows.add_page(&page(&["prod-cache"])));
+        assert_eq!(rows.rows.len(), 2);
+    }
+
+    #[test]
+    fn test_render_markdown() {
+        let markdown = render_markdown(&sample_report());
+        assert!(markdown.starts_with("# Weekly audit\n"));
+        assert!(markdown.contains("| GROUP | RULE |\n| --- | --- |\n"));
+        assert!(markdown.contains("| sg-0abc | tcp\\|22 <0.0.0.0/0> |"));
+        assert!(markdown.contains("_1 row_"));
+        assert!(markdown.contains("## Quotas (us-east-1)\n\n_Error: AccessDenied_"));
+    }
+
+    #[test]
+    fn test_render_html() {
+        let html = render_html(&sample_report());
+        assert!(html.contains("<td>tcp|22 &lt;0.0.0.0/0&gt;</td>"));
+        assert!(html.contains("<p class=\"error\">Error: AccessDenied</p>"));
+    }
+
+    #[test]
+    fn test_report_format_for_output() {
+        assert_eq!(
+            ReportFormat::for_output(Some(Path::new("audit.HTML"))),
+            ReportFormat::Html
+        );
+        assert_eq!(
+            ReportFormat::for_output(Some(Path::new("audit.md"))),
+            ReportFormat::Markdown
+        );
+        assert_eq!(ReportFormat::for_output(None), ReportFormat::Markdown);
+    }
+}
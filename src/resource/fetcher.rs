@@ -10,10 +10,11 @@ use super::dispatch::{invoke_list, invoke_sdk};
 use super::registry::get_resource;
 use crate::aws::client::AwsClients;
 use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
 /// Filter for fetching resources (used for sub-resource filtering)
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct ResourceFilter {
     pub name: String,
     pub values: Vec<String>,
@@ -175,6 +176,21 @@ fn extract_items(response: &Value, path: &str) -> Result<Vec<Value>> {
     }
 }
 
+/// Field of a list row holding the cells whose lookup failed (column field -> reason)
+pub const CELL_ERRORS_FIELD: &str = "_errors";
+
+/// Why the `path` cell of a row failed to load, if it did
+pub fn cell_error<'a>(item: &'a Value, path: &str) -> Option<&'a str> {
+    item.get(CELL_ERRORS_FIELD)?.get(path)?.as_str()
+}
+
+/// Whether any cell of the rows failed to load
+pub fn has_cell_errors(items: &[Value]) -> bool {
+    items
+        .iter()
+        .any(|item| item.get(CELL_ERRORS_FIELD).is_some())
+}
+
 /// Extract a value from a JSON object using dot notation path
 /// Supports: "Field", "Field.SubField", "Field.0", "Tags.Name"
 pub fn extract_json_value(item: &Value, path: &str) -> String {
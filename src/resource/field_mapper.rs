@@ -64,16 +64,66 @@ fn apply_transform(value: &Value, transform: &str) -> Value {
         "tags_to_map" => transform_tags_to_map(value),
         "format_bytes" => transform_format_bytes(value),
         "format_epoch_millis" => transform_format_epoch_millis(value),
+        "format_epoch_seconds" => transform_format_epoch_seconds(value),
         "bool_to_yes_no" => transform_bool_to_yes_no(value),
         "array_to_csv" => transform_array_to_csv(value),
         "first_item" => transform_first_item(value),
         "private_zone_to_type" => transform_private_zone_to_type(value),
         "route53_record_value" => transform_route53_record_value(value),
         "route53_record_id" => transform_route53_record_id(value),
+        "log_filter_id" => transform_log_filter_id(value),
+        "sg_rule_count" => transform_sg_rule_count(value),
         _ => value.clone(),
     }
 }
 
+/// Count the rules in EC2 ipPermissions (one per CIDR range, prefix list or group)
+/// Input: [{"ipRanges": {"item": [{...}, {...}]}, "groups": {"item": {...}}}] -> 3
+fn transform_sg_rule_count(value: &Value) -> Value {
+    let items = |v: &Value| match v {
+        Value::Array(a) => a.len(),
+        Value::Object(_) => 1,
+        _ => 0,
+    };
+    let permissions = match value {
+        Value::Array(a) => a.clone(),
+        Value::Object(_) => vec![value.clone()],
+        _ => Vec::new(),
+    };
+    let count: usize = permissions
+        .iter()
+        .map(|permission| {
+            ["ipRanges", "ipv6Ranges", "prefixListIds", "groups"]
+                .iter()
+                .map(|key| {
+                    items(
+                        permission
+                            .pointer(&format!("/{}/item", key))
+                            .unwrap_or(&Value::Null),
+                    )
+                })
+                .sum::<usize>()
+        })
+        .sum();
+    Value::from(count)
+}
+
+/// Transform a CloudWatch Logs metric/subscription filter to unique ID (logGroupName|filterName)
+/// Filter names are only unique within a log group, and delete calls need both
+/// Input: {"logGroupName": "/aws/lambda/api", "filterName": "errors"} -> "/aws/lambda/api|errors"
+fn transform_log_filter_id(value: &Value) -> Value {
+    let log_group = value
+        .get("logGroupName")
+        .and_then(|v| v.as_str())
+        .unwrap_or("-");
+    let filter = value
+        .get("filterName")
+        .and_then(|v| v.as_str())
+        .unwrap_or("-");
+
+    Value::String(format!("{}|{}", log_group, filter))
+}
+
 /// Transform Route53 record to unique ID (Name#Type)
 /// This creates a unique identifier since multiple records can have the same name with different types
 /// Input: {"Name": "example.com", "Type": "A"} -> "example.com#A"
@@ -221,6 +271,17 @@ pub fn transform_format_epoch_millis(value: &Value) -> Value {
     Value::String(formatted)
 }
 
+/// Format epoch seconds (JSON protocol timestamps, may be fractional) to a date string
+pub fn transform_format_epoch_seconds(value: &Value) -> Value {
+    let seconds = match value {
+        Value::Number(n) => n.as_f64().unwrap_or(0.0),
+        Value::String(s) => s.parse::<f64>().unwrap_or(0.0),
+        _ => return Value::String("-".to_string()),
+    };
+
+    transform_format_epoch_millis(&Value::from((seconds * 1000.0) as i64))
+}
+
 /// Transform boolean to Yes/No string
 pub fn transform_bool_to_yes_no(value: &Value) -> Value {
     match value {
@@ -371,6 +432,30 @@ mod tests {
         assert_eq!(response["_next_token"], "token123");
     }
 
+    #[test]
+    fn test_transform_log_filter_id() {
+        let filter = json!({ "logGroupName": "/aws/lambda/api", "filterName": "errors" });
+        assert_eq!(
+            apply_transform(&filter, "log_filter_id"),
+            json!("/aws/lambda/api|errors")
+        );
+    }
+
+    #[test]
+    fn test_transform_sg_rule_count() {
+        let permissions = json!([
+            {
+                "ipProtocol": "tcp",
+                "ipRanges": { "item": [{ "cidrIp": "10.0.0.0/8" }, { "cidrIp": "0.0.0.0/0" }] },
+                "groups": { "item": { "groupId": "sg-123" } }
+            },
+            { "ipProtocol": "-1", "ipv6Ranges": { "item": { "cidrIpv6": "::/0" } } }
+        ]);
+        assert_eq!(apply_transform(&permissions, "sg_rule_count"), json!(4));
+        assert_eq!(apply_transform(&permissions[1], "sg_rule_count"), json!(1));
+        assert_eq!(apply_transform(&Value::Null, "sg_rule_count"), json!(0));
+    }
+
     #[test]
     fn test_transform_route53_record_value_with_single_resource_record() {
         let record = json!({
@@ -435,6 +520,16 @@ mod tests {
         assert_eq!(result, json!("-"));
     }
 
+    #[test]
+    fn test_transform_format_epoch_seconds() {
+        assert_eq!(
+            transform_format_epoch_seconds(&json!(1700000000.123)),
+            json!("2023-11-14 22:13:20")
+        );
+        assert_eq!(transform_format_epoch_seconds(&json!(0)), json!("-"));
+        assert_eq!(transform_format_epoch_seconds(&Value::Null), json!("-"));
+    }
+
     #[test]
     fn test_transform_route53_record_id() {
         let record = json!({
@@ -86,6 +86,21 @@ impl RestJsonProtocolHandler {
                     }
                 }
             }
+            // POST list APIs (e.g., Batch DescribeJobQueues) page through the body
+            if let Some(pagination) = &config.pagination {
+                if let (Some(input_token), Some(token)) = (
+                    &pagination.input_token,
+                    params.get("_page_token").and_then(|v| v.as_str()),
+                ) {
+                    body.insert(input_token.clone(), Value::String(token.to_string()));
+                }
+                if let Some(max_param) = &pagination.max_results_param {
+                    body.insert(
+                        max_param.clone(),
+                        Value::from(pagination.max_results.unwrap_or(50)),
+                    );
+                }
+            }
             if body.is_empty() {
                 None
             } else {
@@ -1,4 +1,5 @@
 mod fetcher;
+mod references;
 mod registry;
 
 // Data-driven dispatch infrastructure
@@ -9,7 +10,13 @@ pub mod path_extractor;
 pub mod protocol;
 
 pub use dispatch::{
-    describe_resource, execute_action, execute_action_with_result, format_log_timestamp, invoke_sdk,
+    describe_resource, document_edit_fields, document_edits, execute_action,
+    execute_action_with_result, execute_form_action, format_log_timestamp, get_form_fields,
+    invoke_sdk, list_enabled_regions, required_iam_actions, FormField, SENSITIVE_FIELDS_FIELD,
 };
-pub use fetcher::{extract_json_value, fetch_resources_paginated, ResourceFilter};
+pub use fetcher::{
+    cell_error, extract_json_value, fetch_resources_paginated, has_cell_errors, ResourceFilter,
+    CELL_ERRORS_FIELD,
+};
+pub use references::{find_references, ResourceRef};
 pub use registry::*;
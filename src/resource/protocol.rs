@@ -89,6 +89,11 @@ pub struct ApiConfig {
     #[serde(default)]
     pub pagination: Option<PaginationConfig>,
 
+    /// Describe each listed item with describe_config and merge in the details,
+    /// for list APIs that only return identifiers (e.g., DynamoDB ListTables)
+    #[serde(default)]
+    pub describe_items: bool,
+
     /// Pre-request hooks (e.g., "resolve_s3_bucket_region")
     #[serde(default)]
     pub pre_hooks: Vec<String>,
@@ -0,0 +1,274 @@
+//! References to other resources (IDs and ARNs) in detail JSON
+//!
+//! Turns values such as "sg-0123abcd" or "arn:aws:iam::123456789012:role/app"
+//! into links to the resource type listing them, so the describe view can
+//! jump from one resource to the ones it points at.
+
+/// A string value in detail JSON that names another resource
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResourceRef {
+    /// Line of the pretty-printed JSON the value is on
+    pub line: usize,
+    /// The value as it appears in the JSON
+    pub value: String,
+    /// Resource type listing the referenced resource (e.g., "security-groups")
+    pub resource_key: &'static str,
+    /// ID, name or ARN of the row to select in that listing
+    pub target: String,
+    /// Region named by an ARN (None for plain IDs and global ARNs)
+    pub region: Option<String>,
+}
+
+/// EC2-style ID prefixes and the resource types listing them (longest prefixes first)
+const ID_PREFIXES: &[(&str, &str)] = &[
+    ("tgw-attach-", "transit-gateway-attachments"),
+    ("tgw-rtb-", "transit-gateway-route-tables"),
+    ("tgw-", "transit-gateways"),
+    ("subnet-", "subnets"),
+    ("snap-", "ec2-snapshots"),
+    ("vpc-", "vpc"),
+    ("vol-", "ec2-volumes"),
+    ("ami-", "ec2-amis"),
+    ("rtb-", "route-tables"),
+    ("igw-", "internet-gateways"),
+    ("nat-", "nat-gateways"),
+    ("sg-", "security-groups"),
+    ("i-", "ec2-instances"),
+];
+
+/// Every reference in pretty-printed JSON, in line order
+pub fn find_references(json: &str) -> Vec<ResourceRef> {
+    json.lines()
+        .enumerate()
+        .flat_map(|(line, text)| {
+            string_values(text).into_iter().filter_map(move |value| {
+                let (resource_key, target, region) = classify(&value)?;
+                Some(ResourceRef {
+                    line,
+                    value,
+                    resource_key,
+                    target,
+                    region,
+                })
+            })
+        })
+        .collect()
+}
+
+/// Resource type, target and region a value points to
+fn classify(value: &str) -> Option<(&'static str, String, Option<String>)> {
+    if value.starts_with("arn:") {
+        return arn_reference(value);
+    }
+    id_reference(value).map(|key| (key, value.to_string(), None))
+}
+
+/// Resource type of an EC2-style ID ("sg-" followed by 8 or 17 hex digits)
+fn id_reference(value: &str) -> Option<&'static str> {
+    ID_PREFIXES.iter().find_map(|(prefix, key)| {
+        let hex = value.strip_prefix(prefix)?;
+        let valid = matches!(hex.len(), 8 | 17) && hex.chars().all(|c| c.is_ascii_hexdigit());
+        valid.then_some(*key)
+    })
+}
+
+/// Resource type, target and region of an ARN of a resource type taws lists
+fn arn_reference(arn: &str) -> Option<(&'static str, String, Option<String>)> {
+    let parts: Vec<&str> = arn.splitn(6, ':').collect();
+    let [_, _, service, region, _, resource] = parts.as_slice() else {
+        return None;
+    };
+    let region = (!region.is_empty()).then(|| region.to_string());
+    // "role/path/name", "function:name:alias" or a bare "topic-name"
+    let (kind, rest) = match resource.find(['/', ':']) {
+        Some(i) => (&resource[..i], &resource[i + 1..]),
+        None => (*resource, ""),
+    };
+    let last = |s: &str| s.rsplit('/').next().unwrap_or(s).to_string();
+    let first = |s: &str| s.split(['/', ':']).next().unwrap_or(s).to_string();
+
+    let (key, target) = match (*service, kind) {
+        ("ec2", _) => {
+            let id = last(rest);
+            (id_reference(&id)?, id)
+        }
+        ("lambda", "function") => ("lambda-functions", first(rest)),
+        ("iam", "role") => ("iam-roles", last(rest)),
+        ("iam", "user") => ("iam-users", last(rest)),
+        ("iam", "group") => ("iam-groups", last(rest)),
+        ("iam", "policy") => ("iam-policies", last(rest)),
+        ("sns", topic) if rest.is_empty() => ("sns-topics", topic.to_string()),
+        ("sqs", queue) if rest.is_empty() => ("sqs-queues", queue.to_string()),
+        ("s3", bucket) if rest.is_empty() && region.is_none() => ("s3-buckets", bucket.to_string()),
+        ("dynamodb", "table") => ("dynamodb-tables", first(rest)),
+        ("ecs", "cluster") => ("ecs-clusters", rest.to_string()),
+        ("eks", "cluster") => ("eks-clusters", rest.to_string()),
+        ("ecr", "repository") => ("ecr-repositories", rest.to_string()),
+        ("rds", "db") => ("rds-instances", rest.to_string()),
+        ("rds", "snapshot") => ("rds-snapshots", rest.to_string()),
+        ("redshift", "cluster") => ("redshift-clusters", rest.to_string()),
+        ("elasticache", "cluster") => ("elasticache-clusters", rest.to_string()),
+        ("es", "domain") => ("opensearch-domains", rest.to_string()),
+        ("elasticfilesystem", "file-system") => ("efs-file-systems", rest.to_string()),
+        ("fsx", "file-system") => ("fsx-file-systems", rest.to_string()),
+        ("kms", "key") => ("kms-keys", rest.to_string()),
+        ("logs", "log-group") => (
+            "cloudwatch-log-groups",
+            rest.trim_end_matches(":*").to_string(),
+        ),
+        ("cloudwatch", "alarm") => ("cloudwatch-alarms", rest.to_string()),
+        ("cloudformation", "stack") => ("cloudformation-stacks", first(rest)),
+        ("autoscaling", "autoScalingGroup") => (
+            "autoscaling-groups",
+            rest.split_once("autoScalingGroupName/")?.1.to_string(),
+        ),
+        // Listed by their full ARN
+        ("elasticloadbalancing", "loadbalancer") => ("elbv2-load-balancers", arn.to_string()),
+        ("elasticloadbalancing", "targetgroup") => ("elbv2-target-groups", arn.to_string()),
+        ("secretsmanager", "secret") => ("secretsmanager-secrets", arn.to_string()),
+        ("states", "stateMachine") => ("stepfunctions-state-machines", arn.to_string()),
+        ("acm", "certificate") => ("acm-certificates", arn.to_string()),
+        ("kafka", "cluster") => ("msk-clusters", arn.to_string()),
+        ("elasticbeanstalk", "environment") => ("elasticbeanstalk-environments", last(rest)),
+        ("elasticbeanstalk", "application") => ("elasticbeanstalk-applications", rest.to_string()),
+        ("apprunner", "service") => ("apprunner-services", arn.to_string()),
+        ("mq", "broker") => ("mq-brokers", rest.rsplit(':').next()?.to_string()),
+        ("events", "rule") => ("eventbridge-rules", arn.to_string()),
+        ("events", "event-bus") => ("eventbridge-buses", arn.to_string()),
+        _ => return None,
+    };
+    (!target.is_empty()).then_some((key, target, region))
+}
+
+/// String literals of a line of JSON, unescaped only as far as IDs and ARNs need
+fn string_values(line: &str) -> Vec<String> {
+    let mut values = Vec::new();
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        if c != '"' {
+            continue;
+        }
+        let mut value = String::new();
+        while let Some(c) = chars.next() {
+            match c {
+                '"' => break,
+                '\\' => {
+                    if let Some(escaped) = chars.next() {
+                        value.push(escaped);
+                    }
+                }
+                _ => value.push(c),
+            }
+        }
+        values.push(value);
+    }
+    values
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::resource::get_resource;
+
+    #[test]
+    fn test_find_references() {
+        let json = r#"{
+  "InstanceId": "i-0123456789abcdef0",
+  "SecurityGroups": ["sg-0a1b2c3d", "sg-nothex99"],
+  "SubnetId": "subnet-0123456789abcdef0",
+  "IamInstanceProfile": "arn:aws:iam::123456789012:role/service/app-role",
+  "Queue": "arn:aws:sqs:eu-west-1:123456789012:orders-dlq",
+  "Target": "arn:aws:elasticloadbalancing:eu-west-1:123456789012:targetgroup/web/73e2d6bc24d8a067",
+  "Function": "arn:aws:lambda:eu-west-1:123456789012:function:resize:live",
+  "Note": "i-0123456789abcdef0 is the primary"
+}"#;
+        let refs = find_references(json);
+        let summary: Vec<(usize, &str, &str)> = refs
+            .iter()
+            .map(|r| (r.line, r.resource_key, r.target.as_str()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (1, "ec2-instances", "i-0123456789abcdef0"),
+                (2, "security-groups", "sg-0a1b2c3d"),
+                (3, "subnets", "subnet-0123456789abcdef0"),
+                (4, "iam-roles", "app-role"),
+                (5, "sqs-queues", "orders-dlq"),
+                (
+                    6,
+                    "elbv2-target-groups",
+                    "arn:aws:elasticloadbalancing:eu-west-1:123456789012:targetgroup/web/73e2d6bc24d8a067"
+                ),
+                (7, "lambda-functions", "resize"),
+            ]
+        );
+        assert_eq!(refs[3].region, None);
+        assert_eq!(refs[4].region.as_deref(), Some("eu-west-1"));
+        assert_eq!(
+            refs[4].value,
+            "arn:aws:sqs:eu-west-1:123456789012:orders-dlq"
+        );
+    }
+
+    #[test]
+    fn test_reference_resource_types_exist() {
+        let arns = [
+            "arn:aws:ec2:us-east-1:1:volume/vol-0123abcd",
+            "arn:aws:iam::1:user/alice",
+            "arn:aws:iam::1:group/admins",
+            "arn:aws:iam::1:policy/ops/deploy",
+            "arn:aws:sns:us-east-1:1:alerts",
+            "arn:aws:s3:::logs-bucket",
+            "arn:aws:dynamodb:us-east-1:1:table/orders/stream/2024",
+            "arn:aws:ecs:us-east-1:1:cluster/prod",
+            "arn:aws:eks:us-east-1:1:cluster/prod",
+            "arn:aws:ecr:us-east-1:1:repository/web",
+            "arn:aws:rds:us-east-1:1:db:orders",
+            "arn:aws:rds:us-east-1:1:snapshot:orders-nightly",
+            "arn:aws:redshift:us-east-1:1:cluster:warehouse",
+            "arn:aws:elasticache:us-east-1:1:cluster:sessions",
+            "arn:aws:es:us-east-1:1:domain/search",
+            "arn:aws:elasticfilesystem:us-east-1:1:file-system/fs-0123abcd",
+            "arn:aws:fsx:us-east-1:1:file-system/fs-0123456789abcdef0",
+            "arn:aws:kms:us-east-1:1:key/1234abcd-12ab-34cd-56ef-1234567890ab",
+            "arn:aws:logs:us-east-1:1:log-group:/ecs/web:*",
+            "arn:aws:cloudwatch:us-east-1:1:alarm:cpu-high",
+            "arn:aws:cloudformation:us-east-1:1:stack/network/abc-123",
+            "arn:aws:autoscaling:us-east-1:1:autoScalingGroup:uuid:autoScalingGroupName/web-asg",
+            "arn:aws:elasticloadbalancing:us-east-1:1:loadbalancer/app/web/50dc6c495c0c9188",
+            "arn:aws:secretsmanager:us-east-1:1:secret:db-AbCdEf",
+            "arn:aws:states:us-east-1:1:stateMachine:orders",
+            "arn:aws:acm:us-east-1:1:certificate/abc",
+            "arn:aws:kafka:us-east-1:1:cluster/events/abc-1",
+            "arn:aws:mq:us-east-1:1:broker:orders:b-1234",
+            "arn:aws:apprunner:us-east-1:1:service/web/abc123",
+            "arn:aws:elasticbeanstalk:us-east-1:1:environment/shop/shop-prod",
+            "arn:aws:elasticbeanstalk:us-east-1:1:application/shop",
+            "arn:aws:events:us-east-1:1:rule/nightly",
+            "arn:aws:events:us-east-1:1:event-bus/default",
+        ];
+        for arn in arns {
+            let (key, target, _) =
+                arn_reference(arn).unwrap_or_else(|| panic!("{} not recognized", arn));
+            assert!(
+                get_resource(key).is_some(),
+                "{} maps to unknown {}",
+                arn,
+                key
+            );
+            assert!(!target.is_empty());
+        }
+        for (_, key) in ID_PREFIXES {
+            assert!(get_resource(key).is_some(), "unknown resource {}", key);
+        }
+        assert_eq!(
+            arn_reference("arn:aws:logs:us-east-1:1:log-group:/ecs/web:*")
+                .unwrap()
+                .1,
+            "/ecs/web"
+        );
+        assert!(arn_reference("arn:aws:s3:::bucket/key.txt").is_none());
+        assert!(arn_reference("arn:aws:ec2:us-east-1:1:key-pair/key-0123abcd").is_none());
+    }
+}
@@ -3,7 +3,7 @@
 //! This module loads all AWS resource definitions from embedded JSON files
 //! and provides lookup functions for the rest of the application.
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::collections::HashMap;
 use std::sync::OnceLock;
@@ -12,10 +12,15 @@ use super::protocol::{ActionConfig, ApiConfig, DescribeConfig, FieldMapping};
 
 /// Embedded resource JSON files (compiled into the binary)
 const RESOURCE_FILES: &[&str] = &[
+    include_str!("../resources/accessanalyzer.json"),
+    include_str!("../resources/account.json"),
     include_str!("../resources/acm.json"),
     include_str!("../resources/apigateway.json"),
+    include_str!("../resources/apprunner.json"),
     include_str!("../resources/athena.json"),
     include_str!("../resources/autoscaling.json"),
+    include_str!("../resources/backup.json"),
+    include_str!("../resources/batch.json"),
     include_str!("../resources/cloudformation.json"),
     include_str!("../resources/cloudfront.json"),
     include_str!("../resources/cloudtrail.json"),
@@ -24,27 +29,40 @@ const RESOURCE_FILES: &[&str] = &[
     include_str!("../resources/codepipeline.json"),
     include_str!("../resources/cognito.json"),
     include_str!("../resources/common.json"),
+    include_str!("../resources/config.json"),
     include_str!("../resources/dynamodb.json"),
     include_str!("../resources/ec2.json"),
     include_str!("../resources/ecr.json"),
     include_str!("../resources/ecs.json"),
+    include_str!("../resources/efs.json"),
     include_str!("../resources/eks.json"),
+    include_str!("../resources/elasticbeanstalk.json"),
     include_str!("../resources/elasticache.json"),
     include_str!("../resources/elbv2.json"),
     include_str!("../resources/eventbridge.json"),
+    include_str!("../resources/fsx.json"),
+    include_str!("../resources/glue.json"),
     include_str!("../resources/iam.json"),
     include_str!("../resources/kms.json"),
     include_str!("../resources/lambda.json"),
+    include_str!("../resources/mq.json"),
+    include_str!("../resources/msk.json"),
+    include_str!("../resources/opensearch.json"),
+    include_str!("../resources/organizations.json"),
     include_str!("../resources/rds.json"),
     include_str!("../resources/redshift.json"),
     include_str!("../resources/route53.json"),
     include_str!("../resources/s3.json"),
+    include_str!("../resources/sagemaker.json"),
     include_str!("../resources/secretsmanager.json"),
+    include_str!("../resources/servicequotas.json"),
     include_str!("../resources/sns.json"),
     include_str!("../resources/sqs.json"),
     include_str!("../resources/ssm.json"),
+    include_str!("../resources/stepfunctions.json"),
     include_str!("../resources/sts.json"),
     include_str!("../resources/vpc.json"),
+    include_str!("../resources/wafv2.json"),
 ];
 
 /// Color definition from JSON
@@ -62,6 +80,16 @@ pub struct ColumnDef {
     pub width: u16,
     #[serde(default)]
     pub color_map: Option<String>,
+    /// How the raw value is humanized (bytes, seconds, count, ...)
+    #[serde(default)]
+    pub format: Option<crate::humanize::ValueFormat>,
+}
+
+/// Colors a whole row when a field's value is in a color map (e.g., failed stacks)
+#[derive(Debug, Clone, Deserialize)]
+pub struct RowColorDef {
+    pub json_path: String,
+    pub color_map: String,
 }
 
 /// Sub-resource definition from JSON
@@ -130,9 +158,56 @@ pub struct ActionDef {
     /// If true, display the action result in the JSON viewer instead of just executing
     #[serde(default)]
     pub show_result: bool,
+    /// Result fields holding sensitive values (e.g., "SecretString" or
+    /// "Parameter.Value"), masked until revealed. Sensitive actions that ask
+    /// for confirmation are blocked in read-only mode.
+    #[serde(default)]
+    pub sensitive_fields: Vec<String>,
+    /// If true, collect parameters in an input form before executing
+    #[serde(default)]
+    pub form: bool,
+    /// IAM actions required (e.g., "lambda:InvokeFunction"); derived from
+    /// action_configs when empty
+    #[serde(default)]
+    pub iam_actions: Vec<String>,
+    /// Status to follow after the action starts, for completion notifications
+    #[serde(default)]
+    pub watch: Option<WatchDef>,
+    /// If true, run the action on a background task and report its result when
+    /// it finishes (for actions that wait, e.g., for an instance to stop)
+    #[serde(default)]
+    pub background: bool,
+}
+
+/// How to tell when the job started by an action has finished
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WatchDef {
+    /// Row field holding the job status
+    pub status_field: String,
+    /// Statuses meaning the job succeeded
+    #[serde(default)]
+    pub success: Vec<String>,
+    /// Statuses meaning the job failed
+    #[serde(default)]
+    pub failure: Vec<String>,
+    /// Statuses meaning the job is still running; the view refreshes itself
+    /// while a row has one
+    #[serde(default)]
+    pub in_progress: Vec<String>,
+    /// If true, the row disappearing means success (e.g., deletes)
+    #[serde(default)]
+    pub gone_succeeds: bool,
+    /// Minutes after which the job is reported as timed out (default 360)
+    #[serde(default)]
+    pub timeout_minutes: Option<u64>,
 }
 
 impl ActionDef {
+    /// Check if this action reveals sensitive values
+    pub fn is_sensitive(&self) -> bool {
+        !self.sensitive_fields.is_empty()
+    }
+
     /// Check if this action requires confirmation
     pub fn requires_confirm(&self) -> bool {
         self.confirm.is_some() || self.needs_confirm
@@ -215,6 +290,15 @@ pub struct ResourceDef {
     /// If true, preserve the order returned by the API instead of sorting alphabetically
     #[serde(default)]
     pub preserve_order: bool,
+
+    /// Optional whole-row coloring (values missing from the color map keep column colors)
+    #[serde(default)]
+    pub row_color: Option<RowColorDef>,
+
+    /// Field holding days until expiry; rows turn yellow, then red, as it
+    /// drops below the thresholds in config.yaml (`expiry_warning`)
+    #[serde(default)]
+    pub expiry_field: Option<String>,
 }
 
 impl ResourceDef {
@@ -411,6 +495,21 @@ mod tests {
 
         let bool_map = get_color_map("bool");
         assert!(bool_map.is_some(), "Bool color map should exist");
+
+        // Every column color map referenced by a resource must be defined
+        for key in get_all_resource_keys() {
+            let resource = get_resource(key).unwrap();
+            for column in &resource.columns {
+                if let Some(name) = &column.color_map {
+                    assert!(
+                        get_color_map(name).is_some(),
+                        "{} uses undefined color map '{}'",
+                        key,
+                        name
+                    );
+                }
+            }
+        }
     }
 
     #[test]
@@ -616,6 +715,10 @@ mod tests {
             Some("x"),
             "get_secret_value should use 'x' shortcut"
         );
+        assert!(
+            view_action.is_sensitive() && view_action.requires_confirm(),
+            "get_secret_value should be confirmed and masked"
+        );
     }
 
     #[test]
@@ -647,6 +750,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_ssm_documents_has_start_automation_form() {
+        let resource = get_resource("ssm-documents").unwrap();
+
+        let start_action = resource
+            .actions
+            .iter()
+            .find(|a| a.sdk_method == "start_automation")
+            .expect("SSM Documents should have start_automation action");
+        assert!(
+            start_action.form,
+            "start_automation action should collect parameters in a form"
+        );
+
+        let executions = get_resource("ssm-automation-executions").unwrap();
+        assert!(executions
+            .sub_resources
+            .iter()
+            .any(|s| s.resource_key == "ssm-automation-steps"));
+    }
+
     #[test]
     fn test_cloudformation_stacks_has_sub_resources() {
         let resource = get_resource("cloudformation-stacks").unwrap();
@@ -720,4 +844,34 @@ mod tests {
         let delete_complete = get_color_for_value("state", "DELETE_COMPLETE");
         assert_eq!(delete_complete, Some([128, 128, 128]));
     }
+
+    #[test]
+    fn test_cloudformation_failed_rows_are_red() {
+        for key in [
+            "cloudformation-stacks",
+            "cloudformation-events",
+            "cloudformation-resources",
+        ] {
+            let row_color = get_resource(key).unwrap().row_color.as_ref().unwrap();
+            for status in ["UPDATE_ROLLBACK_COMPLETE", "CREATE_FAILED"] {
+                assert_eq!(
+                    get_color_for_value(&row_color.color_map, status),
+                    Some([255, 0, 0])
+                );
+            }
+            assert!(get_color_for_value(&row_color.color_map, "UPDATE_COMPLETE").is_none());
+        }
+    }
+
+    #[test]
+    fn test_cloudformation_stacks_has_template_action() {
+        let resource = get_resource("cloudformation-stacks").unwrap();
+        let action = resource
+            .actions
+            .iter()
+            .find(|a| a.sdk_method == "get_template")
+            .unwrap();
+        assert!(action.show_result);
+        assert_eq!(action.shortcut.as_deref(), Some("t"));
+    }
 }
@@ -0,0 +1,97 @@
+use crate::app::App;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+pub fn render(f: &mut Frame, app: &App) {
+    let (Some(state), Some((header, value))) = (&app.cell_view, app.cell_view_content()) else {
+        return;
+    };
+    let column_count = app.visible_columns().len();
+
+    let area = centered_rect(80, 70, f.area());
+    f.render_widget(Clear, area);
+
+    let title = format!(" {} ({}/{}) ", header, state.column + 1, column_count);
+    let hint = if state.copied {
+        " Copied to clipboard ".to_string()
+    } else {
+        format!(
+            " {} chars │ h/l column  j/k scroll  y copy  Esc close ",
+            value.chars().count()
+        )
+    };
+    let block = Block::default()
+        .title(title)
+        .title_style(
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        )
+        .title_bottom(Line::from(Span::styled(
+            hint,
+            Style::default().fg(if state.copied {
+                Color::Green
+            } else {
+                Color::DarkGray
+            }),
+        )))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+    let inner_area = block.inner(area);
+    f.render_widget(block, area);
+
+    // Mask before wrapping, while values are still on one line
+    let value = match app.redact_classes() {
+        Some(classes) => crate::redact::redact_text(&value, classes),
+        None => value,
+    };
+
+    // Wrap at character boundaries: long values (ARNs, base64 user data) have no spaces
+    let width = (inner_area.width as usize).max(1);
+    let lines: Vec<Line> = value
+        .lines()
+        .flat_map(|line| wrap_chars(line, width))
+        .map(|line| Line::from(Span::styled(line, Style::default().fg(Color::White))))
+        .collect();
+
+    let max_scroll = lines.len().saturating_sub(inner_area.height as usize);
+    let scroll = state.scroll.min(max_scroll);
+    let paragraph = Paragraph::new(lines).scroll((scroll as u16, 0));
+    f.render_widget(paragraph, inner_area);
+}
+
+fn wrap_chars(line: &str, width: usize) -> Vec<String> {
+    let chars: Vec<char> = line.chars().collect();
+    if chars.is_empty() {
+        return vec![String::new()];
+    }
+    chars
+        .chunks(width)
+        .map(|chunk| chunk.iter().collect())
+        .collect()
+}
+
+fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
+    let popup_layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage((100 - percent_y) / 2),
+            Constraint::Percentage(percent_y),
+            Constraint::Percentage((100 - percent_y) / 2),
+        ])
+        .split(r);
+
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage((100 - percent_x) / 2),
+            Constraint::Percentage(percent_x),
+            Constraint::Percentage((100 - percent_x) / 2),
+        ])
+        .split(popup_layout[1])[1]
+}
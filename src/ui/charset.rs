@@ -0,0 +1,98 @@
+//! Pure-ASCII rendering for terminals that garble box-drawing characters
+//!
+//! Like redaction, this works on the rendered frame: once every widget has
+//! drawn, borders, arrows, markers and spinners are swapped for ASCII
+//! stand-ins of the same width, so no widget needs to know about it.
+
+use ratatui::buffer::Buffer;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static ASCII: AtomicBool = AtomicBool::new(false);
+
+/// Switch between Unicode and ASCII borders and markers (from config.yaml)
+pub fn set_ascii(ascii: bool) {
+    ASCII.store(ascii, Ordering::Relaxed);
+}
+
+pub fn is_ascii() -> bool {
+    ASCII.load(Ordering::Relaxed)
+}
+
+/// Replace non-ASCII borders and markers in a rendered frame (when ASCII is on)
+pub fn apply(buffer: &mut Buffer) {
+    if !is_ascii() {
+        return;
+    }
+    for cell in buffer.content.iter_mut() {
+        let mut chars = cell.symbol().chars();
+        if let (Some(c), None) = (chars.next(), chars.next()) {
+            if let Some(replacement) = ascii_replacement(c) {
+                cell.set_char(replacement);
+            }
+        }
+    }
+}
+
+/// ASCII stand-in for a border, block, arrow or marker character
+fn ascii_replacement(c: char) -> Option<char> {
+    let replacement = match c {
+        // Box drawing: lines become - and |, every corner, tee and cross becomes +
+        '─' | '━' | '┄' | '┅' | '┈' | '┉' | '╌' | '╍' | '╴' | '╶' | '╸' | '╺' => {
+            '-'
+        }
+        '═' => '=',
+        '│' | '┃' | '┆' | '┇' | '┊' | '┋' | '╎' | '╏' | '║' | '╵' | '╷' | '╹' | '╻' => {
+            '|'
+        }
+        '\u{2500}'..='\u{257F}' => '+',
+        // Block elements (logo, progress bars)
+        '░' | '▒' => '.',
+        '\u{2580}'..='\u{259F}' => '#',
+        // Spinner frames
+        '⠋' => '|',
+        '⠙' => '/',
+        '⠹' => '-',
+        '⠸' => '\\',
+        '\u{2800}'..='\u{28FF}' => '*',
+        // Arrows and markers
+        '↑' | '▲' | '△' => '^',
+        '↓' | '▼' | '▽' => 'v',
+        '←' | '◀' => '<',
+        '→' | '▶' | '▸' | '›' => '>',
+        '↻' | '⟳' => '~',
+        '✓' | '✔' | '•' | '●' | '★' => '*',
+        '✗' | '✘' | '×' => 'x',
+        '○' | '◯' => 'o',
+        '·' | '…' => '.',
+        _ => return None,
+    };
+    Some(replacement)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::layout::Rect;
+
+    #[test]
+    fn test_ascii_replacement() {
+        assert_eq!(ascii_replacement('┌'), Some('+'));
+        assert_eq!(ascii_replacement('╝'), Some('+'));
+        assert_eq!(ascii_replacement('─'), Some('-'));
+        assert_eq!(ascii_replacement('║'), Some('|'));
+        assert_eq!(ascii_replacement('█'), Some('#'));
+        assert_eq!(ascii_replacement('↻'), Some('~'));
+        assert_eq!(ascii_replacement('✓'), Some('*'));
+        // Text in other scripts is left alone
+        assert_eq!(ascii_replacement('é'), None);
+        assert_eq!(ascii_replacement('a'), None);
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 1));
+        buffer.set_string(0, 0, "┌─ é┐", ratatui::style::Style::default());
+        set_ascii(true);
+        apply(&mut buffer);
+        set_ascii(false);
+        let line: String = buffer.content.iter().map(|c| c.symbol()).collect();
+        assert_eq!(line, "+- é+ ");
+    }
+}
@@ -0,0 +1,96 @@
+use crate::app::App;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+pub fn render(f: &mut Frame, app: &App) {
+    let (Some(state), Some(resource)) = (&app.columns_editor, app.current_resource()) else {
+        return;
+    };
+    let view = app.current_view();
+
+    let height = (resource.columns.len() as u16 + 2).min(f.area().height.saturating_sub(2));
+    let area = centered_rect(50, height, f.area());
+    f.render_widget(Clear, area);
+
+    let block = Block::default()
+        .title(format!(" Columns: {} ", resource.display_name))
+        .title_style(
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        )
+        .title_bottom(Line::from(Span::styled(
+            " space show/hide  s sort  +/- width  r reset  Esc close ",
+            Style::default().fg(Color::DarkGray),
+        )))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+    let inner_area = block.inner(area);
+    f.render_widget(block, area);
+
+    // Keep the selected column in view when the list is taller than the popup
+    let visible_rows = inner_area.height as usize;
+    let offset = (state.selected + 1).saturating_sub(visible_rows);
+
+    let lines: Vec<Line> = resource
+        .columns
+        .iter()
+        .enumerate()
+        .skip(offset)
+        .take(visible_rows)
+        .map(|(i, col)| {
+            let hidden = view.is_hidden(&col.header);
+            let width = view.widths.get(&col.header).copied().unwrap_or(col.width);
+            let sort = match &view.sort {
+                Some(sort) if sort.column == col.header && sort.descending => "↓ desc",
+                Some(sort) if sort.column == col.header => "↑ asc",
+                _ => "",
+            };
+            let mut style = if hidden {
+                Style::default().fg(Color::DarkGray)
+            } else {
+                Style::default().fg(Color::White)
+            };
+            if i == state.selected {
+                style = style.bg(Color::DarkGray).add_modifier(Modifier::BOLD);
+            }
+            Line::from(Span::styled(
+                format!(
+                    " [{}] {:<28} {:>3}%  {}",
+                    if hidden { " " } else { "x" },
+                    col.header,
+                    width,
+                    sort
+                ),
+                style,
+            ))
+        })
+        .collect();
+
+    f.render_widget(Paragraph::new(lines), inner_area);
+}
+
+fn centered_rect(percent_x: u16, height: u16, r: Rect) -> Rect {
+    let popup_layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Fill(1),
+            Constraint::Length(height),
+            Constraint::Fill(1),
+        ])
+        .split(r);
+
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage((100 - percent_x) / 2),
+            Constraint::Percentage(percent_x),
+            Constraint::Percentage((100 - percent_x) / 2),
+        ])
+        .split(popup_layout[1])[1]
+}
@@ -87,16 +87,27 @@ pub fn render(f: &mut Frame, app: &App) {
         .skip(scroll_offset)
         .take(inner_height)
         .map(|(i, suggestion)| {
+            // Resource types whose service the capability probe was denied
+            let denied = app.denied_resource(suggestion).is_some();
             let style = if i == app.command_suggestion_selected {
                 Style::default()
                     .fg(Color::Black)
-                    .bg(Color::Cyan)
+                    .bg(if denied { Color::DarkGray } else { Color::Cyan })
                     .add_modifier(Modifier::BOLD)
+            } else if denied {
+                Style::default().fg(Color::DarkGray)
             } else {
                 Style::default().fg(Color::White)
             };
 
-            Line::from(vec![Span::raw("  "), Span::styled(suggestion, style)])
+            let mut spans = vec![Span::raw("  "), Span::styled(suggestion, style)];
+            if denied {
+                spans.push(Span::styled(
+                    "  (no access)",
+                    Style::default().fg(Color::DarkGray),
+                ));
+            }
+            Line::from(spans)
         })
         .collect();
 
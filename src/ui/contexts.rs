@@ -0,0 +1,84 @@
+use crate::app::App;
+use crate::resource::get_resource;
+use ratatui::{
+    layout::{Alignment, Constraint, Rect},
+    style::{Color, Modifier, Style},
+    text::Span,
+    widgets::{Block, Borders, Cell, Row, Table, TableState},
+    Frame,
+};
+
+pub fn render(f: &mut Frame, app: &App, area: Rect) {
+    // Create bordered box with centered title
+    let title = format!(" Contexts[{}] ", app.config.contexts.len());
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::DarkGray))
+        .title(Span::styled(
+            title,
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        ))
+        .title_alignment(Alignment::Center);
+
+    let inner_area = block.inner(area);
+    f.render_widget(block, area);
+
+    let header_cells = [" CONTEXT", "PROFILE", "REGION", "VIEW"].iter().map(|h| {
+        Cell::from(*h).style(
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )
+    });
+
+    let header = Row::new(header_cells).height(1);
+
+    let active = app.active_context().map(|c| c.name.as_str());
+    let rows = app.config.contexts.iter().map(|context| {
+        let current = Some(context.name.as_str()) == active;
+        let style = if current {
+            Style::default().fg(Color::Green)
+        } else {
+            Style::default()
+        };
+
+        let marker = if current { " * " } else { "   " };
+        let view = context
+            .resource
+            .as_deref()
+            .map(|key| {
+                get_resource(key)
+                    .map(|r| r.display_name.clone())
+                    .unwrap_or_else(|| key.to_string())
+            })
+            .unwrap_or_else(|| "-".to_string());
+
+        Row::new(vec![
+            Cell::from(format!("{}{}", marker, context.name)).style(style),
+            Cell::from(context.profile.clone()).style(style),
+            Cell::from(context.region.clone().unwrap_or_else(|| "-".to_string())).style(style),
+            Cell::from(view).style(Style::default().fg(Color::DarkGray)),
+        ])
+    });
+
+    let widths = [
+        Constraint::Percentage(30),
+        Constraint::Percentage(25),
+        Constraint::Length(16),
+        Constraint::Min(20),
+    ];
+
+    let table = Table::new(rows, widths).header(header).row_highlight_style(
+        Style::default()
+            .bg(Color::DarkGray)
+            .fg(Color::White)
+            .add_modifier(Modifier::BOLD),
+    );
+
+    let mut state = TableState::default();
+    state.select(Some(app.contexts_selected));
+
+    f.render_stateful_widget(table, inner_area, &mut state);
+}
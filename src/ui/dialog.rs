@@ -13,6 +13,7 @@ pub fn render(f: &mut Frame, app: &App) {
         Mode::Warning => render_warning_dialog(f, app),
         Mode::SsoLogin => render_sso_dialog(f, app),
         Mode::ConsoleLogin => render_console_login_dialog(f, app),
+        Mode::Form => render_form_dialog(f, app),
         _ => {}
     }
 }
@@ -79,7 +80,8 @@ fn render_confirm_dialog(f: &mut Frame, app: &App) {
 
     let paragraph = Paragraph::new(text)
         .block(block)
-        .alignment(Alignment::Center);
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true });
 
     f.render_widget(paragraph, area);
 }
@@ -141,6 +143,97 @@ fn render_warning_dialog(f: &mut Frame, app: &App) {
     f.render_widget(paragraph, area);
 }
 
+fn render_form_dialog(f: &mut Frame, app: &App) {
+    let Some(form) = &app.form_state else {
+        return;
+    };
+
+    // Header + blank + two lines per field + blank + error + help + borders
+    let height = (form.fields.len() * 2 + 7).min(30) as u16;
+    let area = centered_rect(70, height, f.area());
+
+    f.render_widget(Clear, area);
+
+    // Scroll the fields so the selected one stays visible in long forms
+    let visible = (area.height.saturating_sub(7) / 2).max(1) as usize;
+    let offset = form.selected.saturating_sub(visible - 1);
+    let title = if form.fields.len() > visible {
+        format!(
+            "<{}> [{}-{}/{}]",
+            form.title,
+            offset + 1,
+            (offset + visible).min(form.fields.len()),
+            form.fields.len()
+        )
+    } else {
+        format!("<{}>", form.title)
+    };
+
+    let mut text = vec![
+        Line::from(Span::styled(
+            title,
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+    ];
+
+    if form.fields.is_empty() {
+        text.push(Line::from(Span::styled(
+            "No parameters",
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+
+    for (i, field) in form.fields.iter().enumerate().skip(offset).take(visible) {
+        let selected = i == form.selected;
+        let label_style = if selected {
+            Style::default()
+                .fg(Color::Black)
+                .bg(Color::Magenta)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::Cyan)
+        };
+        let required = if field.required { "*" } else { "" };
+        let cursor = if selected { "_" } else { "" };
+
+        text.push(Line::from(vec![
+            Span::styled(format!(" {}{} ", field.name, required), label_style),
+            Span::raw(" "),
+            Span::styled(
+                format!("{}{}", field.value, cursor),
+                Style::default().fg(Color::White),
+            ),
+        ]));
+        text.push(Line::from(Span::styled(
+            format!("   {} {}", field.kind, field.hint),
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+
+    text.push(Line::from(""));
+    if let Some(error) = &app.error_message {
+        text.push(Line::from(Span::styled(
+            error.as_str(),
+            Style::default().fg(Color::Red),
+        )));
+    }
+    text.push(Line::from(Span::styled(
+        "<Tab/↑↓> field  <Enter> submit  <Esc> cancel",
+        Style::default().fg(Color::DarkGray),
+    )));
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::DarkGray));
+
+    let paragraph = Paragraph::new(text).block(block).wrap(Wrap { trim: false });
+
+    f.render_widget(paragraph, area);
+}
+
 fn render_sso_dialog(f: &mut Frame, app: &App) {
     let Some(ref sso_state) = app.sso_state else {
         return;
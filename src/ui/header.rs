@@ -66,6 +66,23 @@ fn render_context_column(f: &mut Frame, app: &App, area: Rect) {
         ]),
     ];
 
+    // Show the named context while its profile and region are in effect
+    if let Some(context) = app.active_context() {
+        lines.insert(
+            0,
+            Line::from(vec![
+                Span::styled("Ctx:    ", Style::default().fg(Color::DarkGray)),
+                Span::raw(" "),
+                Span::styled(
+                    context.name.clone(),
+                    Style::default()
+                        .fg(Color::Green)
+                        .add_modifier(Modifier::BOLD),
+                ),
+            ]),
+        );
+    }
+
     // Show parent context if navigating
     if let Some(parent) = &app.parent_context {
         lines.push(Line::from(vec![
@@ -75,6 +92,19 @@ fn render_context_column(f: &mut Frame, app: &App, area: Rect) {
         ]));
     }
 
+    // Show redaction indicator so presenters know values are masked
+    if app.redacted {
+        lines.push(Line::from(vec![
+            Span::styled("Privacy: ", Style::default().fg(Color::DarkGray)),
+            Span::styled(
+                "REDACTED",
+                Style::default()
+                    .fg(Color::Green)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]));
+    }
+
     // Show read-only mode indicator
     if app.readonly {
         lines.push(Line::from(vec![
@@ -88,6 +118,28 @@ fn render_context_column(f: &mut Frame, app: &App, area: Rect) {
         ]));
     }
 
+    // Show SSM Change Calendar state
+    if let Some(calendar) = app.change_calendar.as_ref().filter(|c| c.state != "-") {
+        let color = if calendar.state == "CLOSED" {
+            Color::Red
+        } else {
+            Color::Green
+        };
+        let label = if calendar.state == "CLOSED" {
+            "CLOSED (freeze)".to_string()
+        } else {
+            calendar.state.clone()
+        };
+        lines.push(Line::from(vec![
+            Span::styled("Calendar:", Style::default().fg(Color::DarkGray)),
+            Span::raw(" "),
+            Span::styled(
+                label,
+                Style::default().fg(color).add_modifier(Modifier::BOLD),
+            ),
+        ]));
+    }
+
     // Show custom endpoint indicator
     if app.endpoint_url.is_some() {
         lines.push(Line::from(vec![
@@ -207,31 +259,50 @@ fn render_subresource_shortcuts(
 
 fn render_keybindings_col1(f: &mut Frame, app: &App, area: Rect) {
     // Show resource-specific actions or generic bindings
-    let bindings: Vec<(String, String)> = if let Some(resource) = app.current_resource() {
-        let mut b: Vec<(String, String)> = vec![("<d>".to_string(), "Describe".to_string())];
+    // (key, description, denied by the permission preflight)
+    let bindings: Vec<(String, String, bool)> = if let Some(resource) = app.current_resource() {
+        let mut b = vec![("<d>".to_string(), "Describe".to_string(), false)];
 
         // Add resource-specific actions
         for action in resource.actions.iter().take(4) {
             if let Some(ref shortcut) = action.shortcut {
-                b.push((format!("<{}>", shortcut), action.display_name.clone()));
+                b.push((
+                    format!("<{}>", shortcut),
+                    action.display_name.clone(),
+                    app.denied_permission(resource, action).is_some(),
+                ));
             }
         }
 
-        b.push(("<?>".to_string(), "Help".to_string()));
+        b.push(("<?>".to_string(), "Help".to_string(), false));
         b
     } else {
         vec![
-            ("<d>".to_string(), "Describe".to_string()),
-            ("<?>".to_string(), "Help".to_string()),
+            ("<d>".to_string(), "Describe".to_string(), false),
+            ("<?>".to_string(), "Help".to_string(), false),
         ]
     };
 
     let lines: Vec<Line> = bindings
         .iter()
-        .map(|(key, desc)| {
+        .map(|(key, desc, denied)| {
+            let key_style = if *denied {
+                Style::default()
+                    .fg(Color::DarkGray)
+                    .add_modifier(Modifier::CROSSED_OUT)
+            } else {
+                Style::default().fg(Color::Yellow)
+            };
+            let desc_style = if *denied {
+                Style::default()
+                    .fg(Color::DarkGray)
+                    .add_modifier(Modifier::CROSSED_OUT)
+            } else {
+                Style::default().fg(Color::DarkGray)
+            };
             Line::from(vec![
-                Span::styled(format!("{:<9}", key), Style::default().fg(Color::Yellow)),
-                Span::styled(desc.clone(), Style::default().fg(Color::DarkGray)),
+                Span::styled(format!("{:<9}", key), key_style),
+                Span::styled(desc.clone(), desc_style),
             ])
         })
         .collect();
@@ -246,6 +317,7 @@ fn render_keybindings_col2(f: &mut Frame, app: &App, area: Rect) {
     // Add pagination shortcuts if available
     if app.pagination.has_more {
         bindings.push(("<]>", "Next Page"));
+        bindings.push(("<}>", "Fetch All"));
     }
     if app.pagination.current_page > 1 {
         bindings.push(("<[>", "Prev Page"));
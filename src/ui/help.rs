@@ -21,7 +21,11 @@ pub fn render(f: &mut Frame, app: &App) {
             help_text.push(create_section(&section_title));
             for action in &resource.actions {
                 let shortcut = action.shortcut.as_deref().unwrap_or(&action.key);
-                help_text.push(create_key_line(shortcut, &action.display_name));
+                if app.denied_permission(resource, action).is_some() {
+                    help_text.push(create_denied_key_line(shortcut, &action.display_name));
+                } else {
+                    help_text.push(create_key_line(shortcut, &action.display_name));
+                }
             }
             help_text.push(Line::from(""));
         }
@@ -45,6 +49,8 @@ pub fn render(f: &mut Frame, app: &App) {
             create_key_line("G", "Go to bottom (live mode)"),
             create_key_line("g", "Go to top"),
             create_key_line("SPACE", "Pause/resume"),
+            create_key_line("P", "Pin stream to incident dashboard"),
+            create_key_line("|", "Open in pager ($PAGER)"),
             create_key_line("q / Esc", "Exit log tail"),
             Line::from(""),
         ]);
@@ -61,18 +67,32 @@ pub fn render(f: &mut Frame, app: &App) {
         create_key_line("PgDn / Ctrl+f", "Page down"),
         create_key_line("]", "Next page (load more)"),
         create_key_line("[", "Previous page"),
+        create_key_line("}", "Fetch all remaining pages"),
         create_key_line("R", "Refresh list"),
+        create_key_line("SPACE", "Mark item for bulk actions"),
+        create_key_line("P", "Pin view to incident dashboard"),
         Line::from(""),
         create_section("Views"),
         create_key_line("d / Enter", "Show details panel"),
+        create_key_line("e / |", "Details: open in $EDITOR / $PAGER"),
+        create_key_line("Tab / Enter", "Details: next link / open it"),
         create_key_line("J", "Show JSON view"),
+        create_key_line("v", "Expand cell (h/l column, y copy)"),
+        create_key_line("C", "Columns (show/hide, width, sort)"),
         create_key_line("?", "Toggle help"),
         Line::from(""),
         create_section("General"),
         create_key_line("/", "Filter / Search"),
+        create_key_line("Ctrl+p", "Pick row with external fuzzy finder"),
         create_key_line(":", "Command mode"),
         create_key_line(":profiles", "Switch AWS profile"),
         create_key_line(":regions", "Switch AWS region"),
+        create_key_line(":ctx [name]", "Switch named context"),
+        create_key_line(":incident", "Open incident dashboard"),
+        create_key_line("Ctrl+t", "New tab (own profile/region)"),
+        create_key_line("Tab / Shift+Tab", "Next / previous tab"),
+        create_key_line("Ctrl+w", "Close tab"),
+        create_key_line("Ctrl+r", "Toggle redaction (screen sharing)"),
         create_key_line("Backspace", "Go back"),
         create_key_line("Esc", "Close / Cancel"),
         create_key_line("Ctrl+c", "Quit"),
@@ -116,6 +136,19 @@ fn create_key_line(key: &str, description: &str) -> Line<'static> {
     ])
 }
 
+/// Key line for an action the IAM policy simulator denied
+fn create_denied_key_line(key: &str, description: &str) -> Line<'static> {
+    let style = Style::default()
+        .fg(Color::DarkGray)
+        .add_modifier(Modifier::CROSSED_OUT);
+    Line::from(vec![
+        Span::raw("    "),
+        Span::styled(format!("{:>15}", key), style),
+        Span::raw("  "),
+        Span::styled(format!("{} (denied)", description), style),
+    ])
+}
+
 fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
     let popup_layout = Layout::default()
         .direction(Direction::Vertical)
@@ -0,0 +1,252 @@
+use super::{format_cell_value, get_cell_style};
+use crate::app::{App, IncidentPanelState};
+use crate::config::IncidentPanel;
+use crate::humanize::Formatter;
+use crate::resource::{extract_json_value, get_resource};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Sparkline, Table},
+    Frame,
+};
+
+/// Panels per dashboard row
+const PANELS_PER_ROW: usize = 2;
+
+pub fn render(f: &mut Frame, app: &App, area: Rect) {
+    let state = &app.incident;
+    let title = match &state.layout_name {
+        Some(name) => format!(" Incident: {}[{}] ", name, state.panels.len()),
+        None => format!(" Incident[{}] ", state.panels.len()),
+    };
+
+    if state.panels.is_empty() {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::DarkGray))
+            .title(Span::styled(
+                title,
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            ))
+            .title_alignment(Alignment::Center);
+        let msg = Paragraph::new(
+            "Nothing pinned. Press P on a list or log tail, or m on an alarm, to pin it here.",
+        )
+        .style(Style::default().fg(Color::DarkGray))
+        .block(block);
+        f.render_widget(msg, area);
+        return;
+    }
+
+    let formatter = Formatter::from(&app.config.formatting);
+    let row_count = state.panels.len().div_ceil(PANELS_PER_ROW);
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(vec![Constraint::Ratio(1, row_count as u32); row_count])
+        .split(area);
+
+    for (row_index, row_area) in rows.iter().enumerate() {
+        let start = row_index * PANELS_PER_ROW;
+        let panels = &state.panels[start..(start + PANELS_PER_ROW).min(state.panels.len())];
+        let cells = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(vec![
+                Constraint::Ratio(1, panels.len() as u32);
+                panels.len()
+            ])
+            .split(*row_area);
+        for (offset, panel) in panels.iter().enumerate() {
+            render_panel(
+                f,
+                panel,
+                start + offset == state.focused,
+                &formatter,
+                cells[offset],
+            );
+        }
+    }
+}
+
+fn render_panel(
+    f: &mut Frame,
+    panel: &IncidentPanelState,
+    focused: bool,
+    formatter: &Formatter,
+    area: Rect,
+) {
+    let border_color = if focused {
+        Color::Cyan
+    } else {
+        Color::DarkGray
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(border_color))
+        .title(Span::styled(
+            format!(" {} ", panel.panel.title()),
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        ));
+    let inner_area = block.inner(area);
+    f.render_widget(block, area);
+
+    if let Some(err) = &panel.error {
+        let msg = Paragraph::new(format!("Error: {}", err)).style(Style::default().fg(Color::Red));
+        f.render_widget(msg, inner_area);
+        return;
+    }
+
+    match &panel.panel {
+        IncidentPanel::Resource { resource_key, .. } => {
+            render_resource_panel(f, panel, resource_key, formatter, inner_area)
+        }
+        IncidentPanel::Logs { .. } => render_logs_panel(f, panel, inner_area),
+        IncidentPanel::Metric { .. } => render_metric_panel(f, panel, inner_area),
+    }
+}
+
+fn render_resource_panel(
+    f: &mut Frame,
+    panel: &IncidentPanelState,
+    resource_key: &str,
+    formatter: &Formatter,
+    area: Rect,
+) {
+    let Some(resource) = get_resource(resource_key) else {
+        let msg = Paragraph::new(format!("Unknown resource: {}", resource_key))
+            .style(Style::default().fg(Color::Red));
+        f.render_widget(msg, area);
+        return;
+    };
+    if panel.items.is_empty() {
+        let msg = Paragraph::new("No items").style(Style::default().fg(Color::DarkGray));
+        f.render_widget(msg, area);
+        return;
+    }
+
+    let header = Row::new(resource.columns.iter().map(|col| {
+        Cell::from(format!(" {}", formatter.header(&col.header, col.format))).style(
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )
+    }));
+    let rows = panel.items.iter().map(|item| {
+        Row::new(resource.columns.iter().map(|col| {
+            let value = extract_json_value(item, &col.json_path);
+            Cell::from(format!(" {}", format_cell_value(&value, col, formatter)))
+                .style(get_cell_style(&value, col))
+        }))
+    });
+    let widths: Vec<Constraint> = resource
+        .columns
+        .iter()
+        .map(|col| Constraint::Percentage(col.width))
+        .collect();
+
+    f.render_widget(Table::new(rows, widths).header(header), area);
+}
+
+fn render_logs_panel(f: &mut Frame, panel: &IncidentPanelState, area: Rect) {
+    if panel.log_events.is_empty() {
+        let msg =
+            Paragraph::new("Waiting for log events...").style(Style::default().fg(Color::DarkGray));
+        f.render_widget(msg, area);
+        return;
+    }
+
+    // Newest events at the bottom, like the log tail view
+    let visible = area.height as usize;
+    let skip = panel.log_events.len().saturating_sub(visible);
+    let lines: Vec<Line> = panel.log_events[skip..]
+        .iter()
+        .map(|event| {
+            let style = if event.message.contains("ERROR") || event.message.contains("error") {
+                Style::default().fg(Color::Red)
+            } else if event.message.contains("WARN") || event.message.contains("warn") {
+                Style::default().fg(Color::Yellow)
+            } else {
+                Style::default().fg(Color::White)
+            };
+            Line::from(vec![
+                Span::styled(
+                    format!(
+                        "{} ",
+                        crate::resource::format_log_timestamp(event.timestamp)
+                    ),
+                    Style::default().fg(Color::DarkGray),
+                ),
+                Span::styled(event.message.trim_end().to_string(), style),
+            ])
+        })
+        .collect();
+    f.render_widget(Paragraph::new(lines), area);
+}
+
+fn render_metric_panel(f: &mut Frame, panel: &IncidentPanelState, area: Rect) {
+    let Some(latest) = panel.values.last() else {
+        let msg = Paragraph::new("No datapoints in the last hour")
+            .style(Style::default().fg(Color::DarkGray));
+        f.render_widget(msg, area);
+        return;
+    };
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(1), Constraint::Min(1)])
+        .split(area);
+
+    let min = panel.values.iter().cloned().fold(f64::INFINITY, f64::min);
+    let max = panel
+        .values
+        .iter()
+        .cloned()
+        .fold(f64::NEG_INFINITY, f64::max);
+    let summary = Line::from(vec![
+        Span::styled(
+            format!("latest {:.2}", latest),
+            Style::default()
+                .fg(Color::Green)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            format!("  min {:.2}  max {:.2}  (1h, 1m)", min, max),
+            Style::default().fg(Color::DarkGray),
+        ),
+    ]);
+    f.render_widget(Paragraph::new(summary), chunks[0]);
+
+    // Only the most recent points that fit the panel width are drawn
+    let skip = panel.values.len().saturating_sub(chunks[1].width as usize);
+    let data = sparkline_data(&panel.values[skip..]);
+    let sparkline = Sparkline::default()
+        .data(&data)
+        .style(Style::default().fg(Color::Cyan));
+    f.render_widget(sparkline, chunks[1]);
+}
+
+/// Scale metric values to sparkline bars (the sparkline needs non-negative integers)
+fn sparkline_data(values: &[f64]) -> Vec<u64> {
+    let min = values
+        .iter()
+        .cloned()
+        .fold(f64::INFINITY, f64::min)
+        .min(0.0);
+    values
+        .iter()
+        .map(|v| ((v - min) * 100.0).round() as u64)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::sparkline_data;
+
+    #[test]
+    fn sparkline_data_scales_and_shifts_negative_values() {
+        assert_eq!(sparkline_data(&[0.5, 1.0, 2.25]), vec![50, 100, 225]);
+        assert_eq!(sparkline_data(&[-1.0, 0.0, 1.0]), vec![0, 100, 200]);
+    }
+}
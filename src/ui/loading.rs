@@ -0,0 +1,215 @@
+//! Frames drawn while a fetch or form action is in flight: the rows loaded so
+//! far and skeleton rows where the table will be, and a spinner with the count
+
+use crate::app::FetchProgress;
+use crate::redact::RedactClasses;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+    Frame,
+};
+
+const SPINNER: [&str; 4] = ["⠋", "⠙", "⠹", "⠸"];
+
+/// Draw the fetch over the last full frame, which is already in the buffer
+pub fn render(f: &mut Frame, progress: &FetchProgress, redact_classes: Option<RedactClasses>) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(6), // Header (multi-line)
+            Constraint::Min(1),    // Main content (table or describe)
+            Constraint::Length(1), // Footer/crumb
+        ])
+        .split(f.area());
+
+    if progress.skeleton {
+        let content = chunks[1];
+        let offset = progress.table_offset.min(content.height.saturating_sub(1));
+        let table_area = Rect {
+            y: content.y + offset,
+            height: content.height - offset,
+            ..content
+        };
+        render_table(f, progress, redact_classes, table_area);
+    }
+
+    let crumb = Line::from(vec![
+        Span::styled(
+            format!("<{}>", progress.crumb),
+            Style::default().fg(Color::Black).bg(Color::Cyan),
+        ),
+        Span::raw(" "),
+        Span::styled(status_text(progress), Style::default().fg(Color::Yellow)),
+    ]);
+    f.render_widget(Paragraph::new(crumb), chunks[2]);
+}
+
+fn spinner(progress: &FetchProgress) -> &'static str {
+    let frame = progress.started.elapsed().as_millis() / 100;
+    SPINNER[frame as usize % SPINNER.len()]
+}
+
+/// "⠋ Loading EC2 Instances · 120 loaded · 3.2s"
+fn status_text(progress: &FetchProgress) -> String {
+    let loaded = progress
+        .loaded
+        .map(|loaded| format!(" · {} loaded", loaded))
+        .unwrap_or_default();
+    format!(
+        "{} {}{} · {:.1}s",
+        spinner(progress),
+        progress.activity,
+        loaded,
+        progress.started.elapsed().as_secs_f64()
+    )
+}
+
+fn render_table(
+    f: &mut Frame,
+    progress: &FetchProgress,
+    redact_classes: Option<RedactClasses>,
+    area: Rect,
+) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::DarkGray))
+        .title(Span::styled(
+            format!(" {} {} ", progress.resource, spinner(progress)),
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        ))
+        .title_alignment(Alignment::Center);
+    let inner_area = block.inner(area);
+    f.render_widget(block, area);
+
+    let total_width = inner_area.width.saturating_sub(2) as usize;
+    let column_widths: Vec<usize> = progress
+        .columns
+        .iter()
+        .map(|col| (total_width * col.width as usize) / 100)
+        .collect();
+
+    let header = Row::new(progress.columns.iter().map(|col| {
+        Cell::from(format!(
+            " {}",
+            progress.formatter.header(&col.header, col.format)
+        ))
+        .style(
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )
+    }))
+    .height(1);
+
+    // Loaded rows first, then placeholders for the pages still coming
+    let loaded = progress.rows.iter().enumerate().map(|(row, item)| {
+        Row::new(progress.columns.iter().enumerate().map(|(col_idx, col)| {
+            let value = crate::resource::extract_json_value(item, &col.json_path);
+            let mut style = super::get_cell_style(&value, col);
+            if progress.selected == Some(row) {
+                style = style.fg(Color::White);
+            }
+            let col_width = column_widths.get(col_idx).copied().unwrap_or(40);
+            let text = super::cell_text(item, col, &progress.formatter, redact_classes, col_width);
+            Cell::from(format!(" {}", text)).style(style)
+        }))
+    });
+    let skeleton_rows =
+        (inner_area.height.saturating_sub(1) as usize).saturating_sub(progress.rows.len());
+    let skeleton = (0..skeleton_rows).map(|row| {
+        Row::new(column_widths.iter().enumerate().map(|(col, width)| {
+            Cell::from(format!(" {}", "░".repeat(skeleton_width(row, col, *width))))
+                .style(Style::default().fg(Color::DarkGray))
+        }))
+    });
+
+    let widths: Vec<Constraint> = progress
+        .columns
+        .iter()
+        .map(|col| Constraint::Percentage(col.width))
+        .collect();
+    let table = Table::new(loaded.chain(skeleton), widths)
+        .header(header)
+        .row_highlight_style(
+            Style::default()
+                .bg(Color::DarkGray)
+                .add_modifier(Modifier::BOLD),
+        );
+    let mut state = TableState::default();
+    state.select(progress.selected);
+    f.render_stateful_widget(table, inner_area, &mut state);
+}
+
+/// Length of a placeholder bar: varied per cell so the rows read as data,
+/// and always short of the column so neighbours stay apart
+fn skeleton_width(row: usize, col: usize, column_width: usize) -> usize {
+    let room = column_width.saturating_sub(3);
+    if room == 0 {
+        return 0;
+    }
+    let share = 40 + (row * 37 + col * 23) % 60;
+    (room * share / 100).max(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_skeleton_width_stays_inside_column() {
+        for row in 0..20 {
+            for col in 0..6 {
+                let width = skeleton_width(row, col, 20);
+                assert!((1..=17).contains(&width), "{} at {},{}", width, row, col);
+            }
+        }
+        assert_eq!(skeleton_width(0, 0, 3), 0);
+        assert_ne!(skeleton_width(0, 0, 30), skeleton_width(1, 0, 30));
+    }
+
+    #[test]
+    fn test_streamed_rows_above_skeleton() {
+        let column = |header: &str, width: u16| crate::resource::ColumnDef {
+            header: header.to_string(),
+            json_path: header.to_lowercase(),
+            width,
+            color_map: None,
+            format: None,
+        };
+        let progress = FetchProgress {
+            activity: "Loading EC2 Instances".to_string(),
+            resource: "EC2 Instances".to_string(),
+            columns: vec![column("NAME", 50), column("STATE", 50)],
+            formatter: (&crate::config::FormattingConfig::default()).into(),
+            crumb: "ec2-instances".to_string(),
+            table_offset: 0,
+            skeleton: true,
+            rows: vec![
+                serde_json::json!({"name": "web-1", "state": "running"}),
+                serde_json::json!({"name": "web-2", "state": "stopped"}),
+            ],
+            selected: Some(0),
+            loaded: Some(2),
+            started: std::time::Instant::now(),
+        };
+        let backend = ratatui::backend::TestBackend::new(60, 16);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        terminal.draw(|f| render(f, &progress, None)).unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let line = |y: u16| -> String {
+            (0..buffer.area.width)
+                .map(|x| buffer[(x, y)].symbol())
+                .collect()
+        };
+        // Header area (6) + border (1) + column header (1), then the rows
+        assert!(line(8).contains("web-1"), "{}", line(8));
+        assert!(line(9).contains("web-2"), "{}", line(9));
+        assert!(line(10).contains('░'), "{}", line(10));
+        assert!(line(15).contains("2 loaded"), "{}", line(15));
+    }
+}
@@ -1,14 +1,23 @@
+mod cell_view;
+pub mod charset;
+mod columns;
 mod command_box;
+mod contexts;
 mod dialog;
 mod header;
 mod help;
 mod highlight;
+mod incident;
+pub mod loading;
 mod profiles;
 mod regions;
 pub mod splash;
 
 use crate::app::{App, Mode};
-use crate::resource::{extract_json_value, get_color_for_value, ColumnDef};
+use crate::humanize::{Formatter, ValueFormat};
+use crate::resource::{
+    cell_error, extract_json_value, get_color_for_value, ColumnDef, ResourceRef,
+};
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
@@ -33,22 +42,39 @@ pub fn render(f: &mut Frame, app: &App) {
     // Header - multi-line with context info
     header::render(f, app, chunks[0]);
 
+    // Tab bar above the main content once more than one tab is open
+    let mut content_area = chunks[1];
+    if app.tabs.len() > 1 {
+        let split = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(1), Constraint::Min(1)])
+            .split(chunks[1]);
+        render_tab_bar(f, app, split[0]);
+        content_area = split[1];
+    }
+
     // Main content - depends on mode and view
     match app.mode {
         Mode::Profiles => {
-            profiles::render(f, app, chunks[1]);
+            profiles::render(f, app, content_area);
         }
         Mode::Regions => {
-            regions::render(f, app, chunks[1]);
+            regions::render(f, app, content_area);
+        }
+        Mode::Contexts => {
+            contexts::render(f, app, content_area);
         }
         Mode::Describe => {
-            render_describe_view(f, app, chunks[1]);
+            render_describe_view(f, app, content_area);
         }
         Mode::LogTail => {
-            render_log_tail_view(f, app, chunks[1]);
+            render_log_tail_view(f, app, content_area);
+        }
+        Mode::Incident => {
+            incident::render(f, app, content_area);
         }
         _ => {
-            render_main_content(f, app, chunks[1]);
+            render_main_content(f, app, content_area);
         }
     }
 
@@ -60,14 +86,46 @@ pub fn render(f: &mut Frame, app: &App) {
         Mode::Help => {
             help::render(f, app);
         }
-        Mode::Confirm | Mode::Warning | Mode::SsoLogin | Mode::ConsoleLogin => {
+        Mode::Confirm | Mode::Warning | Mode::SsoLogin | Mode::ConsoleLogin | Mode::Form => {
             dialog::render(f, app);
         }
         Mode::Command => {
             command_box::render(f, app);
         }
+        Mode::CellView => {
+            cell_view::render(f, app);
+        }
+        Mode::Columns => {
+            columns::render(f, app);
+        }
         _ => {}
     }
+
+    // Mask sensitive values in everything drawn above
+    if app.redacted {
+        crate::redact::redact_buffer(f.buffer_mut(), (&app.config.redaction).into());
+    }
+    charset::apply(f.buffer_mut());
+}
+
+fn render_tab_bar(f: &mut Frame, app: &App, area: Rect) {
+    let mut spans = Vec::new();
+    for (index, (profile, region)) in app.tab_labels().iter().enumerate() {
+        let style = if index == app.active_tab {
+            Style::default()
+                .fg(Color::Black)
+                .bg(Color::Cyan)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::DarkGray)
+        };
+        spans.push(Span::styled(
+            format!(" {}:{}/{} ", index + 1, profile, region),
+            style,
+        ));
+        spans.push(Span::raw(" "));
+    }
+    f.render_widget(Paragraph::new(Line::from(spans)), area);
 }
 
 fn render_main_content(f: &mut Frame, app: &App, area: Rect) {
@@ -174,15 +232,21 @@ fn render_dynamic_table(f: &mut Frame, app: &App, area: Rect) {
         let is_global = resource.is_global;
 
         // Build pagination indicator
-        let page_info = if app.pagination.has_more || app.pagination.current_page > 1 {
+        let page_info = if app.pagination.has_more {
             format!(
-                " pg.{}{}",
-                app.pagination.current_page,
-                if app.pagination.has_more { "+" } else { "" }
+                " pg.{} · {} loaded / more available",
+                app.pagination.current_page, total
             )
+        } else if app.pagination.current_page > 1 {
+            format!(" pg.{}", app.pagination.current_page)
         } else {
             String::new()
         };
+        let page_info = if app.marked_ids.is_empty() {
+            page_info
+        } else {
+            format!("{} ✓{}", page_info, app.marked_ids.len())
+        };
 
         if is_global {
             if query.is_empty() {
@@ -224,15 +288,27 @@ fn render_dynamic_table(f: &mut Frame, app: &App, area: Rect) {
     // Calculate actual column widths in characters based on inner area and percentages
     // Note: inner_area.width is already the usable width inside the border
     let total_width = inner_area.width.saturating_sub(2) as usize; // subtract for table borders
-    let column_widths: Vec<usize> = resource
-        .columns
+    let columns = app.visible_columns();
+    let sort = app.current_view().sort;
+    let column_widths: Vec<usize> = columns
         .iter()
         .map(|col| (total_width * col.width as usize) / 100)
         .collect();
 
     // Build header from column definitions with left padding
-    let header_cells = resource.columns.iter().map(|col| {
-        Cell::from(format!(" {}", col.header)).style(
+    let formatter = Formatter::from(&app.config.formatting);
+    let header_cells = columns.iter().map(|col| {
+        let indicator = match &sort {
+            Some(sort) if sort.column == col.header && sort.descending => "↓",
+            Some(sort) if sort.column == col.header => "↑",
+            _ => "",
+        };
+        Cell::from(format!(
+            " {}{}",
+            formatter.header(&col.header, col.format),
+            indicator
+        ))
+        .style(
             Style::default()
                 .fg(Color::Yellow)
                 .add_modifier(Modifier::BOLD),
@@ -242,6 +318,7 @@ fn render_dynamic_table(f: &mut Frame, app: &App, area: Rect) {
 
     // Build rows from filtered items with left padding
     let selected_row = app.selected;
+    let redact_classes = app.redact_classes();
     let column_widths_clone = column_widths.clone();
     let rows = app
         .filtered_items
@@ -249,27 +326,36 @@ fn render_dynamic_table(f: &mut Frame, app: &App, area: Rect) {
         .enumerate()
         .map(|(row_index, item)| {
             let is_selected = row_index == selected_row;
-            let cells = resource.columns.iter().enumerate().map(|(col_idx, col)| {
+            let is_marked = app.is_marked(item);
+            let row_color = resource
+                .row_color
+                .as_ref()
+                .and_then(|rc| {
+                    get_color_for_value(&rc.color_map, &extract_json_value(item, &rc.json_path))
+                })
+                .or_else(|| {
+                    let field = resource.expiry_field.as_ref()?;
+                    let days = extract_json_value(item, field).parse::<i64>().ok()?;
+                    app.config.expiry_warning.color_for(days)
+                });
+            let cells = columns.iter().enumerate().map(|(col_idx, col)| {
                 let value = extract_json_value(item, &col.json_path);
                 let mut style = get_cell_style(&value, col);
+                if let Some([r, g, b]) = row_color {
+                    style = style.fg(Color::Rgb(r, g, b));
+                }
+                if is_marked {
+                    style = style.fg(Color::Magenta).add_modifier(Modifier::BOLD);
+                }
                 if is_selected {
                     style = style.fg(Color::White);
                 }
-                let display_value = format_cell_value(&value, col);
-                // Truncate from beginning to show the end (more meaningful for paths/names)
-                // The column width from percentage doesn't account for inter-column spacing,
-                // so we use 80% of calculated width to be safe
+                // The lookup behind this cell failed; `v` shows why
+                if cell_error(item, &col.json_path).is_some() {
+                    style = style.fg(Color::Red).add_modifier(Modifier::ITALIC);
+                }
                 let col_width = column_widths_clone.get(col_idx).copied().unwrap_or(40);
-                let usable_width = (col_width * 80) / 100;
-                let display_value = if display_value.chars().count() > usable_width {
-                    let chars: Vec<char> = display_value.chars().collect();
-                    let keep_chars = usable_width.saturating_sub(3); // 3 for "..."
-                    let start = chars.len().saturating_sub(keep_chars);
-                    let truncated: String = chars[start..].iter().collect();
-                    format!("...{}", truncated)
-                } else {
-                    display_value
-                };
+                let display_value = cell_text(item, col, &formatter, redact_classes, col_width);
 
                 if highlight_filter_matches
                     && (col.json_path == resource.name_field || col.json_path == resource.id_field)
@@ -292,8 +378,7 @@ fn render_dynamic_table(f: &mut Frame, app: &App, area: Rect) {
         });
 
     // Build column widths
-    let widths: Vec<Constraint> = resource
-        .columns
+    let widths: Vec<Constraint> = columns
         .iter()
         .map(|col| Constraint::Percentage(col.width))
         .collect();
@@ -311,7 +396,7 @@ fn render_dynamic_table(f: &mut Frame, app: &App, area: Rect) {
 }
 
 /// Get cell style based on value and column definition
-fn get_cell_style(value: &str, col: &ColumnDef) -> Style {
+pub(super) fn get_cell_style(value: &str, col: &ColumnDef) -> Style {
     if let Some(ref color_map_name) = col.color_map {
         if let Some([r, g, b]) = get_color_for_value(color_map_name, value) {
             return Style::default().fg(Color::Rgb(r, g, b));
@@ -320,8 +405,45 @@ fn get_cell_style(value: &str, col: &ColumnDef) -> Style {
     Style::default()
 }
 
-/// Format cell value, adding indicators for transitional states
-fn format_cell_value(value: &str, col: &ColumnDef) -> String {
+/// Format cell value, humanizing numbers and adding indicators for transitional states
+/// Text shown in a table cell: formatted, masked while still whole, then
+/// truncated to fit `col_width`
+pub(super) fn cell_text(
+    item: &serde_json::Value,
+    col: &ColumnDef,
+    formatter: &Formatter,
+    redact_classes: Option<crate::redact::RedactClasses>,
+    col_width: usize,
+) -> String {
+    let display_value = if cell_error(item, &col.json_path).is_some() {
+        "error".to_string()
+    } else {
+        format_cell_value(&extract_json_value(item, &col.json_path), col, formatter)
+    };
+    // Mask before truncating, while values are still whole
+    let display_value = match redact_classes {
+        Some(classes) => crate::redact::redact_text(&display_value, classes),
+        None => display_value,
+    };
+    // Truncate from beginning to show the end (more meaningful for paths/names)
+    // The column width from percentage doesn't account for inter-column spacing,
+    // so we use 80% of calculated width to be safe
+    let usable_width = (col_width * 80) / 100;
+    if display_value.chars().count() > usable_width {
+        let chars: Vec<char> = display_value.chars().collect();
+        let keep_chars = usable_width.saturating_sub(3); // 3 for "..."
+        let start = chars.len().saturating_sub(keep_chars);
+        let truncated: String = chars[start..].iter().collect();
+        format!("...{}", truncated)
+    } else {
+        display_value
+    }
+}
+
+fn format_cell_value(value: &str, col: &ColumnDef, formatter: &Formatter) -> String {
+    if let Some(format) = col.format {
+        return formatter.format(value, format);
+    }
     // Check if this is a state/status column with transitional states
     if col.color_map.is_some() {
         let lower = value.to_lowercase();
@@ -358,7 +480,7 @@ fn render_describe_view(f: &mut Frame, app: &App, area: Rect) {
         .selected_item_json()
         .unwrap_or_else(|| "No item selected".to_string());
 
-    let title = if let Some(resource) = app.current_resource() {
+    let mut title = if let Some(resource) = app.current_resource() {
         describe_title(
             &resource.display_name,
             app.last_action_display_name.as_deref(),
@@ -366,6 +488,18 @@ fn render_describe_view(f: &mut Frame, app: &App, area: Rect) {
     } else {
         " Details ".to_string()
     };
+    if !app.describe_masked_fields.is_empty() {
+        let toggle = if app.describe_revealed {
+            "hide"
+        } else {
+            "reveal"
+        };
+        title.push_str(&format!("[v] {} ", toggle));
+    }
+    let references = app.describe_references();
+    if !references.is_empty() {
+        title.push_str(&format!("[Tab] {} links ", references.len()));
+    }
 
     let block = Block::default()
         .borders(Borders::ALL)
@@ -392,8 +526,25 @@ fn render_describe_view(f: &mut Frame, app: &App, area: Rect) {
         (inner_area, None)
     };
 
+    // Fields shown humanized in the list get their humanized value as a hint
+    let formatter = Formatter::from(&app.config.formatting);
+    let formatted_fields: Vec<(&str, ValueFormat)> = app
+        .current_resource()
+        .map(|resource| {
+            resource
+                .columns
+                .iter()
+                .filter_map(|col| {
+                    let field = col.json_path.rsplit('.').next()?;
+                    Some((field, col.format?))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
     // Apply JSON syntax highlighting with search match highlighting
     let search_text = &app.describe_search_text;
+    let redact_classes = app.redact_classes();
     let lines: Vec<Line> = json
         .lines()
         .enumerate()
@@ -403,7 +554,20 @@ fn render_describe_view(f: &mut Frame, app: &App, area: Rect) {
                 .get(app.describe_current_match)
                 .map(|&m| m == line_num)
                 .unwrap_or(false);
-            highlight_json_line_with_search(line, search_text, is_current_match)
+            let mut highlighted =
+                highlight_json_line_with_search(line, search_text, is_current_match);
+            underline_references(&mut highlighted, line_num, &references, app);
+            if let Some(hint) = formatter.json_line_hint(line, &formatted_fields) {
+                highlighted.spans.push(Span::styled(
+                    format!("  ({})", hint),
+                    Style::default().fg(Color::DarkGray),
+                ));
+            }
+            // Mask before wrapping, while values are still on one line
+            if let Some(classes) = redact_classes {
+                crate::redact::redact_line(&mut highlighted, classes);
+            }
+            highlighted
         })
         .collect();
     let total_lines = lines.len();
@@ -424,6 +588,27 @@ fn render_describe_view(f: &mut Frame, app: &App, area: Rect) {
     }
 }
 
+/// Underline string values that link to other resources; the highlighted one stands out
+fn underline_references(line: &mut Line, line_num: usize, references: &[ResourceRef], app: &App) {
+    for (index, reference) in references.iter().enumerate() {
+        if reference.line != line_num {
+            continue;
+        }
+        let quoted = format!("\"{}\"", reference.value);
+        let style = if app.describe_reference_selected == Some(index) {
+            Style::default()
+                .bg(Color::Blue)
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().add_modifier(Modifier::UNDERLINED)
+        };
+        for span in line.spans.iter_mut().filter(|s| s.content == quoted) {
+            span.style = span.style.patch(style);
+        }
+    }
+}
+
 fn render_describe_search_bar(f: &mut Frame, app: &App, area: Rect) {
     let match_info = if app.describe_match_lines.is_empty() {
         if app.describe_search_text.is_empty() {
@@ -535,7 +720,12 @@ fn render_log_tail_view(f: &mut Frame, app: &App, area: Rect) {
     } else {
         Color::Green
     };
-    let title = format!(" {} | {} ", state.log_stream, status);
+    let pinned = if app.log_tail_pinned() {
+        " | PINNED"
+    } else {
+        ""
+    };
+    let title = format!(" {} | {}{} ", state.log_stream, status, pinned);
 
     let block = Block::default()
         .borders(Borders::ALL)
@@ -758,6 +948,7 @@ fn render_crumb(f: &mut Frame, app: &App, area: Rect) {
         }
         if app.pagination.has_more {
             hints.push("]:next");
+            hints.push("}:all");
         }
         format!(" | {}", hints.join(" "))
     } else {
@@ -774,10 +965,13 @@ fn render_crumb(f: &mut Frame, app: &App, area: Rect) {
         } else if !app.describe_search_text.is_empty() {
             "n/N: next/prev match | /: new search | Esc: clear".to_string()
         } else {
-            "j/k: scroll | /: search | q/d/Esc: back".to_string()
+            "j/k: scroll | /: search | e: edit | |: pager | q/d/Esc: back".to_string()
         }
     } else if app.mode == Mode::LogTail {
-        "j/k: scroll | G: bottom (live) | g: top | SPACE: pause | q: exit".to_string()
+        "j/k: scroll | G: bottom (live) | g: top | SPACE: pause | P: pin | |: pager | q: exit"
+            .to_string()
+    } else if app.mode == Mode::Incident {
+        "Tab/j/k: focus | x: unpin | R: refresh | :incident save <name> | q: exit".to_string()
     } else if app.filter_active {
         if app.filter_text.to_lowercase().starts_with("filters:") {
             // Show resource-specific hint if available
@@ -1,6 +1,6 @@
 use crate::app::App;
 use ratatui::{
-    layout::{Alignment, Rect},
+    layout::{Alignment, Constraint, Rect},
     style::{Color, Modifier, Style},
     text::Span,
     widgets::{Block, Borders, Cell, Row, Table, TableState},
@@ -24,7 +24,7 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
     let inner_area = block.inner(area);
     f.render_widget(block, area);
 
-    let header_cells = [" PROFILE"].iter().map(|h| {
+    let header_cells = [" PROFILE", "REGION", "CREDENTIALS"].iter().map(|h| {
         Cell::from(*h).style(
             Style::default()
                 .fg(Color::Yellow)
@@ -35,24 +35,27 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
     let header = Row::new(header_cells).height(1);
 
     let rows = app.available_profiles.iter().map(|profile| {
-        let style = if profile == &app.profile {
+        let current = profile.name == app.profile;
+        let style = if current {
             Style::default().fg(Color::Green)
         } else {
             Style::default()
         };
 
-        let marker = if profile == &app.profile {
-            " * "
-        } else {
-            "   "
-        };
+        let marker = if current { " * " } else { "   " };
 
         Row::new(vec![
-            Cell::from(format!("{}{}", marker, profile)).style(style)
+            Cell::from(format!("{}{}", marker, profile.name)).style(style),
+            Cell::from(profile.region.clone().unwrap_or_else(|| "-".to_string())).style(style),
+            Cell::from(profile.details()).style(Style::default().fg(Color::DarkGray)),
         ])
     });
 
-    let widths = [ratatui::layout::Constraint::Percentage(100)];
+    let widths = [
+        Constraint::Percentage(35),
+        Constraint::Length(16),
+        Constraint::Min(20),
+    ];
 
     let table = Table::new(rows, widths).header(header).row_highlight_style(
         Style::default()
@@ -68,6 +68,8 @@ pub fn render(f: &mut Frame, splash: &SplashState) {
 
     // Render status message
     render_status(f, splash, content[4]);
+
+    super::charset::apply(f.buffer_mut());
 }
 
 fn render_big_logo(f: &mut Frame, area: Rect) {